@@ -21,7 +21,11 @@ fn main() {
         (NumPyOp::Add, "np.add(a, b) or a + b"),
         (NumPyOp::Subtract, "np.subtract(a, b) or a - b"),
         (NumPyOp::Multiply, "np.multiply(a, b) or a * b"),
+        (NumPyOp::Divide, "np.divide(a, b) or a / b"),
         (NumPyOp::Sum, "np.sum(a)"),
+        (NumPyOp::Mean, "np.mean(a)"),
+        (NumPyOp::Max, "np.max(a)"),
+        (NumPyOp::Min, "np.min(a)"),
         (NumPyOp::Dot, "np.dot(a, b) or a @ b"),
     ];
 
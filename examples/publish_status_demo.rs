@@ -108,6 +108,8 @@ fn demo_publish_actions() {
         PublishAction::NeedsPublish,
         PublishAction::LocalBehind,
         PublishAction::NotPublished,
+        PublishAction::NeedsRepublish,
+        PublishAction::NeedsPush,
         PublishAction::Error,
     ];
 
@@ -136,6 +138,9 @@ fn demo_git_status() {
                 modified: 0,
                 untracked: 0,
                 staged: 0,
+                ahead: 0,
+                behind: 0,
+                lockfile_drift: false,
                 head_sha: "abc123f".to_string(),
                 is_clean: true,
             },
@@ -146,6 +151,9 @@ fn demo_git_status() {
                 modified: 5,
                 untracked: 0,
                 staged: 0,
+                ahead: 0,
+                behind: 0,
+                lockfile_drift: false,
                 head_sha: "def456a".to_string(),
                 is_clean: false,
             },
@@ -156,6 +164,9 @@ fn demo_git_status() {
                 modified: 3,
                 untracked: 2,
                 staged: 1,
+                ahead: 0,
+                behind: 0,
+                lockfile_drift: false,
                 head_sha: "789bcd0".to_string(),
                 is_clean: false,
             },
@@ -202,7 +213,7 @@ fn demo_action_determination() {
             modified: if is_clean { 0 } else { 3 },
             ..Default::default()
         };
-        let action = determine_action(local, remote, &git_status);
+        let action = determine_action(local, remote, false, &git_status);
         println!(
             "  {:<25} {:<8} {:<8} {:<8} {:<15}",
             name,
@@ -251,6 +262,7 @@ fn demo_report_generation() {
             name: "trueno".to_string(),
             local_version: Some("0.8.1".to_string()),
             crates_io_version: Some("0.8.1".to_string()),
+            crates_io_yanked: false,
             git_status: GitStatus {
                 is_clean: true,
                 ..Default::default()
@@ -263,6 +275,7 @@ fn demo_report_generation() {
             name: "pacha".to_string(),
             local_version: Some("0.1.2".to_string()),
             crates_io_version: Some("0.1.1".to_string()),
+            crates_io_yanked: false,
             git_status: GitStatus {
                 is_clean: true,
                 ..Default::default()
@@ -275,6 +288,7 @@ fn demo_report_generation() {
             name: "depyler".to_string(),
             local_version: Some("3.21.0".to_string()),
             crates_io_version: Some("3.20.0".to_string()),
+            crates_io_yanked: false,
             git_status: GitStatus {
                 modified: 8,
                 untracked: 3,
@@ -289,6 +303,7 @@ fn demo_report_generation() {
             name: "certeza".to_string(),
             local_version: Some("0.1.0".to_string()),
             crates_io_version: None,
+            crates_io_yanked: false,
             git_status: GitStatus {
                 is_clean: true,
                 ..Default::default()
@@ -386,6 +401,8 @@ mod tests {
             PublishAction::NeedsPublish,
             PublishAction::LocalBehind,
             PublishAction::NotPublished,
+            PublishAction::NeedsRepublish,
+            PublishAction::NeedsPush,
             PublishAction::Error,
         ];
 
@@ -417,7 +434,7 @@ mod tests {
             is_clean: true,
             ..Default::default()
         };
-        let action = determine_action(Some("1.0.0"), Some("1.0.0"), &git);
+        let action = determine_action(Some("1.0.0"), Some("1.0.0"), false, &git);
         assert_eq!(action, PublishAction::UpToDate);
     }
 
@@ -427,7 +444,7 @@ mod tests {
             is_clean: true,
             ..Default::default()
         };
-        let action = determine_action(Some("1.0.1"), Some("1.0.0"), &git);
+        let action = determine_action(Some("1.0.1"), Some("1.0.0"), false, &git);
         assert_eq!(action, PublishAction::NeedsPublish);
     }
 
@@ -438,6 +455,7 @@ mod tests {
                 name: "a".to_string(),
                 local_version: Some("1.0.0".to_string()),
                 crates_io_version: Some("1.0.0".to_string()),
+                crates_io_yanked: false,
                 git_status: GitStatus::default(),
                 action: PublishAction::UpToDate,
                 path: PathBuf::from("."),
@@ -447,6 +465,7 @@ mod tests {
                 name: "b".to_string(),
                 local_version: Some("1.0.1".to_string()),
                 crates_io_version: Some("1.0.0".to_string()),
+                crates_io_yanked: false,
                 git_status: GitStatus::default(),
                 action: PublishAction::NeedsPublish,
                 path: PathBuf::from("."),
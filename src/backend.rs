@@ -39,13 +39,20 @@
 /// | Medium | 10K elements | 100K elements | Moderate compute/transfer ratio |
 /// | High | 1K elements | 10K elements | O(n²/n³) complexity favors GPU |
 ///
+/// With `adaptive_thresholds` enabled, an [`AdaptiveThresholdStore`] learns
+/// these crossovers from measured latencies and can be persisted as JSON.
+/// [`BackendSelector::calibrate`] seeds that store by benchmarking the host.
+///
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::Duration;
 
 #[cfg(feature = "trueno-integration")]
 use trueno::{Matrix, Vector};
 
 /// Compute backend options
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[allow(clippy::upper_case_acronyms)]
 #[allow(dead_code)]
 pub enum Backend {
@@ -55,6 +62,8 @@ pub enum Backend {
     SIMD,
     /// GPU acceleration (WebGPU/Vulkan)
     GPU,
+    /// Browser GPU acceleration via WebGPU (WASM builds)
+    WebGpu,
 }
 
 impl std::fmt::Display for Backend {
@@ -63,12 +72,13 @@ impl std::fmt::Display for Backend {
             Backend::Scalar => write!(f, "Scalar"),
             Backend::SIMD => write!(f, "SIMD"),
             Backend::GPU => write!(f, "GPU"),
+            Backend::WebGpu => write!(f, "WebGPU"),
         }
     }
 }
 
 /// Operation complexity for MoE (Mixture-of-Experts) routing
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub enum OpComplexity {
     /// Simple operations (add, mul) - O(n), prefer SIMD unless very large
@@ -79,6 +89,15 @@ pub enum OpComplexity {
     High,
 }
 
+/// Element width (f64) the MoE element thresholds are calibrated for
+pub const DEFAULT_DTYPE_BYTES: usize = 8;
+
+/// WebGPU buffer upload bandwidth in bytes/sec (browser staging copies)
+pub const WEBGPU_TRANSFER_BANDWIDTH: f64 = 8e9;
+
+/// Sustained WebGPU compute throughput in FLOPS (integrated/laptop GPUs)
+pub const WEBGPU_GFLOPS: f64 = 2e12;
+
 /// Cost model for backend selection
 /// Based on spec section 2.2 lines 191-204
 #[allow(dead_code)]
@@ -91,14 +110,29 @@ pub struct BackendSelector {
 
     /// Minimum dispatch ratio (default: 5× per Gregg & Hazelwood 2011)
     min_dispatch_ratio: f64,
+
+    /// Backend returned when GPU dispatch wins (GPU, or WebGpu in WASM builds)
+    gpu_backend: Backend,
+
+    /// Learned MoE thresholds, consulted when adaptive thresholds are enabled
+    adaptive: Option<AdaptiveThresholdStore>,
 }
 
 impl Default for BackendSelector {
     fn default() -> Self {
-        Self {
+        let selector = Self {
             pcie_bandwidth: 32e9,    // 32 GB/s
             gpu_gflops: 20e12,       // 20 TFLOPS
             min_dispatch_ratio: 5.0, // 5× rule
+            gpu_backend: Backend::GPU,
+            adaptive: None,
+        };
+
+        // Browser-only builds have no native GPU, only WebGPU
+        if cfg!(all(feature = "wasm", not(feature = "native"))) {
+            selector.with_webgpu()
+        } else {
+            selector
         }
     }
 }
@@ -126,6 +160,46 @@ impl BackendSelector {
         self
     }
 
+    /// Dispatch GPU work to WebGPU, using WebGPU transfer/compute constants
+    ///
+    /// This is the default for WASM builds; the 5× dispatch rule still applies.
+    pub fn with_webgpu(mut self) -> Self {
+        self.gpu_backend = Backend::WebGpu;
+        self.pcie_bandwidth = WEBGPU_TRANSFER_BANDWIDTH;
+        self.gpu_gflops = WEBGPU_GFLOPS;
+        self
+    }
+
+    /// Backend used when GPU dispatch is worthwhile
+    pub fn gpu_backend(&self) -> Backend {
+        self.gpu_backend
+    }
+
+    /// Route MoE selection through learned thresholds
+    /// (`TruenoConfig.adaptive_thresholds`)
+    pub fn with_adaptive_thresholds(mut self, store: AdaptiveThresholdStore) -> Self {
+        self.adaptive = Some(store);
+        self
+    }
+
+    /// Adaptive threshold store, if adaptive mode is on
+    pub fn adaptive_store(&self) -> Option<&AdaptiveThresholdStore> {
+        self.adaptive.as_ref()
+    }
+
+    /// Mutable adaptive threshold store, for recording measured latencies
+    pub fn adaptive_store_mut(&mut self) -> Option<&mut AdaptiveThresholdStore> {
+        self.adaptive.as_mut()
+    }
+
+    /// MoE thresholds in effect for a complexity level
+    pub fn moe_thresholds(&self, complexity: OpComplexity) -> MoeThresholds {
+        match &self.adaptive {
+            Some(store) => store.thresholds(complexity),
+            None => MoeThresholds::default_for(complexity),
+        }
+    }
+
     /// Select optimal backend based on workload characteristics
     ///
     /// # Arguments
@@ -147,6 +221,13 @@ impl BackendSelector {
     /// - Device→Host transfer
     /// - CPU-GPU synchronization
     pub fn select_backend(&self, data_bytes: usize, flops: u64) -> Backend {
+        self.explain(data_bytes, flops).backend
+    }
+
+    /// Select a backend and report the cost model numbers behind the choice
+    ///
+    /// Same decision as [`Self::select_backend`].
+    pub fn explain(&self, data_bytes: usize, flops: u64) -> BackendDecision {
         // Calculate transfer time (seconds)
         let transfer_s = data_bytes as f64 / self.pcie_bandwidth;
 
@@ -154,11 +235,19 @@ impl BackendSelector {
         let compute_s = flops as f64 / self.gpu_gflops;
 
         // Apply 5× dispatch rule
-        if compute_s > self.min_dispatch_ratio * transfer_s {
-            Backend::GPU
+        let backend = if compute_s > self.min_dispatch_ratio * transfer_s {
+            self.gpu_backend
         } else {
             // Fallback to SIMD for intermediate workloads
             Backend::SIMD
+        };
+
+        BackendDecision {
+            backend,
+            transfer_s,
+            compute_s,
+            ratio: compute_s / transfer_s,
+            min_dispatch_ratio: self.min_dispatch_ratio,
         }
     }
 
@@ -221,37 +310,156 @@ impl BackendSelector {
     /// - **Low complexity** (element-wise): SIMD at 1M+ elements, never GPU
     /// - **Medium complexity** (reductions): SIMD at 10K+, GPU at 100K+ elements
     /// - **High complexity** (matmul): SIMD at 1K+, GPU at 10K+ elements
+    ///
+    /// With [`Self::with_adaptive_thresholds`], learned thresholds replace these.
     pub fn select_with_moe(&self, complexity: OpComplexity, data_size: usize) -> Backend {
-        match complexity {
-            OpComplexity::Low => {
-                // Element-wise: memory-bound, GPU overhead not justified
-                if data_size > 1_000_000 {
-                    Backend::SIMD
-                } else {
-                    Backend::Scalar
-                }
+        let thresholds = self.moe_thresholds(complexity);
+        if thresholds.gpu.is_some_and(|gpu| data_size > gpu) {
+            self.gpu_backend
+        } else if data_size > thresholds.simd {
+            Backend::SIMD
+        } else {
+            Backend::Scalar
+        }
+    }
+
+    /// Select a single backend for a fused sequence of operations
+    ///
+    /// # Arguments
+    /// * `ops` - `(complexity, data_size)` for each operation, in order
+    ///
+    /// Each MoE GPU threshold is the size at which an operation's compute pays
+    /// for its own host↔device transfer. A fused chain keeps data on the device
+    /// and pays that transfer once, so each op contributes `data_size / gpu`
+    /// of the break-even and the chain goes to the GPU when the sum exceeds 1.
+    /// Otherwise SIMD is chosen if any op would pick it on its own.
+    pub fn select_for_pipeline(&self, ops: &[(OpComplexity, usize)]) -> Backend {
+        let gpu_share: f64 = ops
+            .iter()
+            .filter_map(|&(complexity, data_size)| {
+                let gpu = self.moe_thresholds(complexity).gpu?;
+                Some(data_size as f64 / gpu.max(1) as f64)
+            })
+            .sum();
+        if gpu_share > 1.0 {
+            return self.gpu_backend;
+        }
+
+        let any_simd = ops
+            .iter()
+            .any(|&(complexity, data_size)| data_size > self.moe_thresholds(complexity).simd);
+        if any_simd {
+            Backend::SIMD
+        } else {
+            Backend::Scalar
+        }
+    }
+
+    /// MoE routing that accounts for element width
+    ///
+    /// # Arguments
+    /// * `complexity` - Operation complexity (Low/Medium/High)
+    /// * `data_size` - Number of elements in the operation
+    /// * `data_bytes` - Total size of the operands in bytes
+    ///
+    /// GPU dispatch pays off when compute (per element) outweighs PCIe
+    /// transfer (per byte), so the GPU thresholds of [`Self::select_with_moe`]
+    /// scale with element width relative to [`DEFAULT_DTYPE_BYTES`]: narrow
+    /// dtypes reach the GPU at fewer elements, wide ones need more. The
+    /// Scalar/SIMD split is unchanged.
+    pub fn select_with_moe_bytes(
+        &self,
+        complexity: OpComplexity,
+        data_size: usize,
+        data_bytes: usize,
+    ) -> Backend {
+        let backend = self.select_with_moe(complexity, data_size);
+        if data_size == 0 || data_bytes == 0 {
+            return backend;
+        }
+
+        // Element count an f64 operand would need for the same compute/transfer ratio
+        let f64_equivalent = (data_size as u128 * data_size as u128 * DEFAULT_DTYPE_BYTES as u128
+            / data_bytes as u128)
+            .min(usize::MAX as u128) as usize;
+
+        let gpu = self.gpu_backend;
+        match (self.select_with_moe(complexity, f64_equivalent), backend) {
+            (b, _) if b == gpu => gpu,
+            (_, b) if b == gpu => Backend::SIMD,
+            (_, backend) => backend,
+        }
+    }
+
+    /// Calibrate MoE thresholds by benchmarking this machine
+    ///
+    /// Times a representative op per complexity level (element-wise add,
+    /// dot product, square matmul) at each of [`CALIBRATION_SIZES`] on the
+    /// Scalar and SIMD backends. Medium and High GPU latency is modelled from this selector's
+    /// transfer/compute constants plus [`GPU_LAUNCH_OVERHEAD`], since no
+    /// device runtime is linked. The samples feed an [`AdaptiveThresholdStore`]
+    /// (enabling adaptive mode if it was off), so the learned thresholds
+    /// persist with [`AdaptiveThresholdStore::save`].
+    ///
+    /// Does nothing if the store is already calibrated; see [`Self::recalibrate`].
+    #[cfg(feature = "native")]
+    pub fn calibrate(&mut self) {
+        self.calibrate_native(false);
+    }
+
+    /// Calibrate even if the store already holds calibration results
+    #[cfg(feature = "native")]
+    pub fn recalibrate(&mut self) {
+        self.calibrate_native(true);
+    }
+
+    #[cfg(feature = "native")]
+    fn calibrate_native(&mut self, force: bool) {
+        let (pcie_bandwidth, gpu_gflops) = (self.pcie_bandwidth, self.gpu_gflops);
+        self.calibrate_with(force, |complexity, backend, n| match backend {
+            Backend::Scalar | Backend::SIMD => Some(benchmark_op(complexity, backend, n)),
+            Backend::GPU | Backend::WebGpu => {
+                let (bytes, flops) = op_cost(complexity, n);
+                let seconds = bytes as f64 / pcie_bandwidth + flops as f64 / gpu_gflops;
+                Some(GPU_LAUNCH_OVERHEAD + Duration::from_secs_f64(seconds))
             }
-            OpComplexity::Medium => {
-                // Reductions (dot product, sum): moderate compute
-                if data_size > 100_000 {
-                    Backend::GPU
-                } else if data_size > 10_000 {
-                    Backend::SIMD
-                } else {
-                    Backend::Scalar
+        });
+    }
+
+    /// Calibrate MoE thresholds from an injected timer
+    ///
+    /// `time_op(complexity, backend, data_size)` returns the latency of one
+    /// run, or `None` if that backend is unavailable (its threshold then keeps
+    /// the built-in value). The GPU is never timed for Low complexity ops,
+    /// which stay off the GPU. Unless `force` is set, an already calibrated
+    /// store is left untouched; a forced run starts from an empty store.
+    pub fn calibrate_with<F>(&mut self, force: bool, mut time_op: F)
+    where
+        F: FnMut(OpComplexity, Backend, usize) -> Option<Duration>,
+    {
+        let gpu_backend = self.gpu_backend;
+        let store = self
+            .adaptive
+            .get_or_insert_with(AdaptiveThresholdStore::new);
+        if store.is_calibrated() && !force {
+            return;
+        }
+
+        let mut calibrated = AdaptiveThresholdStore::new();
+        for complexity in [OpComplexity::Low, OpComplexity::Medium, OpComplexity::High] {
+            for backend in [Backend::Scalar, Backend::SIMD, gpu_backend] {
+                if complexity == OpComplexity::Low && backend == gpu_backend {
+                    continue;
                 }
-            }
-            OpComplexity::High => {
-                // Matrix operations: compute-intensive, O(n²) or O(n³)
-                if data_size > 10_000 {
-                    Backend::GPU
-                } else if data_size > 1_000 {
-                    Backend::SIMD
-                } else {
-                    Backend::Scalar
+                for n in CALIBRATION_SIZES {
+                    if let Some(latency) = time_op(complexity, backend, n) {
+                        calibrated.record(complexity, backend, n, latency);
+                    }
                 }
             }
         }
+        calibrated.calibrated = true;
+        *store = calibrated;
     }
 
     /// Map Batuta Backend to Trueno Backend
@@ -260,7 +468,7 @@ impl BackendSelector {
         match backend {
             Backend::Scalar => trueno::Backend::Scalar,
             Backend::SIMD => trueno::Backend::Auto, // Let Trueno pick best SIMD (AVX2/NEON)
-            Backend::GPU => trueno::Backend::GPU,
+            Backend::GPU | Backend::WebGpu => trueno::Backend::GPU,
         }
     }
 
@@ -328,6 +536,344 @@ impl BackendSelector {
     }
 }
 
+/// A backend choice with the cost model inputs that produced it
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BackendDecision {
+    /// Selected backend
+    pub backend: Backend,
+    /// Host → device transfer time in seconds
+    pub transfer_s: f64,
+    /// GPU compute time in seconds
+    pub compute_s: f64,
+    /// `compute_s / transfer_s` (infinite when nothing is transferred)
+    pub ratio: f64,
+    /// Ratio GPU dispatch must exceed (the 5× rule by default)
+    pub min_dispatch_ratio: f64,
+}
+
+impl std::fmt::Display for BackendDecision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let verdict = if self.ratio > self.min_dispatch_ratio {
+            ">"
+        } else {
+            "<="
+        };
+        write!(
+            f,
+            "{}: compute {:.2}µs / transfer {:.2}µs = {:.2}× ({} {}× rule)",
+            self.backend,
+            self.compute_s * 1e6,
+            self.transfer_s * 1e6,
+            self.ratio,
+            verdict,
+            self.min_dispatch_ratio
+        )
+    }
+}
+
+/// Element-count thresholds for MoE routing at one complexity level
+///
+/// An operation goes to SIMD above `simd` elements and to the GPU above
+/// `gpu` elements (`None` means never).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MoeThresholds {
+    /// Scalar → SIMD crossover
+    pub simd: usize,
+    /// SIMD → GPU crossover
+    pub gpu: Option<usize>,
+}
+
+impl MoeThresholds {
+    /// Built-in thresholds from the module-level table
+    pub fn default_for(complexity: OpComplexity) -> Self {
+        match complexity {
+            // Element-wise: memory-bound, GPU overhead not justified
+            OpComplexity::Low => Self {
+                simd: 1_000_000,
+                gpu: None,
+            },
+            // Reductions (dot product, sum): moderate compute
+            OpComplexity::Medium => Self {
+                simd: 10_000,
+                gpu: Some(100_000),
+            },
+            // Matrix operations: compute-intensive, O(n²) or O(n³)
+            OpComplexity::High => Self {
+                simd: 1_000,
+                gpu: Some(10_000),
+            },
+        }
+    }
+}
+
+/// Measured latencies kept per (complexity, backend); oldest are dropped first
+pub const MAX_LATENCY_SAMPLES: usize = 1024;
+
+/// One measured operation latency
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LatencySample {
+    /// Number of elements processed
+    pub data_size: usize,
+    /// Wall-clock latency in seconds
+    pub seconds: f64,
+}
+
+/// Learns MoE crossover points from measured latencies
+///
+/// Latency is modelled per backend as `fixed + per_element × n` (least
+/// squares over the recorded samples); a threshold is where two backends'
+/// lines cross. Until a backend pair has samples at two or more distinct
+/// sizes, the built-in threshold is kept.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AdaptiveThresholdStore {
+    samples: BTreeMap<OpComplexity, BTreeMap<Backend, Vec<LatencySample>>>,
+    thresholds: BTreeMap<OpComplexity, MoeThresholds>,
+    #[serde(default)]
+    calibrated: bool,
+}
+
+impl AdaptiveThresholdStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a measured latency and update the learned thresholds
+    pub fn record(
+        &mut self,
+        complexity: OpComplexity,
+        backend: Backend,
+        data_size: usize,
+        latency: Duration,
+    ) {
+        let samples = self
+            .samples
+            .entry(complexity)
+            .or_default()
+            .entry(backend)
+            .or_default();
+        if samples.len() >= MAX_LATENCY_SAMPLES {
+            samples.remove(0);
+        }
+        samples.push(LatencySample {
+            data_size,
+            seconds: latency.as_secs_f64(),
+        });
+
+        let learned = self.learn(complexity);
+        self.thresholds.insert(complexity, learned);
+    }
+
+    /// Current thresholds for a complexity level (learned or built-in)
+    pub fn thresholds(&self, complexity: OpComplexity) -> MoeThresholds {
+        self.thresholds
+            .get(&complexity)
+            .copied()
+            .unwrap_or_else(|| MoeThresholds::default_for(complexity))
+    }
+
+    /// Recorded samples for a complexity level and backend
+    pub fn samples(&self, complexity: OpComplexity, backend: Backend) -> &[LatencySample] {
+        self.samples
+            .get(&complexity)
+            .and_then(|by_backend| by_backend.get(&backend))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Whether the samples came from [`BackendSelector::calibrate`]
+    pub fn is_calibrated(&self) -> bool {
+        self.calibrated
+    }
+
+    /// Save the store as JSON
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Load a store saved with [`Self::save`]
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    fn learn(&self, complexity: OpComplexity) -> MoeThresholds {
+        let defaults = MoeThresholds::default_for(complexity);
+        let fit = |backend: Backend| fit_latency(self.samples(complexity, backend));
+        let scalar = fit(Backend::Scalar);
+        let simd = fit(Backend::SIMD);
+        let gpu = fit(Backend::GPU).or_else(|| fit(Backend::WebGpu));
+
+        let simd_threshold = match (scalar, simd) {
+            (Some(scalar), Some(simd)) => crossover(scalar, simd),
+            _ => Some(defaults.simd),
+        };
+        // Low ops are memory-bound and never routed to the GPU
+        let gpu_threshold = match (simd, gpu) {
+            _ if complexity == OpComplexity::Low => None,
+            (Some(simd), Some(gpu)) => crossover(simd, gpu),
+            _ => defaults.gpu,
+        };
+
+        MoeThresholds {
+            simd: simd_threshold.unwrap_or(usize::MAX),
+            gpu: gpu_threshold,
+        }
+    }
+}
+
+/// Data sizes (elements) each op is benchmarked at during calibration
+pub const CALIBRATION_SIZES: [usize; 5] = [1_000, 4_000, 16_000, 64_000, 256_000];
+
+/// Fixed GPU kernel launch + synchronization cost assumed during calibration
+pub const GPU_LAUNCH_OVERHEAD: Duration = Duration::from_micros(20);
+
+/// `(bytes transferred, flops)` of the calibration op for `n` f32 elements
+fn op_cost(complexity: OpComplexity, n: usize) -> (usize, u64) {
+    match complexity {
+        OpComplexity::Low => (3 * n * 4, n as u64),
+        OpComplexity::Medium => (2 * n * 4, 2 * n as u64),
+        OpComplexity::High => {
+            let dim = matrix_dim(n);
+            (3 * dim * dim * 4, 2 * (dim as u64).pow(3))
+        }
+    }
+}
+
+/// Side of the square matrix with roughly `n` elements
+fn matrix_dim(n: usize) -> usize {
+    ((n as f64).sqrt() as usize).max(1)
+}
+
+/// f32 lanes the SIMD calibration kernels are unrolled to (one AVX2 register)
+#[cfg(feature = "native")]
+const SIMD_LANES: usize = 8;
+
+/// Best-of-three wall-clock latency of the calibration op on a CPU backend
+///
+/// The scalar kernels keep a sequential dependency (or an opaque load) per
+/// element so the compiler cannot vectorize them; the SIMD kernels are laid
+/// out for auto-vectorization.
+#[cfg(feature = "native")]
+fn benchmark_op(complexity: OpComplexity, backend: Backend, n: usize) -> Duration {
+    use std::hint::black_box;
+    use std::time::Instant;
+
+    let simd = backend != Backend::Scalar;
+    let len = match complexity {
+        OpComplexity::High => matrix_dim(n).pow(2),
+        _ => n,
+    };
+    let a: Vec<f32> = (0..len).map(|i| (i % 7) as f32).collect();
+    let b: Vec<f32> = (0..len).map(|i| (i % 5) as f32).collect();
+
+    (0..3)
+        .map(|_| {
+            let start = Instant::now();
+            match complexity {
+                OpComplexity::Low => {
+                    let _ = black_box(add_kernel(black_box(&a), black_box(&b), simd));
+                }
+                OpComplexity::Medium => {
+                    let _ = black_box(dot_kernel(black_box(&a), black_box(&b), simd));
+                }
+                OpComplexity::High => {
+                    let _ = black_box(matmul_kernel(black_box(&a), black_box(&b), simd));
+                }
+            }
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+#[cfg(feature = "native")]
+fn add_kernel(a: &[f32], b: &[f32], simd: bool) -> Vec<f32> {
+    if simd {
+        a.iter().zip(b).map(|(x, y)| x + y).collect()
+    } else {
+        a.iter()
+            .zip(b)
+            .map(|(x, y)| std::hint::black_box(*x) + y)
+            .collect()
+    }
+}
+
+#[cfg(feature = "native")]
+fn dot_kernel(a: &[f32], b: &[f32], simd: bool) -> f32 {
+    if !simd {
+        return a.iter().zip(b).fold(0.0, |acc, (x, y)| acc + x * y);
+    }
+    let (chunks_a, chunks_b) = (a.chunks_exact(SIMD_LANES), b.chunks_exact(SIMD_LANES));
+    let tail: f32 = chunks_a
+        .remainder()
+        .iter()
+        .zip(chunks_b.remainder())
+        .map(|(x, y)| x * y)
+        .sum();
+    let mut lanes = [0.0f32; SIMD_LANES];
+    for (xs, ys) in chunks_a.zip(chunks_b) {
+        for ((lane, x), y) in lanes.iter_mut().zip(xs).zip(ys) {
+            *lane += x * y;
+        }
+    }
+    lanes.iter().sum::<f32>() + tail
+}
+
+/// Square matmul: dot-product order for Scalar, row-axpy order for SIMD
+#[cfg(feature = "native")]
+fn matmul_kernel(a: &[f32], b: &[f32], simd: bool) -> Vec<f32> {
+    let dim = matrix_dim(a.len());
+    let mut out = vec![0.0f32; dim * dim];
+    for (i, out_row) in out.chunks_exact_mut(dim).enumerate() {
+        let a_row = &a[i * dim..(i + 1) * dim];
+        if simd {
+            for (&a_ik, b_row) in a_row.iter().zip(b.chunks_exact(dim)) {
+                for (o, b_kj) in out_row.iter_mut().zip(b_row) {
+                    *o += a_ik * b_kj;
+                }
+            }
+        } else {
+            for (j, o) in out_row.iter_mut().enumerate() {
+                *o = a_row
+                    .iter()
+                    .enumerate()
+                    .fold(0.0, |acc, (k, a_ik)| acc + a_ik * b[k * dim + j]);
+            }
+        }
+    }
+    out
+}
+
+/// Least-squares `(fixed, per_element)` latency line, given two or more sizes
+fn fit_latency(samples: &[LatencySample]) -> Option<(f64, f64)> {
+    let n = samples.len() as f64;
+    let mean_x = samples.iter().map(|s| s.data_size as f64).sum::<f64>() / n;
+    let mean_y = samples.iter().map(|s| s.seconds).sum::<f64>() / n;
+    let (sxx, sxy) = samples.iter().fold((0.0, 0.0), |(sxx, sxy), s| {
+        let dx = s.data_size as f64 - mean_x;
+        (sxx + dx * dx, sxy + dx * (s.seconds - mean_y))
+    });
+    if sxx == 0.0 {
+        return None;
+    }
+    let slope = sxy / sxx;
+    Some((mean_y - slope * mean_x, slope))
+}
+
+/// Size above which `faster` (lower per-element cost) beats `baseline`
+///
+/// `None` if it never does.
+fn crossover(baseline: (f64, f64), faster: (f64, f64)) -> Option<usize> {
+    let (base_fixed, base_slope) = baseline;
+    let (fast_fixed, fast_slope) = faster;
+    if fast_slope >= base_slope {
+        return (fast_fixed < base_fixed).then_some(0);
+    }
+    let n = (fast_fixed - base_fixed) / (base_slope - fast_slope);
+    Some(n.clamp(0.0, usize::MAX as f64) as usize)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -460,6 +1006,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_moe_bytes_default_width_matches_moe() {
+        let selector = BackendSelector::new();
+
+        for complexity in [OpComplexity::Low, OpComplexity::Medium, OpComplexity::High] {
+            for n in [0, 500, 5_000, 50_000, 500_000, 5_000_000] {
+                assert_eq!(
+                    selector.select_with_moe_bytes(complexity, n, n * DEFAULT_DTYPE_BYTES),
+                    selector.select_with_moe(complexity, n),
+                    "{:?} at {} elements",
+                    complexity,
+                    n
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_moe_bytes_element_width() {
+        let selector = BackendSelector::new();
+
+        // Narrow elements are cheap to transfer: GPU at fewer elements
+        assert_eq!(
+            selector.select_with_moe_bytes(OpComplexity::Medium, 50_000, 50_000),
+            Backend::GPU
+        );
+
+        // Wide elements cost more to transfer: stay on SIMD
+        assert_eq!(
+            selector.select_with_moe_bytes(OpComplexity::Medium, 150_000, 150_000 * 16),
+            Backend::SIMD
+        );
+
+        // Element-wise ops never go to the GPU
+        assert_eq!(
+            selector.select_with_moe_bytes(OpComplexity::Low, 50_000_000, 50_000_000),
+            Backend::SIMD
+        );
+    }
+
     #[test]
     fn test_moe_high_complexity() {
         let selector = BackendSelector::new();
@@ -483,6 +1069,344 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_explain_matches_select_backend() {
+        let selector = BackendSelector::new();
+
+        // 1 MB / 1 GFLOP: compute 50 μs, transfer 31.25 μs → 1.6× (< 5×)
+        let decision = selector.explain(1_000_000, 1_000_000_000);
+        assert_eq!(
+            decision.backend,
+            selector.select_backend(1_000_000, 1_000_000_000)
+        );
+        assert_eq!(decision.backend, Backend::SIMD);
+        assert!((decision.transfer_s - 31.25e-6).abs() < 1e-12);
+        assert!((decision.compute_s - 50e-6).abs() < 1e-12);
+        assert!((decision.ratio - 1.6).abs() < 1e-9);
+        assert_eq!(decision.min_dispatch_ratio, 5.0);
+        assert_eq!(
+            decision.to_string(),
+            "SIMD: compute 50.00µs / transfer 31.25µs = 1.60× (<= 5× rule)"
+        );
+
+        let gpu = selector.explain(1_000_000, 100_000_000_000);
+        assert_eq!(gpu.backend, Backend::GPU);
+        assert!(gpu.to_string().contains("(> 5× rule)"));
+    }
+
+    #[test]
+    fn test_pipeline_amortizes_transfer() {
+        let selector = BackendSelector::new();
+        let op = (OpComplexity::Medium, 40_000);
+        assert_eq!(selector.select_with_moe(op.0, op.1), Backend::SIMD);
+
+        // 3 × 40K / 100K = 1.2 of the GPU break-even once transfer is shared
+        assert_eq!(selector.select_for_pipeline(&[op, op, op]), Backend::GPU);
+        assert_eq!(selector.select_for_pipeline(&[op, op]), Backend::SIMD);
+    }
+
+    #[test]
+    fn test_pipeline_single_op_matches_moe() {
+        let selector = BackendSelector::new();
+        for complexity in [OpComplexity::Low, OpComplexity::Medium, OpComplexity::High] {
+            for size in [0, 500, 5_000, 50_000, 500_000, 5_000_000] {
+                assert_eq!(
+                    selector.select_for_pipeline(&[(complexity, size)]),
+                    selector.select_with_moe(complexity, size)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_pipeline_edge_cases() {
+        let selector = BackendSelector::new();
+        assert_eq!(selector.select_for_pipeline(&[]), Backend::Scalar);
+
+        // Element-wise ops never justify GPU, however many are chained
+        let low = (OpComplexity::Low, 900_000);
+        assert_eq!(selector.select_for_pipeline(&[low; 10]), Backend::Scalar);
+
+        // ...but ride along with a chain that does
+        let high = (OpComplexity::High, 8_000);
+        assert_eq!(
+            selector.select_for_pipeline(&[high, low, high]),
+            Backend::GPU
+        );
+
+        let webgpu = BackendSelector::new().with_webgpu();
+        assert_eq!(webgpu.select_for_pipeline(&[high, high]), Backend::WebGpu);
+    }
+
+    /// Record `fixed_ns + per_element_ns × n` latencies at a few sizes
+    fn record_line(
+        store: &mut AdaptiveThresholdStore,
+        complexity: OpComplexity,
+        backend: Backend,
+        fixed_ns: u64,
+        per_element_ns: u64,
+    ) {
+        for n in [1_000, 10_000, 100_000, 1_000_000] {
+            let latency = Duration::from_nanos(fixed_ns + per_element_ns * n as u64);
+            store.record(complexity, backend, n, latency);
+        }
+    }
+
+    #[test]
+    fn test_adaptive_defaults_match_moe() {
+        let store = AdaptiveThresholdStore::new();
+        let adaptive = BackendSelector::new().with_adaptive_thresholds(store);
+        let fixed = BackendSelector::new();
+        for complexity in [OpComplexity::Low, OpComplexity::Medium, OpComplexity::High] {
+            for size in [0, 500, 5_000, 50_000, 500_000, 5_000_000] {
+                assert_eq!(
+                    adaptive.select_with_moe(complexity, size),
+                    fixed.select_with_moe(complexity, size)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_adaptive_learns_simd_crossover() {
+        let mut store = AdaptiveThresholdStore::new();
+        // Scalar: 10 ns/elem; SIMD: 2 μs setup + 2 ns/elem → crossover at 250
+        record_line(&mut store, OpComplexity::High, Backend::Scalar, 0, 10);
+        record_line(&mut store, OpComplexity::High, Backend::SIMD, 2_000, 2);
+
+        let learned = store.thresholds(OpComplexity::High);
+        assert!(learned.simd < MoeThresholds::default_for(OpComplexity::High).simd);
+        assert!((240..=260).contains(&learned.simd), "{}", learned.simd);
+        // No GPU samples: GPU threshold unchanged
+        assert_eq!(learned.gpu, Some(10_000));
+
+        let selector = BackendSelector::new().with_adaptive_thresholds(store);
+        assert_eq!(
+            selector.select_with_moe(OpComplexity::High, 500),
+            Backend::SIMD
+        );
+        assert_eq!(
+            BackendSelector::new().select_with_moe(OpComplexity::High, 500),
+            Backend::Scalar
+        );
+    }
+
+    #[test]
+    fn test_adaptive_learns_gpu_crossover() {
+        let mut selector =
+            BackendSelector::new().with_adaptive_thresholds(AdaptiveThresholdStore::new());
+        let store = selector.adaptive_store_mut().unwrap();
+        // SIMD: 2 ns/elem; GPU: 1 ms launch + transfer, 0 ns/elem → ~500K
+        record_line(store, OpComplexity::High, Backend::SIMD, 0, 2);
+        record_line(store, OpComplexity::High, Backend::GPU, 1_000_000, 0);
+
+        let learned = selector.moe_thresholds(OpComplexity::High);
+        let gpu = learned.gpu.unwrap();
+        assert!(gpu > 10_000, "GPU threshold should move up, got {}", gpu);
+        assert!((490_000..=510_000).contains(&gpu), "{}", gpu);
+        assert_eq!(
+            selector.select_with_moe(OpComplexity::High, 50_000),
+            Backend::SIMD
+        );
+        assert_eq!(
+            selector.select_with_moe(OpComplexity::High, 1_000_000),
+            Backend::GPU
+        );
+    }
+
+    #[test]
+    fn test_adaptive_store_save_load() {
+        let mut store = AdaptiveThresholdStore::new();
+        record_line(&mut store, OpComplexity::Medium, Backend::Scalar, 0, 10);
+        record_line(&mut store, OpComplexity::Medium, Backend::SIMD, 2_000, 2);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("thresholds.json");
+        store.save(&path).unwrap();
+        let loaded = AdaptiveThresholdStore::load(&path).unwrap();
+
+        assert_eq!(loaded, store);
+        assert_eq!(loaded.samples(OpComplexity::Medium, Backend::SIMD).len(), 4);
+        assert!(AdaptiveThresholdStore::load(&dir.path().join("missing.json")).is_err());
+    }
+
+    #[test]
+    fn test_adaptive_store_caps_samples() {
+        let mut store = AdaptiveThresholdStore::new();
+        for i in 0..MAX_LATENCY_SAMPLES + 10 {
+            store.record(
+                OpComplexity::Low,
+                Backend::Scalar,
+                i,
+                Duration::from_nanos(i as u64),
+            );
+        }
+        let samples = store.samples(OpComplexity::Low, Backend::Scalar);
+        assert_eq!(samples.len(), MAX_LATENCY_SAMPLES);
+        assert_eq!(samples[0].data_size, 10);
+    }
+
+    /// Linear latency model for calibration: work per element grows with complexity
+    fn modelled_latency(complexity: OpComplexity, backend: Backend, n: usize) -> Option<Duration> {
+        let work = match complexity {
+            OpComplexity::Low => 1,
+            OpComplexity::Medium => 4,
+            OpComplexity::High => 32,
+        };
+        let (fixed_ns, per_element_ns) = match backend {
+            Backend::Scalar => (0, 8 * work),
+            Backend::SIMD => (4_000, work),
+            Backend::GPU | Backend::WebGpu => (2_000_000, 0),
+        };
+        Some(Duration::from_nanos(fixed_ns + per_element_ns * n as u64))
+    }
+
+    #[test]
+    fn test_calibrate_thresholds_monotonic_in_complexity() {
+        let mut selector = BackendSelector::new();
+        selector.calibrate_with(false, modelled_latency);
+        assert!(selector.adaptive_store().unwrap().is_calibrated());
+
+        let low = selector.moe_thresholds(OpComplexity::Low);
+        let medium = selector.moe_thresholds(OpComplexity::Medium);
+        let high = selector.moe_thresholds(OpComplexity::High);
+        // Heavier ops amortize fixed costs sooner: thresholds rise as complexity falls
+        assert!(high.simd < medium.simd && medium.simd < low.simd);
+        let gpu = |t: MoeThresholds| t.gpu.unwrap();
+        assert!(gpu(high) < gpu(medium));
+        for t in [medium, high] {
+            assert!(t.simd < gpu(t));
+        }
+        // Low: never GPU, and the GPU is not even timed
+        assert_eq!(low.gpu, None);
+        assert!(selector
+            .adaptive_store()
+            .unwrap()
+            .samples(OpComplexity::Low, Backend::GPU)
+            .is_empty());
+        // SIMD: 4 μs setup / 7 ns per element saved at Low → ~571 elements
+        assert!((550..=600).contains(&low.simd), "{}", low.simd);
+    }
+
+    #[test]
+    fn test_adaptive_store_never_learns_low_gpu_threshold() {
+        let mut store = AdaptiveThresholdStore::new();
+        for backend in [Backend::SIMD, Backend::GPU] {
+            for n in CALIBRATION_SIZES {
+                let latency = modelled_latency(OpComplexity::High, backend, n).unwrap();
+                store.record(OpComplexity::Low, backend, n, latency);
+            }
+        }
+        assert_eq!(store.thresholds(OpComplexity::Low).gpu, None);
+    }
+
+    #[test]
+    fn test_calibrate_skips_unless_forced() {
+        let mut selector = BackendSelector::new();
+        selector.calibrate_with(false, modelled_latency);
+        let calibrated = selector.adaptive_store().unwrap().clone();
+
+        let mut calls = 0;
+        selector.calibrate_with(false, |_, _, _| {
+            calls += 1;
+            None
+        });
+        assert_eq!(calls, 0);
+        assert_eq!(selector.adaptive_store(), Some(&calibrated));
+
+        // Survives a save/load round trip
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("calibration.json");
+        calibrated.save(&path).unwrap();
+        let mut reloaded = BackendSelector::new()
+            .with_adaptive_thresholds(AdaptiveThresholdStore::load(&path).unwrap());
+        reloaded.calibrate_with(false, |_, _, _| panic!("should not recalibrate"));
+
+        // Forced: unavailable backends fall back to built-in thresholds
+        selector.calibrate_with(true, |complexity, backend, n| match backend {
+            Backend::GPU => None,
+            _ => modelled_latency(complexity, backend, n),
+        });
+        let high = selector.moe_thresholds(OpComplexity::High);
+        assert_eq!(high.gpu, MoeThresholds::default_for(OpComplexity::High).gpu);
+        assert!(selector
+            .adaptive_store()
+            .unwrap()
+            .samples(OpComplexity::Low, Backend::GPU)
+            .is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "native")]
+    fn test_native_kernels_agree() {
+        let a: Vec<f32> = (0..100).map(|i| (i % 7) as f32).collect();
+        let b: Vec<f32> = (0..100).map(|i| (i % 5) as f32).collect();
+        assert_eq!(add_kernel(&a, &b, true), add_kernel(&a, &b, false));
+        assert_eq!(dot_kernel(&a, &b, true), dot_kernel(&a, &b, false));
+        assert_eq!(matmul_kernel(&a, &b, true), matmul_kernel(&a, &b, false));
+    }
+
+    #[test]
+    fn test_webgpu_large_matmul() {
+        let selector = BackendSelector::new().with_webgpu();
+        assert_eq!(selector.gpu_backend(), Backend::WebGpu);
+
+        // MoE routing keeps its thresholds but dispatches to WebGPU
+        assert_eq!(
+            selector.select_with_moe(OpComplexity::High, 50_000),
+            Backend::WebGpu
+        );
+        assert_eq!(
+            selector.select_with_moe(OpComplexity::Medium, 500_000),
+            Backend::WebGpu
+        );
+        assert_eq!(
+            selector.select_with_moe(OpComplexity::High, 5_000),
+            Backend::SIMD
+        );
+        assert_eq!(
+            selector.select_with_moe_bytes(OpComplexity::High, 50_000, 50_000 * 4),
+            Backend::WebGpu
+        );
+    }
+
+    #[test]
+    fn test_webgpu_cost_model_constants() {
+        let native = BackendSelector::new();
+        let webgpu = BackendSelector::new().with_webgpu();
+
+        // 1 MB transfer: native 31.25 μs, WebGPU 125 μs
+        // 1 GFLOP: native compute 50 μs (< 156 μs), WebGPU 500 μs (< 625 μs)
+        assert_eq!(
+            native.select_backend(1_000_000, 1_000_000_000),
+            Backend::SIMD
+        );
+        assert_eq!(
+            webgpu.select_backend(1_000_000, 1_000_000_000),
+            Backend::SIMD
+        );
+
+        // 2 GFLOP: native compute 100 μs (< 156 μs), WebGPU 1 ms (> 625 μs)
+        assert_eq!(
+            native.select_backend(1_000_000, 2_000_000_000),
+            Backend::SIMD
+        );
+        assert_eq!(
+            webgpu.select_backend(1_000_000, 2_000_000_000),
+            Backend::WebGpu
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "wasm", not(feature = "native")))]
+    fn test_wasm_default_selects_webgpu() {
+        let selector = BackendSelector::new();
+        assert_eq!(
+            selector.select_with_moe(OpComplexity::High, 50_000),
+            Backend::WebGpu
+        );
+    }
+
     #[test]
     #[cfg(feature = "trueno-integration")]
     #[ignore] // TODO: Fix Trueno API type inference issues
@@ -1030,6 +1954,7 @@ mod tests {
         assert_eq!(format!("{}", Backend::Scalar), "Scalar");
         assert_eq!(format!("{}", Backend::SIMD), "SIMD");
         assert_eq!(format!("{}", Backend::GPU), "GPU");
+        assert_eq!(format!("{}", Backend::WebGpu), "WebGPU");
     }
 
     #[test]
@@ -1068,7 +1993,12 @@ mod tests {
         assert_eq!(backend, deserialized);
 
         // Test all variants
-        for backend in &[Backend::Scalar, Backend::SIMD, Backend::GPU] {
+        for backend in &[
+            Backend::Scalar,
+            Backend::SIMD,
+            Backend::GPU,
+            Backend::WebGpu,
+        ] {
             let json = serde_json::to_string(backend).unwrap();
             let deserialized: Backend = serde_json::from_str(&json).unwrap();
             assert_eq!(*backend, deserialized);
@@ -1,8 +1,82 @@
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
+/// Current configuration schema version, written by `batuta init`
+pub const CONFIG_VERSION: &str = "1.0";
+
+/// Allowed values for `optimization.profile`
+pub const OPTIMIZATION_PROFILES: &[&str] = &["fast", "balanced", "aggressive"];
+
+/// Allowed values for `transpilation.ruchy_strictness`
+pub const RUCHY_STRICTNESS_LEVELS: &[&str] = &["permissive", "gradual", "strict"];
+
+/// Allowed values for `transpilation.bashrs.target_shell`
+pub const TARGET_SHELLS: &[&str] = &["bash", "sh", "zsh"];
+
+/// On-disk configuration format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    /// Infer from the file extension
+    Auto,
+    Toml,
+    Json,
+    Yaml,
+}
+
+impl ConfigFormat {
+    /// Resolve `Auto` from the extension of `path` (`toml`, `json`, `yaml`/`yml`)
+    pub fn resolve(self, path: &std::path::Path) -> anyhow::Result<Self> {
+        if self != ConfigFormat::Auto {
+            return Ok(self);
+        }
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("toml") => Ok(ConfigFormat::Toml),
+            Some("json") => Ok(ConfigFormat::Json),
+            Some("yaml" | "yml") => Ok(ConfigFormat::Yaml),
+            _ => anyhow::bail!(
+                "Cannot infer config format of {} (expected .toml, .json, .yaml or .yml)",
+                path.display()
+            ),
+        }
+    }
+}
+
+/// A single invalid configuration value
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{field}: {message}")]
+pub struct ConfigError {
+    /// Dotted path of the offending field (e.g. `optimization.profile`)
+    pub field: String,
+    /// What is wrong and how to fix it
+    pub message: String,
+}
+
+impl ConfigError {
+    fn new(field: &str, message: impl Into<String>) -> Self {
+        Self {
+            field: field.to_string(),
+            message: message.into(),
+        }
+    }
+
+    fn not_one_of(field: &str, value: &str, allowed: &[&str]) -> Self {
+        Self::new(
+            field,
+            format!("\"{}\" is not one of: {}", value, allowed.join(", ")),
+        )
+    }
+}
+
 /// Batuta project configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Every section defaults field by field, so configs written before a field
+/// existed still load; [`BatutaConfig::migrate`] handles the rest.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct BatutaConfig {
     /// Configuration file version
     pub version: String,
@@ -29,7 +103,7 @@ pub struct BatutaConfig {
 impl Default for BatutaConfig {
     fn default() -> Self {
         Self {
-            version: "1.0".to_string(),
+            version: CONFIG_VERSION.to_string(),
             project: ProjectConfig::default(),
             source: SourceConfig::default(),
             transpilation: TranspilationConfig::default(),
@@ -40,7 +114,8 @@ impl Default for BatutaConfig {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ProjectConfig {
     /// Project name
     pub name: String,
@@ -70,7 +145,8 @@ impl Default for ProjectConfig {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct SourceConfig {
     /// Source code directory (relative to config file)
     pub path: PathBuf,
@@ -102,7 +178,59 @@ impl Default for SourceConfig {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl SourceConfig {
+    /// Compile `exclude`/`include` into a reusable [`PathMatcher`]
+    ///
+    /// Patterns without a `/` (e.g. `target`, `*.pyc`) match any path
+    /// component; patterns with one match from the source root.
+    pub fn build_matcher(&self) -> anyhow::Result<PathMatcher> {
+        Ok(PathMatcher {
+            exclude: compile_patterns(&self.exclude)?,
+            include: compile_patterns(&self.include)?,
+        })
+    }
+}
+
+/// Compiled `SourceConfig` include/exclude patterns
+#[derive(Debug, Clone)]
+pub struct PathMatcher {
+    exclude: globset::GlobSet,
+    include: globset::GlobSet,
+}
+
+impl PathMatcher {
+    /// Whether `path` (relative to the source root) should be processed
+    ///
+    /// An `include` match always wins over an `exclude` match.
+    pub fn is_included(&self, path: &std::path::Path) -> bool {
+        self.include.is_match(path) || !self.exclude.is_match(path)
+    }
+}
+
+fn compile_patterns(patterns: &[String]) -> anyhow::Result<globset::GlobSet> {
+    let mut builder = globset::GlobSetBuilder::new();
+    for pattern in patterns {
+        let pattern = pattern.trim_end_matches('/');
+        let anchored = pattern.trim_start_matches("./");
+        // A match on a directory also covers everything below it
+        let variants = if pattern.contains('/') {
+            vec![anchored.to_string(), format!("{}/**", anchored)]
+        } else {
+            vec![format!("**/{}", pattern), format!("**/{}/**", pattern)]
+        };
+        for variant in variants {
+            let glob = globset::GlobBuilder::new(&variant)
+                .literal_separator(true)
+                .build()
+                .map_err(|e| anyhow::anyhow!("Invalid source pattern \"{}\": {}", pattern, e))?;
+            builder.add(glob);
+        }
+    }
+    Ok(builder.build()?)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TranspilationConfig {
     /// Output directory for generated Rust code
     pub output_dir: PathBuf,
@@ -144,7 +272,8 @@ impl Default for TranspilationConfig {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct DecyConfig {
     /// Enable ownership inference
     pub ownership_inference: bool,
@@ -166,7 +295,8 @@ impl Default for DecyConfig {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct DepylerConfig {
     /// Enable type inference
     pub type_inference: bool,
@@ -192,7 +322,8 @@ impl Default for DepylerConfig {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct BashrsConfig {
     /// Target shell compatibility
     pub target_shell: String,
@@ -210,7 +341,8 @@ impl Default for BashrsConfig {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct OptimizationConfig {
     /// Optimization profile (fast, balanced, aggressive)
     pub profile: String,
@@ -244,7 +376,45 @@ impl Default for OptimizationConfig {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl OptimizationConfig {
+    /// Set `profile` and the SIMD/GPU/MoE settings it implies
+    ///
+    /// - `fast`: SIMD only, low GPU threshold, no MoE routing
+    /// - `balanced`: the defaults
+    /// - `aggressive`: GPU and MoE routing on, high GPU threshold
+    ///
+    /// `trueno` backend preferences are left untouched.
+    pub fn apply_profile(&mut self, profile: &str) -> anyhow::Result<()> {
+        let (enable_simd, enable_gpu, gpu_threshold, use_moe_routing) = match profile {
+            "fast" => (true, false, 100, false),
+            "balanced" => {
+                let defaults = Self::default();
+                (
+                    defaults.enable_simd,
+                    defaults.enable_gpu,
+                    defaults.gpu_threshold,
+                    defaults.use_moe_routing,
+                )
+            }
+            "aggressive" => (true, true, 1000, true),
+            other => anyhow::bail!(
+                "Unknown optimization profile \"{}\" (expected one of: {})",
+                other,
+                OPTIMIZATION_PROFILES.join(", ")
+            ),
+        };
+
+        self.profile = profile.to_string();
+        self.enable_simd = enable_simd;
+        self.enable_gpu = enable_gpu;
+        self.gpu_threshold = gpu_threshold;
+        self.use_moe_routing = use_moe_routing;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TruenoConfig {
     /// Preferred backends in priority order
     pub backends: Vec<String>,
@@ -266,7 +436,8 @@ impl Default for TruenoConfig {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ValidationConfig {
     /// Enable syscall tracing
     pub trace_syscalls: bool,
@@ -296,7 +467,8 @@ impl Default for ValidationConfig {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct RenacerConfig {
     /// Syscalls to trace (empty = all)
     pub trace_syscalls: Vec<String>,
@@ -314,7 +486,8 @@ impl Default for RenacerConfig {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct BuildConfig {
     /// Build in release mode
     pub release: bool,
@@ -341,13 +514,114 @@ impl Default for BuildConfig {
 }
 
 impl BatutaConfig {
-    /// Load configuration from TOML file
+    /// Load configuration from TOML file, expanding path fields (see [`Self::expand`])
     pub fn load(path: &std::path::Path) -> anyhow::Result<Self> {
         let content = std::fs::read_to_string(path)?;
-        let config = toml::from_str(&content)?;
+        let mut config: Self = toml::from_str(&content)?;
+        config.prepare(path)?;
         Ok(config)
     }
 
+    /// Load `path`, then apply overrides from a sibling `*.local.toml` if present
+    ///
+    /// e.g. `batuta.toml` layered with `batuta.local.toml`. Paths are
+    /// expanded after merging.
+    pub fn load_layered(path: &std::path::Path) -> anyhow::Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        let mut config: Self = toml::from_str(&content)?;
+
+        let local_path = path.with_extension("local.toml");
+        if local_path.exists() {
+            config.merge(PartialBatutaConfig::load(&local_path)?);
+        }
+
+        config.prepare(path)?;
+        Ok(config)
+    }
+
+    /// Apply every field present in `other` on top of this config
+    pub fn merge(&mut self, other: PartialBatutaConfig) {
+        if let Some(version) = other.version {
+            self.version = version;
+        }
+        if let Some(project) = other.project {
+            self.project.merge(project);
+        }
+        if let Some(source) = other.source {
+            self.source.merge(source);
+        }
+        if let Some(transpilation) = other.transpilation {
+            self.transpilation.merge(transpilation);
+        }
+        if let Some(optimization) = other.optimization {
+            self.optimization.merge(optimization);
+        }
+        if let Some(validation) = other.validation {
+            self.validation.merge(validation);
+        }
+        if let Some(build) = other.build {
+            self.build.merge(build);
+        }
+    }
+
+    /// Post-load steps shared by every loader: migrate, then expand paths
+    fn prepare(&mut self, path: &std::path::Path) -> anyhow::Result<()> {
+        let original_version = self.version.clone();
+        self.migrate()?;
+        if self.version != original_version {
+            tracing::warn!(
+                "Migrated {} from config version {} to {}; run `batuta init` or re-save to update it",
+                path.display(),
+                original_version,
+                self.version
+            );
+        }
+        self.expand()
+    }
+
+    /// Upgrade a config written by an older batuta to [`CONFIG_VERSION`]
+    ///
+    /// Fields added since are already filled with defaults on load; this
+    /// rewrites values whose meaning changed. Unknown or newer versions are
+    /// an error.
+    pub fn migrate(&mut self) -> anyhow::Result<()> {
+        loop {
+            match self.version.as_str() {
+                CONFIG_VERSION => return Ok(()),
+                // 0.9 called the balanced profile "default" and left ruchy
+                // strictness unset, meaning gradual
+                "0.9" => {
+                    if self.optimization.profile == "default" {
+                        self.optimization.profile = "balanced".to_string();
+                    }
+                    if self.transpilation.ruchy_strictness.is_none() {
+                        self.transpilation.ruchy_strictness = Some("gradual".to_string());
+                    }
+                    self.version = "1.0".to_string();
+                }
+                other => anyhow::bail!(
+                    "Unsupported config version \"{}\" (this batuta reads up to {})",
+                    other,
+                    CONFIG_VERSION
+                ),
+            }
+        }
+    }
+
+    /// Resolve `${VAR}` and a leading `~` in path fields
+    ///
+    /// Applies to `source.path`, `transpilation.output_dir` and `build.target`.
+    /// An unset variable is an error rather than an empty string.
+    pub fn expand(&mut self) -> anyhow::Result<()> {
+        self.source.path = expand_path("source.path", &self.source.path)?;
+        self.transpilation.output_dir =
+            expand_path("transpilation.output_dir", &self.transpilation.output_dir)?;
+        if let Some(target) = &self.build.target {
+            self.build.target = Some(expand_str("build.target", target)?);
+        }
+        Ok(())
+    }
+
     /// Save configuration to TOML file
     pub fn save(&self, path: &std::path::Path) -> anyhow::Result<()> {
         let content = toml::to_string_pretty(self)?;
@@ -355,6 +629,84 @@ impl BatutaConfig {
         Ok(())
     }
 
+    /// Save configuration as TOML, JSON or YAML
+    pub fn save_as(&self, path: &std::path::Path, format: ConfigFormat) -> anyhow::Result<()> {
+        let content = match format.resolve(path)? {
+            ConfigFormat::Toml => toml::to_string_pretty(self)?,
+            ConfigFormat::Json => serde_json::to_string_pretty(self)?,
+            ConfigFormat::Yaml => serde_yaml::to_string(self)?,
+            ConfigFormat::Auto => unreachable!("resolve never returns Auto"),
+        };
+        std::fs::write(path, content)?;
+        Ok(())
+    }
+
+    /// Load configuration in the format given by the file extension
+    ///
+    /// Like [`Self::load`], path fields are expanded.
+    pub fn load_any(path: &std::path::Path) -> anyhow::Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        let mut config: Self = match ConfigFormat::Auto.resolve(path)? {
+            ConfigFormat::Toml => toml::from_str(&content)?,
+            ConfigFormat::Json => serde_json::from_str(&content)?,
+            ConfigFormat::Yaml => serde_yaml::from_str(&content)?,
+            ConfigFormat::Auto => unreachable!("resolve never returns Auto"),
+        };
+        config.prepare(path)?;
+        Ok(config)
+    }
+
+    /// Check enumerated fields and thresholds, reporting every problem at once
+    pub fn validate(&self) -> Result<(), Vec<ConfigError>> {
+        let mut errors = Vec::new();
+
+        if !OPTIMIZATION_PROFILES.contains(&self.optimization.profile.as_str()) {
+            errors.push(ConfigError::not_one_of(
+                "optimization.profile",
+                &self.optimization.profile,
+                OPTIMIZATION_PROFILES,
+            ));
+        }
+        if let Some(strictness) = &self.transpilation.ruchy_strictness {
+            if !RUCHY_STRICTNESS_LEVELS.contains(&strictness.as_str()) {
+                errors.push(ConfigError::not_one_of(
+                    "transpilation.ruchy_strictness",
+                    strictness,
+                    RUCHY_STRICTNESS_LEVELS,
+                ));
+            }
+        }
+        let shell = &self.transpilation.bashrs.target_shell;
+        if !TARGET_SHELLS.contains(&shell.as_str()) {
+            errors.push(ConfigError::not_one_of(
+                "transpilation.bashrs.target_shell",
+                shell,
+                TARGET_SHELLS,
+            ));
+        }
+
+        for (field, value) in [
+            (
+                "optimization.gpu_threshold",
+                self.optimization.gpu_threshold,
+            ),
+            (
+                "optimization.trueno.cpu_threshold",
+                self.optimization.trueno.cpu_threshold,
+            ),
+        ] {
+            if value == 0 {
+                errors.push(ConfigError::new(field, "must be greater than 0"));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
     /// Create a new config from project analysis
     pub fn from_analysis(analysis: &crate::types::ProjectAnalysis) -> Self {
         let mut config = Self::default();
@@ -380,6 +732,288 @@ impl BatutaConfig {
     }
 }
 
+// ============================================================================
+// LAYERED OVERRIDES
+// ============================================================================
+
+/// Replace each field of `$target` whose override in `$other` is present
+macro_rules! merge_fields {
+    ($target:expr, $other:expr; $($field:ident),* $(,)?) => {
+        $(if let Some(value) = $other.$field {
+            $target.$field = value;
+        })*
+    };
+}
+
+/// Like `merge_fields!`, for fields that are themselves optional
+macro_rules! merge_optional_fields {
+    ($target:expr, $other:expr; $($field:ident),* $(,)?) => {
+        $(if let Some(value) = $other.$field {
+            $target.$field = Some(value);
+        })*
+    };
+}
+
+/// Per-field overrides for [`BatutaConfig`]; see [`BatutaConfig::merge`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartialBatutaConfig {
+    pub version: Option<String>,
+    pub project: Option<PartialProjectConfig>,
+    pub source: Option<PartialSourceConfig>,
+    pub transpilation: Option<PartialTranspilationConfig>,
+    pub optimization: Option<PartialOptimizationConfig>,
+    pub validation: Option<PartialValidationConfig>,
+    pub build: Option<PartialBuildConfig>,
+}
+
+/// Overrides for [`ProjectConfig`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartialProjectConfig {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub primary_language: Option<String>,
+    pub authors: Option<Vec<String>>,
+    pub license: Option<String>,
+}
+
+/// Overrides for [`SourceConfig`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartialSourceConfig {
+    pub path: Option<PathBuf>,
+    pub exclude: Option<Vec<String>>,
+    pub include: Option<Vec<String>>,
+}
+
+/// Overrides for [`TranspilationConfig`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartialTranspilationConfig {
+    pub output_dir: Option<PathBuf>,
+    pub incremental: Option<bool>,
+    pub cache: Option<bool>,
+    pub use_ruchy: Option<bool>,
+    pub ruchy_strictness: Option<String>,
+    pub modules: Option<Vec<String>>,
+    pub decy: Option<PartialDecyConfig>,
+    pub depyler: Option<PartialDepylerConfig>,
+    pub bashrs: Option<PartialBashrsConfig>,
+}
+
+/// Overrides for [`DecyConfig`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartialDecyConfig {
+    pub ownership_inference: Option<bool>,
+    pub actionable_diagnostics: Option<bool>,
+    pub use_static_fixer: Option<bool>,
+}
+
+/// Overrides for [`DepylerConfig`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartialDepylerConfig {
+    pub type_inference: Option<bool>,
+    pub numpy_to_trueno: Option<bool>,
+    pub sklearn_to_aprender: Option<bool>,
+    pub pytorch_to_realizar: Option<bool>,
+}
+
+/// Overrides for [`BashrsConfig`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartialBashrsConfig {
+    pub target_shell: Option<String>,
+    pub use_clap: Option<bool>,
+}
+
+/// Overrides for [`OptimizationConfig`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartialOptimizationConfig {
+    pub profile: Option<String>,
+    pub enable_simd: Option<bool>,
+    pub enable_gpu: Option<bool>,
+    pub gpu_threshold: Option<usize>,
+    pub use_moe_routing: Option<bool>,
+    pub trueno: Option<PartialTruenoConfig>,
+}
+
+/// Overrides for [`TruenoConfig`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartialTruenoConfig {
+    pub backends: Option<Vec<String>>,
+    pub adaptive_thresholds: Option<bool>,
+    pub cpu_threshold: Option<usize>,
+}
+
+/// Overrides for [`ValidationConfig`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartialValidationConfig {
+    pub trace_syscalls: Option<bool>,
+    pub run_original_tests: Option<bool>,
+    pub diff_output: Option<bool>,
+    pub benchmark: Option<bool>,
+    pub renacer: Option<PartialRenacerConfig>,
+}
+
+/// Overrides for [`RenacerConfig`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartialRenacerConfig {
+    pub trace_syscalls: Option<Vec<String>>,
+    pub output_format: Option<String>,
+}
+
+/// Overrides for [`BuildConfig`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartialBuildConfig {
+    pub release: Option<bool>,
+    pub target: Option<String>,
+    pub wasm: Option<bool>,
+    pub cargo_flags: Option<Vec<String>>,
+}
+
+impl ProjectConfig {
+    fn merge(&mut self, other: PartialProjectConfig) {
+        merge_fields!(self, other; name, authors);
+        merge_optional_fields!(self, other; description, primary_language, license);
+    }
+}
+
+impl SourceConfig {
+    fn merge(&mut self, other: PartialSourceConfig) {
+        merge_fields!(self, other; path, exclude, include);
+    }
+}
+
+impl TranspilationConfig {
+    fn merge(&mut self, other: PartialTranspilationConfig) {
+        merge_fields!(self, other; output_dir, incremental, cache, use_ruchy, modules);
+        merge_optional_fields!(self, other; ruchy_strictness);
+        if let Some(decy) = other.decy {
+            self.decy.merge(decy);
+        }
+        if let Some(depyler) = other.depyler {
+            self.depyler.merge(depyler);
+        }
+        if let Some(bashrs) = other.bashrs {
+            self.bashrs.merge(bashrs);
+        }
+    }
+}
+
+impl DecyConfig {
+    fn merge(&mut self, other: PartialDecyConfig) {
+        merge_fields!(self, other; ownership_inference, actionable_diagnostics, use_static_fixer);
+    }
+}
+
+impl DepylerConfig {
+    fn merge(&mut self, other: PartialDepylerConfig) {
+        merge_fields!(self, other; type_inference, numpy_to_trueno, sklearn_to_aprender, pytorch_to_realizar);
+    }
+}
+
+impl BashrsConfig {
+    fn merge(&mut self, other: PartialBashrsConfig) {
+        merge_fields!(self, other; target_shell, use_clap);
+    }
+}
+
+impl OptimizationConfig {
+    fn merge(&mut self, other: PartialOptimizationConfig) {
+        merge_fields!(self, other; profile, enable_simd, enable_gpu, gpu_threshold, use_moe_routing);
+        if let Some(trueno) = other.trueno {
+            self.trueno.merge(trueno);
+        }
+    }
+}
+
+impl TruenoConfig {
+    fn merge(&mut self, other: PartialTruenoConfig) {
+        merge_fields!(self, other; backends, adaptive_thresholds, cpu_threshold);
+    }
+}
+
+impl ValidationConfig {
+    fn merge(&mut self, other: PartialValidationConfig) {
+        merge_fields!(self, other; trace_syscalls, run_original_tests, diff_output, benchmark);
+        if let Some(renacer) = other.renacer {
+            self.renacer.merge(renacer);
+        }
+    }
+}
+
+impl RenacerConfig {
+    fn merge(&mut self, other: PartialRenacerConfig) {
+        merge_fields!(self, other; trace_syscalls, output_format);
+    }
+}
+
+impl BuildConfig {
+    fn merge(&mut self, other: PartialBuildConfig) {
+        merge_fields!(self, other; release, wasm, cargo_flags);
+        merge_optional_fields!(self, other; target);
+    }
+}
+
+impl PartialBatutaConfig {
+    /// Load overrides from a TOML file; absent keys leave the base untouched
+    pub fn load(path: &std::path::Path) -> anyhow::Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(toml::from_str(&content)?)
+    }
+}
+
+fn expand_path(field: &str, path: &std::path::Path) -> anyhow::Result<PathBuf> {
+    match path.to_str() {
+        Some(path) => Ok(PathBuf::from(expand_str(field, path)?)),
+        // Non-UTF-8 paths can't contain placeholders we understand
+        None => Ok(path.to_path_buf()),
+    }
+}
+
+/// Expand a leading `~` and every `${VAR}` in `value`
+fn expand_str(field: &str, value: &str) -> anyhow::Result<String> {
+    let mut rest = value;
+    let mut expanded = String::with_capacity(value.len());
+
+    if rest == "~" || rest.starts_with("~/") {
+        let home = std::env::var("HOME")
+            .map_err(|_| anyhow::anyhow!("{}: cannot expand `~`, HOME is not set", field))?;
+        expanded.push_str(&home);
+        rest = &rest[1..];
+    }
+
+    while let Some(start) = rest.find("${") {
+        expanded.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let Some(end) = after.find('}') else {
+            anyhow::bail!("{}: unterminated `${{` in \"{}\"", field, value);
+        };
+        let name = &after[..end];
+        let var = std::env::var(name).map_err(|_| {
+            anyhow::anyhow!(
+                "{}: environment variable ${{{}}} is not set (in \"{}\")",
+                field,
+                name,
+                value
+            )
+        })?;
+        expanded.push_str(&var);
+        rest = &after[end + 1..];
+    }
+    expanded.push_str(rest);
+
+    Ok(expanded)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -871,6 +1505,436 @@ mod tests {
         assert!(!config.transpilation.incremental);
     }
 
+    // ============================================================================
+    // PATH MATCHER TESTS
+    // ============================================================================
+
+    #[test]
+    fn test_matcher_default_excludes() {
+        let matcher = SourceConfig::default().build_matcher().unwrap();
+
+        assert!(!matcher.is_included(std::path::Path::new("target/foo.rs")));
+        assert!(!matcher.is_included(std::path::Path::new("pkg/node_modules/x.js")));
+        assert!(!matcher.is_included(std::path::Path::new("src/module.pyc")));
+        assert!(!matcher.is_included(std::path::Path::new("__pycache__")));
+        assert!(matcher.is_included(std::path::Path::new("src/main.py")));
+        // Names merely containing an excluded word are kept
+        assert!(matcher.is_included(std::path::Path::new("src/target_utils.py")));
+    }
+
+    #[test]
+    fn test_matcher_include_overrides_exclude() {
+        let config = SourceConfig {
+            include: vec!["target/generated/*.rs".to_string()],
+            ..Default::default()
+        };
+        let matcher = config.build_matcher().unwrap();
+
+        assert!(matcher.is_included(std::path::Path::new("target/generated/api.rs")));
+        assert!(!matcher.is_included(std::path::Path::new("target/foo.rs")));
+        assert!(!matcher.is_included(std::path::Path::new("target/generated/deep/x.rs")));
+    }
+
+    #[test]
+    fn test_matcher_invalid_pattern() {
+        let config = SourceConfig {
+            exclude: vec!["src/[".to_string()],
+            ..Default::default()
+        };
+
+        let err = config.build_matcher().unwrap_err().to_string();
+        assert!(err.contains("src/["), "{}", err);
+    }
+
+    // ============================================================================
+    // PROFILE PRESET TESTS
+    // ============================================================================
+
+    #[test]
+    fn test_apply_profile_aggressive() {
+        let mut config = OptimizationConfig::default();
+        config.apply_profile("aggressive").unwrap();
+
+        assert_eq!(config.profile, "aggressive");
+        assert!(config.enable_gpu);
+        assert!(config.use_moe_routing);
+        assert!(config.gpu_threshold > OptimizationConfig::default().gpu_threshold);
+    }
+
+    #[test]
+    fn test_apply_profile_fast() {
+        let mut config = OptimizationConfig {
+            enable_gpu: true,
+            ..Default::default()
+        };
+        config.apply_profile("fast").unwrap();
+
+        assert_eq!(config.profile, "fast");
+        assert!(config.enable_simd);
+        assert!(!config.enable_gpu);
+        assert!(!config.use_moe_routing);
+        assert!(config.gpu_threshold < OptimizationConfig::default().gpu_threshold);
+    }
+
+    #[test]
+    fn test_apply_profile_balanced_restores_defaults() {
+        let mut config = OptimizationConfig::default();
+        config.trueno.cpu_threshold = 42;
+        config.apply_profile("aggressive").unwrap();
+        config.apply_profile("balanced").unwrap();
+
+        let expected = OptimizationConfig {
+            trueno: config.trueno.clone(),
+            ..OptimizationConfig::default()
+        };
+        assert_eq!(config, expected);
+        assert_eq!(config.trueno.cpu_threshold, 42);
+    }
+
+    #[test]
+    fn test_apply_profile_unknown() {
+        let mut config = OptimizationConfig::default();
+        let err = config.apply_profile("ludicrous").unwrap_err().to_string();
+
+        assert!(err.contains("ludicrous"), "{}", err);
+        assert_eq!(config, OptimizationConfig::default());
+    }
+
+    // ============================================================================
+    // MIGRATION TESTS
+    // ============================================================================
+
+    #[test]
+    fn test_load_migrates_0_9_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("batuta.toml");
+        // A 0.9 config: no trueno/renacer sections, old profile name
+        std::fs::write(
+            &config_path,
+            r#"
+version = "0.9"
+
+[project]
+name = "legacy"
+
+[optimization]
+profile = "default"
+enable_gpu = true
+"#,
+        )
+        .unwrap();
+
+        let config = BatutaConfig::load(&config_path).unwrap();
+
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!(config.project.name, "legacy");
+        assert_eq!(config.optimization.profile, "balanced");
+        assert!(config.optimization.enable_gpu);
+        assert_eq!(
+            config.transpilation.ruchy_strictness,
+            Some("gradual".to_string())
+        );
+        assert_eq!(config.optimization.trueno, TruenoConfig::default());
+        assert_eq!(config.validation, ValidationConfig::default());
+        assert_eq!(config.build, BuildConfig::default());
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_migrate_current_is_noop() {
+        let mut config = BatutaConfig::default();
+        config.migrate().unwrap();
+        assert_eq!(config, BatutaConfig::default());
+    }
+
+    #[test]
+    fn test_migrate_unknown_version_is_error() {
+        let mut config = BatutaConfig {
+            version: "7.0".to_string(),
+            ..Default::default()
+        };
+
+        let err = config.migrate().unwrap_err().to_string();
+        assert!(
+            err.contains("Unsupported config version \"7.0\""),
+            "{}",
+            err
+        );
+    }
+
+    // ============================================================================
+    // FORMAT TESTS
+    // ============================================================================
+
+    #[test]
+    fn test_round_trip_each_format() {
+        let temp_dir = TempDir::new().unwrap();
+
+        for name in ["batuta.toml", "batuta.json", "batuta.yaml", "batuta.yml"] {
+            let path = temp_dir.path().join(name);
+            BatutaConfig::default()
+                .save_as(&path, ConfigFormat::Auto)
+                .unwrap();
+
+            let loaded = BatutaConfig::load_any(&path).unwrap();
+            assert_eq!(loaded, BatutaConfig::default(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_save_as_explicit_format() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("batuta.toml");
+
+        // An explicit format wins over the extension
+        BatutaConfig::default()
+            .save_as(&path, ConfigFormat::Json)
+            .unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(value["optimization"]["profile"], "balanced");
+    }
+
+    #[test]
+    fn test_unknown_extension_is_error() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("batuta.ini");
+
+        let err = BatutaConfig::default()
+            .save_as(&path, ConfigFormat::Auto)
+            .unwrap_err();
+        assert!(err.to_string().contains("Cannot infer config format"));
+        assert!(BatutaConfig::load_any(&path).is_err());
+    }
+
+    // ============================================================================
+    // PATH EXPANSION TESTS
+    // ============================================================================
+
+    #[test]
+    fn test_expand_env_vars() {
+        std::env::set_var("BATUTA_TEST_EXPAND_ROOT", "/opt/work");
+        let mut config = BatutaConfig::default();
+        config.source.path = PathBuf::from("${BATUTA_TEST_EXPAND_ROOT}/src");
+        config.transpilation.output_dir = PathBuf::from("${BATUTA_TEST_EXPAND_ROOT}/out");
+        config.build.target = Some("${BATUTA_TEST_EXPAND_ROOT}-target".to_string());
+
+        config.expand().unwrap();
+
+        assert_eq!(config.source.path, PathBuf::from("/opt/work/src"));
+        assert_eq!(
+            config.transpilation.output_dir,
+            PathBuf::from("/opt/work/out")
+        );
+        assert_eq!(config.build.target, Some("/opt/work-target".to_string()));
+    }
+
+    #[test]
+    fn test_expand_home_tilde() {
+        let home = std::env::var("HOME").unwrap();
+        let mut config = BatutaConfig::default();
+        config.transpilation.output_dir = PathBuf::from("~/rust-output");
+        // Only a leading tilde is special
+        config.source.path = PathBuf::from("src/~backup");
+
+        config.expand().unwrap();
+
+        assert_eq!(
+            config.transpilation.output_dir,
+            PathBuf::from(format!("{}/rust-output", home))
+        );
+        assert_eq!(config.source.path, PathBuf::from("src/~backup"));
+    }
+
+    #[test]
+    fn test_expand_undefined_var_is_error() {
+        let mut config = BatutaConfig::default();
+        config.source.path = PathBuf::from("${BATUTA_TEST_UNDEFINED_VAR}/src");
+
+        let err = config.expand().unwrap_err().to_string();
+
+        assert!(err.contains("source.path"), "{}", err);
+        assert!(err.contains("${BATUTA_TEST_UNDEFINED_VAR}"), "{}", err);
+    }
+
+    #[test]
+    fn test_expand_unterminated_placeholder() {
+        let err = expand_str("source.path", "${HOME/src").unwrap_err();
+        assert!(err.to_string().contains("unterminated"));
+    }
+
+    #[test]
+    fn test_load_expands_paths() {
+        std::env::set_var("BATUTA_TEST_LOAD_OUT", "/tmp/batuta-out");
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("batuta.toml");
+        let mut config = BatutaConfig::default();
+        config.transpilation.output_dir = PathBuf::from("${BATUTA_TEST_LOAD_OUT}");
+        config.save(&config_path).unwrap();
+
+        let loaded = BatutaConfig::load(&config_path).unwrap();
+
+        assert_eq!(
+            loaded.transpilation.output_dir,
+            PathBuf::from("/tmp/batuta-out")
+        );
+    }
+
+    // ============================================================================
+    // MERGE TESTS
+    // ============================================================================
+
+    #[test]
+    fn test_merge_only_profile() {
+        let mut config = BatutaConfig::default();
+        config.project.name = "base".to_string();
+        config.optimization.gpu_threshold = 2000;
+        let before = config.clone();
+
+        let overrides: PartialBatutaConfig = toml::from_str(
+            r#"
+            [optimization]
+            profile = "aggressive"
+            "#,
+        )
+        .unwrap();
+        config.merge(overrides);
+
+        assert_eq!(config.optimization.profile, "aggressive");
+        assert_eq!(config.optimization.gpu_threshold, 2000);
+        assert_eq!(
+            config.optimization.enable_simd,
+            before.optimization.enable_simd
+        );
+        assert_eq!(config.project.name, "base");
+        assert_eq!(config.source.exclude, before.source.exclude);
+        assert_eq!(
+            config.transpilation.output_dir,
+            before.transpilation.output_dir
+        );
+        assert_eq!(config.build.release, before.build.release);
+    }
+
+    #[test]
+    fn test_merge_nested_and_optional_fields() {
+        let mut config = BatutaConfig::default();
+
+        config.merge(PartialBatutaConfig {
+            transpilation: Some(PartialTranspilationConfig {
+                ruchy_strictness: Some("strict".to_string()),
+                bashrs: Some(PartialBashrsConfig {
+                    target_shell: Some("zsh".to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            build: Some(PartialBuildConfig {
+                target: Some("wasm32-unknown-unknown".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            config.transpilation.ruchy_strictness,
+            Some("strict".to_string())
+        );
+        assert_eq!(config.transpilation.bashrs.target_shell, "zsh");
+        assert!(config.transpilation.bashrs.use_clap);
+        assert_eq!(
+            config.build.target,
+            Some("wasm32-unknown-unknown".to_string())
+        );
+    }
+
+    #[test]
+    fn test_merge_empty_is_noop() {
+        let mut config = BatutaConfig::default();
+        config.merge(PartialBatutaConfig::default());
+
+        assert_eq!(
+            toml::to_string(&config).unwrap(),
+            toml::to_string(&BatutaConfig::default()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_load_layered_applies_local_overrides() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("batuta.toml");
+        let mut base = BatutaConfig::default();
+        base.project.name = "layered".to_string();
+        base.save(&config_path).unwrap();
+
+        // Without a local file the base is loaded as-is
+        let loaded = BatutaConfig::load_layered(&config_path).unwrap();
+        assert_eq!(loaded.optimization.profile, "balanced");
+
+        std::fs::write(
+            temp_dir.path().join("batuta.local.toml"),
+            "[optimization]\nenable_gpu = true\n",
+        )
+        .unwrap();
+        let loaded = BatutaConfig::load_layered(&config_path).unwrap();
+
+        assert!(loaded.optimization.enable_gpu);
+        assert_eq!(loaded.project.name, "layered");
+    }
+
+    // ============================================================================
+    // VALIDATION TESTS
+    // ============================================================================
+
+    #[test]
+    fn test_validate_default_config() {
+        assert_eq!(BatutaConfig::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_accepts_all_allowed_values() {
+        let mut config = BatutaConfig::default();
+        config.transpilation.ruchy_strictness = None;
+        assert!(config.validate().is_ok());
+
+        for profile in OPTIMIZATION_PROFILES {
+            config.optimization.profile = profile.to_string();
+            assert!(config.validate().is_ok());
+        }
+        for shell in TARGET_SHELLS {
+            config.transpilation.bashrs.target_shell = shell.to_string();
+            assert!(config.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_validate_reports_all_errors() {
+        let mut config = BatutaConfig::default();
+        config.optimization.gpu_threshold = 0;
+        config.transpilation.ruchy_strictness = Some("banana".to_string());
+        config.transpilation.bashrs.target_shell = "fish".to_string();
+
+        let errors = config.validate().unwrap_err();
+
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec![
+                "transpilation.ruchy_strictness",
+                "transpilation.bashrs.target_shell",
+                "optimization.gpu_threshold",
+            ]
+        );
+        assert_eq!(
+            errors[0].to_string(),
+            "transpilation.ruchy_strictness: \"banana\" is not one of: permissive, gradual, strict"
+        );
+        assert_eq!(
+            errors[2].to_string(),
+            "optimization.gpu_threshold: must be greater than 0"
+        );
+    }
+
     #[test]
     fn test_config_clone() {
         let config = BatutaConfig::default();
@@ -10,6 +10,7 @@
 //! - BCH: Book Chapter (mdBook)
 //! - BLP: Blog Post
 //! - PDM: Presentar Demo
+//! - API: API Reference
 //!
 //! # Toyota Way Integration
 //! - Jidoka: LLM-as-a-Judge validation
@@ -19,11 +20,19 @@
 //! - Kaizen: Dynamic template composition
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::ops::Range;
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::sync::Arc;
 use thiserror::Error;
 
+#[cfg(feature = "native")]
+mod tokenizer;
+#[cfg(feature = "native")]
+#[allow(unused_imports)]
+pub use tokenizer::{default_counter, BpeTokenizer, EstimateCounter, TokenCounter};
+
 // ============================================================================
 // ERRORS
 // ============================================================================
@@ -34,6 +43,9 @@ pub enum ContentError {
     #[error("Invalid content type: {0}")]
     InvalidContentType(String),
 
+    #[error("Unknown model: {0}")]
+    UnknownModel(String),
+
     #[error("Template not found: {0}")]
     TemplateNotFound(String),
 
@@ -73,6 +85,8 @@ pub enum ContentType {
     BlogPost,
     /// Presentar Demo (PDM) - Interactive WASM demos
     PresentarDemo,
+    /// API Reference (API) - Per-item reference documentation
+    ApiReference,
 }
 
 /// Course level configuration for detailed outlines
@@ -152,6 +166,7 @@ impl ContentType {
             ContentType::BookChapter => "BCH",
             ContentType::BlogPost => "BLP",
             ContentType::PresentarDemo => "PDM",
+            ContentType::ApiReference => "API",
         }
     }
 
@@ -163,6 +178,7 @@ impl ContentType {
             ContentType::BookChapter => "Book Chapter",
             ContentType::BlogPost => "Blog Post",
             ContentType::PresentarDemo => "Presentar Demo",
+            ContentType::ApiReference => "API Reference",
         }
     }
 
@@ -174,6 +190,7 @@ impl ContentType {
             ContentType::BookChapter => "Markdown (mdBook)",
             ContentType::BlogPost => "Markdown + TOML",
             ContentType::PresentarDemo => "HTML + YAML",
+            ContentType::ApiReference => "Markdown (rustdoc-style)",
         }
     }
 
@@ -185,6 +202,16 @@ impl ContentType {
             ContentType::BookChapter => 2000..8000,    // words
             ContentType::BlogPost => 500..3000,        // words
             ContentType::PresentarDemo => 0..0,        // N/A
+            ContentType::ApiReference => 1000..6000,   // words
+        }
+    }
+
+    /// Get the unit of the target length range
+    pub fn length_unit(&self) -> &'static str {
+        match self {
+            ContentType::HighLevelOutline | ContentType::DetailedOutline => "lines",
+            ContentType::BookChapter | ContentType::BlogPost | ContentType::ApiReference => "words",
+            ContentType::PresentarDemo => "n/a",
         }
     }
 
@@ -196,8 +223,30 @@ impl ContentType {
             ContentType::BookChapter,
             ContentType::BlogPost,
             ContentType::PresentarDemo,
+            ContentType::ApiReference,
         ]
     }
+
+    /// Export the content-type catalog as JSON for tooling and docs
+    pub fn catalog_json() -> serde_json::Value {
+        let entries: Vec<serde_json::Value> = Self::all()
+            .iter()
+            .map(|ct| {
+                let range = ct.target_length();
+                serde_json::json!({
+                    "name": ct.name(),
+                    "code": ct.code(),
+                    "output_format": ct.output_format(),
+                    "target_length": {
+                        "min": range.start,
+                        "max": range.end,
+                    },
+                    "unit": ct.length_unit(),
+                })
+            })
+            .collect();
+        serde_json::Value::Array(entries)
+    }
 }
 
 impl FromStr for ContentType {
@@ -210,6 +259,7 @@ impl FromStr for ContentType {
             "bch" | "book-chapter" | "chapter" => Ok(ContentType::BookChapter),
             "blp" | "blog-post" | "blog" => Ok(ContentType::BlogPost),
             "pdm" | "presentar-demo" | "demo" => Ok(ContentType::PresentarDemo),
+            "api" | "api-reference" | "reference" => Ok(ContentType::ApiReference),
             _ => Err(ContentError::InvalidContentType(s.to_string())),
         }
     }
@@ -227,12 +277,20 @@ pub enum ModelContext {
     Claude200K,
     /// Claude Haiku (200K)
     ClaudeHaiku,
+    /// Claude 3.5 Sonnet (200K)
+    Claude35Sonnet,
     /// Gemini Pro (1M)
     GeminiPro,
     /// Gemini Flash (1M)
     GeminiFlash,
+    /// Gemini 1.5 Pro (1M)
+    Gemini15Pro,
+    /// Gemini 1.5 Flash (1M)
+    Gemini15Flash,
     /// GPT-4 Turbo (128K)
     Gpt4Turbo,
+    /// GPT-4o (128K)
+    Gpt4o,
     /// Custom context window
     Custom(usize),
 }
@@ -243,9 +301,13 @@ impl ModelContext {
         match self {
             ModelContext::Claude200K => 200_000,
             ModelContext::ClaudeHaiku => 200_000,
+            ModelContext::Claude35Sonnet => 200_000,
             ModelContext::GeminiPro => 1_000_000,
             ModelContext::GeminiFlash => 1_000_000,
+            ModelContext::Gemini15Pro => 1_000_000,
+            ModelContext::Gemini15Flash => 1_000_000,
             ModelContext::Gpt4Turbo => 128_000,
+            ModelContext::Gpt4o => 128_000,
             ModelContext::Custom(size) => *size,
         }
     }
@@ -255,14 +317,48 @@ impl ModelContext {
         match self {
             ModelContext::Claude200K => "claude-sonnet",
             ModelContext::ClaudeHaiku => "claude-haiku",
+            ModelContext::Claude35Sonnet => "claude-3.5-sonnet",
             ModelContext::GeminiPro => "gemini-pro",
             ModelContext::GeminiFlash => "gemini-flash",
+            ModelContext::Gemini15Pro => "gemini-1.5-pro",
+            ModelContext::Gemini15Flash => "gemini-1.5-flash",
             ModelContext::Gpt4Turbo => "gpt-4-turbo",
+            ModelContext::Gpt4o => "gpt-4o",
             ModelContext::Custom(_) => "custom",
         }
     }
 }
 
+impl FromStr for ModelContext {
+    type Err = ContentError;
+
+    /// Parse a model name, or `custom:<tokens>` for an explicit window
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_lowercase();
+        if let Some(size) = name.strip_prefix("custom:") {
+            return size
+                .parse()
+                .map(ModelContext::Custom)
+                .map_err(|_| ContentError::UnknownModel(s.to_string()));
+        }
+        match name.as_str() {
+            "claude-sonnet" | "claude-opus" | "claude" => Ok(ModelContext::Claude200K),
+            "claude-haiku" | "haiku" => Ok(ModelContext::ClaudeHaiku),
+            "claude-3.5-sonnet" | "claude-3-5-sonnet" => Ok(ModelContext::Claude35Sonnet),
+            "gemini-pro" | "gemini" => Ok(ModelContext::GeminiPro),
+            "gemini-flash" => Ok(ModelContext::GeminiFlash),
+            "gemini-1.5-pro" | "gemini-1-5-pro" => Ok(ModelContext::Gemini15Pro),
+            "gemini-1.5-flash" | "gemini-1-5-flash" => Ok(ModelContext::Gemini15Flash),
+            "gpt-4-turbo" | "gpt4-turbo" => Ok(ModelContext::Gpt4Turbo),
+            "gpt-4o" | "gpt4o" => Ok(ModelContext::Gpt4o),
+            _ => Err(ContentError::UnknownModel(s.to_string())),
+        }
+    }
+}
+
+/// Output tokens [`TokenBudget::fit`] will not trim below
+pub const MIN_OUTPUT_TOKENS: usize = 2_000;
+
 /// Token budget calculation (spec section 5.4)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TokenBudget {
@@ -335,6 +431,52 @@ impl TokenBudget {
         }
     }
 
+    /// Shrink the budget until it fits the context window
+    ///
+    /// Source and RAG context are trimmed proportionally first; only if both
+    /// are exhausted is the output target reduced, never below
+    /// [`MIN_OUTPUT_TOKENS`] (or its current value if already lower). Fails
+    /// without modifying the budget if even that does not fit.
+    pub fn fit(&mut self) -> Result<BudgetTrim, ContentError> {
+        let total = self.prompt_tokens() + self.output_target;
+        if total <= self.context_window {
+            return Ok(BudgetTrim::default());
+        }
+        let excess = total - self.context_window;
+
+        let output_floor = self.output_target.min(MIN_OUTPUT_TOKENS);
+        let floor = self.system_reserve + self.few_shot + output_floor;
+        if floor > self.context_window {
+            return Err(ContentError::TokenBudgetExceeded {
+                used: floor,
+                limit: self.context_window,
+            });
+        }
+
+        let context = self.source_context + self.rag_context;
+        let trim = if excess <= context {
+            let source = (excess * self.source_context)
+                .div_ceil(context)
+                .min(self.source_context);
+            BudgetTrim {
+                source_context: source,
+                rag_context: excess - source,
+                output_target: 0,
+            }
+        } else {
+            BudgetTrim {
+                source_context: self.source_context,
+                rag_context: self.rag_context,
+                output_target: excess - context,
+            }
+        };
+
+        self.source_context -= trim.source_context;
+        self.rag_context -= trim.rag_context;
+        self.output_target -= trim.output_target;
+        Ok(trim)
+    }
+
     /// Estimate tokens from word count (rough: 1 word ≈ 1.3 tokens)
     pub fn words_to_tokens(words: usize) -> usize {
         (words as f64 * 1.3).ceil() as usize
@@ -390,6 +532,24 @@ impl Default for TokenBudget {
     }
 }
 
+/// Tokens removed from each allocation by [`TokenBudget::fit`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BudgetTrim {
+    /// Trimmed from source context
+    pub source_context: usize,
+    /// Trimmed from RAG context
+    pub rag_context: usize,
+    /// Trimmed from the output target
+    pub output_target: usize,
+}
+
+impl BudgetTrim {
+    /// Total tokens trimmed
+    pub fn total(&self) -> usize {
+        self.source_context + self.rag_context + self.output_target
+    }
+}
+
 // ============================================================================
 // SOURCE CONTEXT (Genchi Genbutsu)
 // ============================================================================
@@ -414,7 +574,7 @@ pub struct SourceSnippet {
     pub lines: Option<(usize, usize)>,
     /// Content
     pub content: String,
-    /// Estimated tokens
+    /// Token count (exact with an installed cl100k vocabulary, else estimated)
     pub tokens: usize,
 }
 
@@ -435,6 +595,71 @@ impl SourceContext {
         self.snippets.push(snippet);
     }
 
+    /// Read a file (or a 1-indexed, inclusive line range of it) as a snippet
+    ///
+    /// Line numbers past either end of the file are clamped to it.
+    pub fn add_file(
+        &mut self,
+        path: impl Into<PathBuf>,
+        lines: Option<(usize, usize)>,
+    ) -> Result<(), ContentError> {
+        let path = path.into();
+        let text = std::fs::read_to_string(&path).map_err(|e| {
+            ContentError::SourceContextError(format!(
+                "Failed to read source file {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        let (content, lines) = match lines {
+            Some((start, end)) => {
+                let all: Vec<&str> = text.lines().collect();
+                let last = all.len().max(1);
+                let start = start.clamp(1, last);
+                let end = end.clamp(start, last);
+                let content = all.get(start - 1..end).unwrap_or_default().join("\n");
+                (content, Some((start, end)))
+            }
+            None => (text, None),
+        };
+
+        let tokens = Self::snippet_tokens(&content);
+        self.add_snippet(SourceSnippet {
+            path,
+            lines,
+            content,
+            tokens,
+        });
+        Ok(())
+    }
+
+    #[cfg(feature = "native")]
+    fn snippet_tokens(content: &str) -> usize {
+        default_counter().count_tokens(content)
+    }
+
+    #[cfg(not(feature = "native"))]
+    fn snippet_tokens(content: &str) -> usize {
+        TokenBudget::words_to_tokens(content.split_whitespace().count())
+    }
+
+    /// Recount every snippet's tokens with exact cl100k BPE
+    /// ([`BpeTokenizer::cl100k`])
+    #[cfg(feature = "native")]
+    pub fn count_tokens(&mut self) {
+        self.count_tokens_with(default_counter());
+    }
+
+    /// Recount every snippet's tokens with `counter` (e.g. a [`BpeTokenizer`])
+    #[cfg(feature = "native")]
+    pub fn count_tokens_with(&mut self, counter: &dyn TokenCounter) {
+        for snippet in &mut self.snippets {
+            snippet.tokens = counter.count_tokens(&snippet.content);
+        }
+        self.total_tokens = self.snippets.iter().map(|s| s.tokens).sum();
+    }
+
     /// Format for inclusion in prompt
     pub fn format_for_prompt(&self) -> String {
         if self.snippets.is_empty() {
@@ -490,6 +715,27 @@ pub struct ValidationViolation {
     pub text: String,
     /// Suggested fix
     pub suggestion: String,
+    /// Concrete replacement text, when the fix is mechanical
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub autofix: Option<String>,
+}
+
+/// Change in validation results relative to a baseline
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ValidationDelta {
+    /// Violations not present in the baseline
+    pub new_violations: Vec<ValidationViolation>,
+    /// Number of baseline violations that no longer occur
+    pub resolved_count: usize,
+    /// Score difference (positive means improved)
+    pub score_change: i16,
+}
+
+impl ValidationDelta {
+    /// True when new violations appeared or the score dropped
+    pub fn is_regression(&self) -> bool {
+        !self.new_violations.is_empty() || self.score_change < 0
+    }
 }
 
 /// Validation result from content validation
@@ -563,6 +809,41 @@ impl ValidationResult {
             .any(|v| v.severity == ValidationSeverity::Error)
     }
 
+    /// Violations that carry a concrete autofix
+    pub fn autofixable(&self) -> Vec<&ValidationViolation> {
+        self.violations
+            .iter()
+            .filter(|v| v.autofix.is_some())
+            .collect()
+    }
+
+    /// Compare against a baseline result (e.g. from the main branch)
+    ///
+    /// Violations are matched on constraint and text rather than location, so
+    /// unrelated edits that shift line numbers don't count as new violations.
+    pub fn diff(&self, baseline: &ValidationResult) -> ValidationDelta {
+        let key = |v: &ValidationViolation| (v.constraint.clone(), v.text.clone());
+        let mut remaining: HashMap<(String, String), usize> = HashMap::new();
+        for v in &baseline.violations {
+            *remaining.entry(key(v)).or_default() += 1;
+        }
+
+        let mut new_violations = Vec::new();
+        for v in &self.violations {
+            match remaining.get_mut(&key(v)) {
+                Some(count) if *count > 0 => *count -= 1,
+                _ => new_violations.push(v.clone()),
+            }
+        }
+        let resolved_count = remaining.values().sum();
+
+        ValidationDelta {
+            new_violations,
+            resolved_count,
+            score_change: i16::from(self.score) - i16::from(baseline.score),
+        }
+    }
+
     /// Format as display string
     pub fn format_display(&self) -> String {
         let mut output = String::new();
@@ -592,23 +873,105 @@ impl ValidationResult {
             ));
             output.push_str(&format!("    Text: \"{}\"\n", v.text));
             output.push_str(&format!("    Fix: {}\n", v.suggestion));
+            if let Some(autofix) = &v.autofix {
+                output.push_str(&format!("    Autofix: {}\n", autofix.replace('\n', "\\n")));
+            }
         }
 
         output
     }
 }
 
+/// A user-defined validation check run alongside the built-in rules
+pub trait ValidationRule: Send + Sync {
+    /// Return every violation of this rule found in `content`
+    fn check(&self, content: &str) -> Vec<ValidationViolation>;
+}
+
+/// Opt-in rule: flag headings with no introductory paragraph
+///
+/// A heading followed directly by a subheading or a code block leaves the
+/// reader without an introduction to the section. Not run by default, as
+/// `# Title` followed by `## Section` is a common layout; enable it with
+/// `ContentValidator::new(..).with_rule(SectionIntroRule)`. Violations are
+/// `Info` hints with no autofix, since the intro has to be written.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SectionIntroRule;
+
+impl ValidationRule for SectionIntroRule {
+    fn check(&self, content: &str) -> Vec<ValidationViolation> {
+        let lines: Vec<&str> = content.lines().collect();
+        let mut in_code_block = false;
+        let mut violations = Vec::new();
+
+        for (line_num, line) in lines.iter().enumerate() {
+            if line.trim().starts_with("```") {
+                in_code_block = !in_code_block;
+                continue;
+            }
+            if in_code_block {
+                continue;
+            }
+            let Some(level) = heading_level(line) else {
+                continue;
+            };
+
+            let next = lines[line_num + 1..]
+                .iter()
+                .map(|l| l.trim())
+                .find(|l| !l.is_empty());
+            let missing_intro = next.is_some_and(|next| {
+                next.starts_with("```") || heading_level(next).is_some_and(|l| l > level)
+            });
+            if missing_intro {
+                violations.push(ValidationViolation {
+                    constraint: "section_intro".to_string(),
+                    severity: ValidationSeverity::Info,
+                    location: format!("line {}", line_num + 1),
+                    text: line.trim().to_string(),
+                    suggestion: format!(
+                        "Add an introductory paragraph under \"{}\" before its first subsection or code block",
+                        line.trim_start_matches('#').trim()
+                    ),
+                    autofix: None,
+                });
+            }
+        }
+        violations
+    }
+}
+
 /// Content validator for Jidoka quality gates
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct ContentValidator {
     /// Content type being validated
     content_type: ContentType,
+    /// Team-specific rules added with [`ContentValidator::with_rule`]
+    custom_rules: Vec<Arc<dyn ValidationRule>>,
+}
+
+impl std::fmt::Debug for ContentValidator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ContentValidator")
+            .field("content_type", &self.content_type)
+            .field("custom_rules", &self.custom_rules.len())
+            .finish()
+    }
 }
 
 impl ContentValidator {
     /// Create a new validator for a content type
     pub fn new(content_type: ContentType) -> Self {
-        Self { content_type }
+        Self {
+            content_type,
+            custom_rules: Vec::new(),
+        }
+    }
+
+    /// Add a custom rule to run after the built-in checks
+    pub fn with_rule(mut self, rule: impl ValidationRule + 'static) -> Self {
+        self.custom_rules.push(Arc::new(rule));
+        self
     }
 
     /// Validate content against all rules
@@ -629,6 +992,12 @@ impl ContentValidator {
             _ => {}
         }
 
+        for rule in &self.custom_rules {
+            for violation in rule.check(content) {
+                result.add_violation(violation);
+            }
+        }
+
         result
     }
 
@@ -656,6 +1025,7 @@ impl ContentValidator {
                         location: format!("line {}", line_num + 1),
                         text: line.trim().chars().take(60).collect::<String>() + "...",
                         suggestion: "Use direct instruction instead of meta-commentary".to_string(),
+                        autofix: None,
                     });
                 }
             }
@@ -687,6 +1057,7 @@ impl ContentValidator {
                             text: line.trim().chars().take(60).collect::<String>(),
                             suggestion: "Consider using active voice for clearer instruction"
                                 .to_string(),
+                            autofix: None,
                         });
                     }
                 }
@@ -696,10 +1067,11 @@ impl ContentValidator {
 
     /// Validate code blocks have language specifiers
     fn validate_code_blocks(&self, content: &str, result: &mut ValidationResult) {
+        let lines: Vec<&str> = content.lines().collect();
         let mut in_code_block = false;
         let mut block_start = 0;
 
-        for (line_num, line) in content.lines().enumerate() {
+        for (line_num, line) in lines.iter().enumerate() {
             if line.trim().starts_with("```") {
                 if !in_code_block {
                     // Starting a code block
@@ -707,6 +1079,11 @@ impl ContentValidator {
                     block_start = line_num + 1;
                     let lang = line.trim().trim_start_matches('`');
                     if lang.is_empty() {
+                        let body: Vec<&str> = lines[line_num + 1..]
+                            .iter()
+                            .take_while(|l| !l.trim().starts_with("```"))
+                            .copied()
+                            .collect();
                         result.add_violation(ValidationViolation {
                             constraint: "code_block_language".to_string(),
                             severity: ValidationSeverity::Warning,
@@ -714,6 +1091,11 @@ impl ContentValidator {
                             text: "```".to_string(),
                             suggestion: "Specify language: ```rust, ```python, ```bash, etc."
                                 .to_string(),
+                            autofix: Some(format!(
+                                "{}```{}",
+                                &line[..line.len() - line.trim_start().len()],
+                                guess_code_language(&body)
+                            )),
                         });
                     }
                 } else {
@@ -731,6 +1113,7 @@ impl ContentValidator {
                 location: format!("line {}", block_start),
                 text: "Unclosed code block".to_string(),
                 suggestion: "Add closing ``` to code block".to_string(),
+                autofix: Some("```".to_string()),
             });
         }
     }
@@ -754,6 +1137,7 @@ impl ContentValidator {
                             last_level,
                             last_level + 1
                         ),
+                        autofix: Some(format!("{}{}", "#".repeat(last_level + 1), &line[level..])),
                     });
                 }
                 last_level = level;
@@ -773,11 +1157,53 @@ impl ContentValidator {
                 location: "beginning".to_string(),
                 text: "Missing TOML frontmatter".to_string(),
                 suggestion: "Add +++ frontmatter with title, date, description".to_string(),
+                autofix: None,
             });
         }
     }
 }
 
+/// ATX heading level (1-6) of a Markdown line, if it is a heading
+fn heading_level(line: &str) -> Option<usize> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    let rest = &line[level..];
+    ((1..=6).contains(&level) && (rest.is_empty() || rest.starts_with(' '))).then_some(level)
+}
+
+/// Best-effort language tag for an unlabelled code block
+fn guess_code_language(body: &[&str]) -> &'static str {
+    let code: Vec<&str> = body
+        .iter()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .collect();
+    let any = |prefixes: &[&str]| {
+        code.iter()
+            .any(|l| prefixes.iter().any(|p| l.starts_with(p)))
+    };
+
+    if any(&[
+        "$ ", "cargo ", "git ", "cd ", "pip ", "npm ", "echo ", "export ",
+    ]) {
+        "bash"
+    } else if any(&[
+        "fn ", "pub ", "use ", "impl ", "let ", "struct ", "#[", "mod ",
+    ]) {
+        "rust"
+    } else if any(&["def ", "import ", "from ", "class ", "print("]) {
+        "python"
+    } else if code
+        .first()
+        .is_some_and(|l| l.starts_with('[') && l.ends_with(']'))
+    {
+        "toml"
+    } else if code.first().is_some_and(|l| l.starts_with('{')) {
+        "json"
+    } else {
+        "text"
+    }
+}
+
 // ============================================================================
 // PROMPT EMITTER
 // ============================================================================
@@ -795,6 +1221,10 @@ pub struct EmitConfig {
     pub word_count: Option<usize>,
     /// Source context paths
     pub source_context_paths: Vec<PathBuf>,
+    /// Read `source_context_paths` into the prompt (off by default, so
+    /// emitting never touches the filesystem unless asked)
+    #[serde(default)]
+    pub load_source_files: bool,
     /// RAG context directory
     pub rag_context_path: Option<PathBuf>,
     /// RAG token limit
@@ -842,6 +1272,12 @@ impl EmitConfig {
         self
     }
 
+    /// Read source context paths into the prompt's source context section
+    pub fn with_load_source_files(mut self, load: bool) -> Self {
+        self.load_source_files = load;
+        self
+    }
+
     /// Set RAG context
     pub fn with_rag_context(mut self, path: PathBuf, limit: usize) -> Self {
         self.rag_context_path = Some(path);
@@ -856,6 +1292,48 @@ impl EmitConfig {
     }
 }
 
+/// A prompt split into its named sections, in emission order
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmitResult {
+    /// Request title, audience, length and output format
+    pub header: String,
+    /// Referenced source material, when source files were loaded
+    pub source_context: Option<String>,
+    /// Toyota Way constraints
+    pub constraints: String,
+    /// Content-type specific structure and output schema
+    pub structure: String,
+    /// Quality gate checklist
+    pub quality_gates: String,
+    /// Token budget breakdown, when requested
+    pub token_budget: Option<String>,
+    /// Closing instruction
+    pub footer: String,
+}
+
+impl EmitResult {
+    /// Sections present in this result, in emission order
+    pub fn sections(&self) -> Vec<&str> {
+        [
+            Some(self.header.as_str()),
+            self.source_context.as_deref(),
+            Some(self.constraints.as_str()),
+            Some(self.structure.as_str()),
+            Some(self.quality_gates.as_str()),
+            self.token_budget.as_deref(),
+            Some(self.footer.as_str()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// Concatenate all sections into a single prompt
+    pub fn to_prompt(&self) -> String {
+        self.sections().concat()
+    }
+}
+
 /// Prompt emitter for content generation
 #[derive(Debug, Clone)]
 pub struct PromptEmitter {
@@ -912,38 +1390,47 @@ Before submitting, verify:
 
     /// Emit a prompt for the given configuration
     pub fn emit(&self, config: &EmitConfig) -> Result<String, ContentError> {
+        Ok(self.emit_structured(config)?.to_prompt())
+    }
+
+    /// Emit the prompt as separate named sections
+    ///
+    /// Performs no I/O unless `config.load_source_files` is set, in which
+    /// case the files in `config.source_context_paths` are read into the
+    /// source context section and a missing file is an error.
+    pub fn emit_structured(&self, config: &EmitConfig) -> Result<EmitResult, ContentError> {
         let content_type = config
             .content_type
             .ok_or_else(|| ContentError::MissingRequiredField("content_type".to_string()))?;
 
-        let mut prompt = String::new();
+        let mut header = String::new();
 
         // Header
-        prompt.push_str(&format!(
+        header.push_str(&format!(
             "# Content Generation Request: {}\n\n",
             content_type.name()
         ));
 
         // Context section
-        prompt.push_str("## Context\n\n");
-        prompt.push_str(&format!(
+        header.push_str("## Context\n\n");
+        header.push_str(&format!(
             "You are creating a {} ({}).\n\n",
             content_type.name(),
             content_type.code()
         ));
 
         if let Some(title) = &config.title {
-            prompt.push_str(&format!("**Title/Topic**: {}\n", title));
+            header.push_str(&format!("**Title/Topic**: {}\n", title));
         }
         if let Some(audience) = &config.audience {
-            prompt.push_str(&format!("**Target Audience**: {}\n", audience));
+            header.push_str(&format!("**Target Audience**: {}\n", audience));
         }
         if let Some(word_count) = config.word_count {
-            prompt.push_str(&format!("**Target Length**: {} words\n", word_count));
+            header.push_str(&format!("**Target Length**: {} words\n", word_count));
         } else {
             let range = content_type.target_length();
             if range.start > 0 {
-                prompt.push_str(&format!(
+                header.push_str(&format!(
                     "**Target Length**: {}-{} {}\n",
                     range.start,
                     range.end,
@@ -958,38 +1445,44 @@ Before submitting, verify:
                 ));
             }
         }
-        prompt.push_str(&format!(
+        header.push_str(&format!(
             "**Output Format**: {}\n\n",
             content_type.output_format()
         ));
 
-        // Toyota Way constraints
-        prompt.push_str(&self.toyota_constraints);
-        prompt.push('\n');
-
-        // Content-type specific instructions
-        prompt.push_str(&self.emit_type_specific(content_type, config));
-        prompt.push('\n');
-
-        // Quality gates
-        prompt.push_str(&self.quality_gates);
-        prompt.push('\n');
+        // Source material (Genchi Genbutsu)
+        let source_context = if !config.load_source_files || config.source_context_paths.is_empty()
+        {
+            None
+        } else {
+            let mut context = SourceContext::new();
+            for path in &config.source_context_paths {
+                context.add_file(path, None)?;
+            }
+            Some(context.format_for_prompt())
+        };
 
         // Token budget if requested
-        if config.show_budget {
+        let token_budget = config.show_budget.then(|| {
             let budget = TokenBudget::new(config.model).with_output_target(
                 TokenBudget::words_to_tokens(config.word_count.unwrap_or(4000)),
             );
-            prompt.push_str("## Token Budget\n\n");
-            prompt.push_str(&budget.format_display(config.model.name()));
-            prompt.push('\n');
-        }
-
-        // Final instruction
-        prompt.push_str("---\n\n");
-        prompt.push_str("Generate the content now, following all constraints above.\n");
+            format!(
+                "## Token Budget\n\n{}\n",
+                budget.format_display(config.model.name())
+            )
+        });
 
-        Ok(prompt)
+        Ok(EmitResult {
+            header,
+            source_context,
+            constraints: format!("{}\n", self.toyota_constraints),
+            structure: format!("{}\n", self.emit_type_specific(content_type, config)),
+            quality_gates: format!("{}\n", self.quality_gates),
+            token_budget,
+            footer: "---\n\nGenerate the content now, following all constraints above.\n"
+                .to_string(),
+        })
     }
 
     /// Emit type-specific instructions
@@ -1281,6 +1774,48 @@ instructions:
 - Keyboard navigation
 - Screen reader support
 - Graceful degradation
+"#
+            .to_string(),
+            ContentType::ApiReference => r#"## API Reference Guidelines
+
+1. **One entry per public item**: modules, types, traits, functions, methods
+2. **Source-accurate**: Copy signatures verbatim from the provided source
+3. **Complete**: Document every parameter, return value, and error case
+4. **Runnable examples**: Every example must compile and run as-is
+
+## Required Structure (per item)
+
+````markdown
+### `item_name`
+
+**Signature**:
+
+```rust
+pub fn item_name(param: Type) -> Result<Output, Error>
+```
+
+One-sentence summary of what the item does.
+
+**Parameters**:
+- `param` - What it is and any constraints
+
+**Returns**: What the value represents; when each error variant occurs
+
+**Panics/Safety**: Conditions, or "None"
+
+**Examples**:
+
+```rust
+let output = item_name(value)?;
+assert_eq!(output, expected);
+```
+````
+
+## Organization
+
+- H2 (##) per module, H3 (###) per item
+- Order items as they appear in the source
+- Cross-link related items with backticks
 "#
             .to_string(),
         }
@@ -1346,6 +1881,10 @@ mod tests {
             ContentType::from_str("demo").unwrap(),
             ContentType::PresentarDemo
         );
+        assert_eq!(
+            ContentType::from_str("api-reference").unwrap(),
+            ContentType::ApiReference
+        );
     }
 
     #[test]
@@ -1372,7 +1911,7 @@ mod tests {
     #[test]
     fn test_CONTENT_006_content_type_all() {
         let all = ContentType::all();
-        assert_eq!(all.len(), 5);
+        assert_eq!(all.len(), 6);
         assert!(all.contains(&ContentType::HighLevelOutline));
         assert!(all.contains(&ContentType::PresentarDemo));
     }
@@ -1384,6 +1923,25 @@ mod tests {
         assert_eq!(ContentType::BlogPost.target_length(), 500..3000);
     }
 
+    #[test]
+    fn test_CONTENT_008_content_type_catalog_json() {
+        let catalog = ContentType::catalog_json();
+        let entries = catalog.as_array().unwrap();
+        assert_eq!(entries.len(), ContentType::all().len());
+
+        for ct in ContentType::all() {
+            let entry = entries
+                .iter()
+                .find(|e| e["code"] == ct.code())
+                .unwrap_or_else(|| panic!("missing catalog entry for {}", ct.code()));
+            assert_eq!(entry["name"], ct.name());
+            assert_eq!(entry["output_format"], ct.output_format());
+            assert_eq!(entry["target_length"]["min"], ct.target_length().start);
+            assert_eq!(entry["target_length"]["max"], ct.target_length().end);
+            assert_eq!(entry["unit"], ct.length_unit());
+        }
+    }
+
     // ========================================================================
     // TokenBudget Tests
     // ========================================================================
@@ -1480,6 +2038,121 @@ mod tests {
     // SourceContext Tests
     // ========================================================================
 
+    #[test]
+    fn test_BUDGET_011_fit_trims_context_proportionally() {
+        let mut budget = TokenBudget {
+            context_window: 10_000,
+            system_reserve: 1_000,
+            source_context: 6_000,
+            rag_context: 3_000,
+            few_shot: 500,
+            output_target: 2_500,
+        };
+        let trim = budget.fit().unwrap();
+        // 13000 total, 3000 over: split 2:1 between source and RAG
+        assert_eq!(trim.source_context, 2_000);
+        assert_eq!(trim.rag_context, 1_000);
+        assert_eq!(trim.output_target, 0);
+        assert_eq!(trim.total(), 3_000);
+        assert_eq!(budget.source_context, 4_000);
+        assert_eq!(budget.rag_context, 2_000);
+        assert!(budget.validate().is_ok());
+    }
+
+    #[test]
+    fn test_BUDGET_012_fit_trims_output_to_floor() {
+        let mut budget = TokenBudget {
+            context_window: 6_000,
+            system_reserve: 1_000,
+            source_context: 1_000,
+            rag_context: 0,
+            few_shot: 500,
+            output_target: 5_000,
+        };
+        let trim = budget.fit().unwrap();
+        assert_eq!(trim.source_context, 1_000);
+        assert_eq!(trim.output_target, 500);
+        assert_eq!(budget.output_target, 4_500);
+        assert_eq!(budget.available_margin(), 0);
+    }
+
+    #[test]
+    fn test_BUDGET_013_fit_noop_when_within_window() {
+        let mut budget = TokenBudget::new(ModelContext::Claude200K).with_source_context(5_000);
+        let before = budget.clone();
+        assert_eq!(budget.fit().unwrap(), BudgetTrim::default());
+        assert_eq!(budget, before);
+    }
+
+    #[test]
+    fn test_BUDGET_014_fit_fails_when_floor_overflows() {
+        let mut budget = TokenBudget {
+            context_window: 3_000,
+            system_reserve: 1_000,
+            source_context: 5_000,
+            rag_context: 5_000,
+            few_shot: 500,
+            output_target: 10_000,
+        };
+        let before = budget.clone();
+        let result = budget.fit();
+        assert!(matches!(
+            result,
+            Err(ContentError::TokenBudgetExceeded {
+                used: 3_500,
+                limit: 3_000
+            })
+        ));
+        assert_eq!(budget, before);
+    }
+
+    #[test]
+    fn test_BUDGET_015_model_context_from_str() {
+        let models = [
+            ModelContext::Claude200K,
+            ModelContext::ClaudeHaiku,
+            ModelContext::Claude35Sonnet,
+            ModelContext::GeminiPro,
+            ModelContext::GeminiFlash,
+            ModelContext::Gemini15Pro,
+            ModelContext::Gemini15Flash,
+            ModelContext::Gpt4Turbo,
+            ModelContext::Gpt4o,
+        ];
+        for model in models {
+            assert_eq!(model.name().parse::<ModelContext>().unwrap(), model);
+        }
+        assert_eq!(
+            "GPT-4o".parse::<ModelContext>().unwrap(),
+            ModelContext::Gpt4o
+        );
+        assert_eq!(
+            "custom:64000".parse::<ModelContext>().unwrap(),
+            ModelContext::Custom(64_000)
+        );
+        assert!(matches!(
+            "llama-2".parse::<ModelContext>(),
+            Err(ContentError::UnknownModel(_))
+        ));
+        assert!("custom:lots".parse::<ModelContext>().is_err());
+    }
+
+    #[test]
+    fn test_BUDGET_016_large_context_models() {
+        assert_eq!(ModelContext::Claude35Sonnet.window_size(), 200_000);
+        assert_eq!(ModelContext::Gpt4o.window_size(), 128_000);
+        assert_eq!(ModelContext::Gemini15Pro.window_size(), 1_000_000);
+
+        let budget_for = |model| {
+            TokenBudget::new(model)
+                .with_source_context(300_000)
+                .with_rag_context(100_000)
+        };
+        assert!(budget_for(ModelContext::Gemini15Pro).validate().is_ok());
+        assert!(budget_for(ModelContext::Gpt4o).validate().is_err());
+        assert!(budget_for(ModelContext::Gpt4Turbo).validate().is_err());
+    }
+
     #[test]
     fn test_SOURCE_001_source_context_new() {
         let ctx = SourceContext::new();
@@ -1530,6 +2203,81 @@ mod tests {
         assert!(formatted.contains("fn main()"));
     }
 
+    #[test]
+    #[cfg(feature = "native")]
+    fn test_SOURCE_006_source_context_count_tokens() {
+        let mut ctx = SourceContext::new();
+        for content in [
+            "Hello, world!",
+            "The quick brown fox jumps over the lazy dog.",
+        ] {
+            ctx.add_snippet(SourceSnippet {
+                path: PathBuf::from("/src/lib.rs"),
+                lines: None,
+                content: content.to_string(),
+                tokens: 0,
+            });
+        }
+        ctx.count_tokens();
+
+        // cl100k_base encodes these as 4 and 10 tokens
+        assert_eq!(ctx.snippets[0].tokens, 4);
+        assert_eq!(ctx.snippets[1].tokens, 10);
+        assert_eq!(
+            ctx.total_tokens,
+            ctx.snippets[0].tokens + ctx.snippets[1].tokens
+        );
+    }
+
+    #[test]
+    fn test_SOURCE_007_source_context_add_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("lib.rs");
+        std::fs::write(&path, "line one\nline two\nline three\nline four\n").unwrap();
+
+        let mut ctx = SourceContext::new();
+        ctx.add_file(&path, None).unwrap();
+        ctx.add_file(&path, Some((2, 3))).unwrap();
+
+        assert_eq!(ctx.snippets.len(), 2);
+        assert!(ctx.snippets[0].content.contains("line four"));
+        assert_eq!(ctx.snippets[0].lines, None);
+        assert_eq!(ctx.snippets[1].content, "line two\nline three");
+        assert_eq!(ctx.snippets[1].lines, Some((2, 3)));
+        assert!(ctx.snippets.iter().all(|s| s.tokens > 0));
+        assert_eq!(
+            ctx.total_tokens,
+            ctx.snippets[0].tokens + ctx.snippets[1].tokens
+        );
+    }
+
+    #[test]
+    fn test_SOURCE_008_source_context_add_file_clamps_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("lib.rs");
+        std::fs::write(&path, "a\nb\nc").unwrap();
+
+        let mut ctx = SourceContext::new();
+        ctx.add_file(&path, Some((0, 99))).unwrap();
+        ctx.add_file(&path, Some((7, 9))).unwrap();
+
+        assert_eq!(ctx.snippets[0].content, "a\nb\nc");
+        assert_eq!(ctx.snippets[0].lines, Some((1, 3)));
+        assert_eq!(ctx.snippets[1].content, "c");
+        assert_eq!(ctx.snippets[1].lines, Some((3, 3)));
+    }
+
+    #[test]
+    fn test_SOURCE_009_source_context_add_file_missing() {
+        let mut ctx = SourceContext::new();
+        let err = ctx
+            .add_file("/nonexistent/batuta/lib.rs", None)
+            .unwrap_err();
+        assert!(matches!(err, ContentError::SourceContextError(_)));
+        assert!(err.to_string().contains("/nonexistent/batuta/lib.rs"));
+        assert!(ctx.snippets.is_empty());
+    }
+
     // ========================================================================
     // Validation Tests
     // ========================================================================
@@ -1550,6 +2298,7 @@ mod tests {
             location: "line 1".to_string(),
             text: "bad text".to_string(),
             suggestion: "fix it".to_string(),
+            autofix: None,
         }];
         let result = ValidationResult::fail(violations);
         assert!(!result.passed);
@@ -1565,6 +2314,7 @@ mod tests {
             location: "line 1".to_string(),
             text: "text".to_string(),
             suggestion: "fix".to_string(),
+            autofix: None,
         });
         assert_eq!(result.score, 90); // 100 - 10 for warning
     }
@@ -1579,6 +2329,7 @@ mod tests {
             location: "line 1".to_string(),
             text: "text".to_string(),
             suggestion: "fix".to_string(),
+            autofix: None,
         });
         assert!(result.has_critical());
     }
@@ -1669,6 +2420,7 @@ mod tests {
             location: "line 5".to_string(),
             text: "some text".to_string(),
             suggestion: "fix this".to_string(),
+            autofix: None,
         });
         let display = result.format_display();
         assert!(display.contains("90/100"));
@@ -1676,6 +2428,169 @@ mod tests {
         assert!(display.contains("test_constraint"));
     }
 
+    struct NoTodoRule;
+
+    impl ValidationRule for NoTodoRule {
+        fn check(&self, content: &str) -> Vec<ValidationViolation> {
+            content
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| line.contains("TODO"))
+                .map(|(line_num, line)| ValidationViolation {
+                    constraint: "no_todo".to_string(),
+                    severity: ValidationSeverity::Error,
+                    location: format!("line {}", line_num + 1),
+                    text: line.trim().to_string(),
+                    suggestion: "Resolve the TODO before publishing".to_string(),
+                    autofix: None,
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_VALID_013_custom_rule_fires() {
+        let validator = ContentValidator::new(ContentType::DetailedOutline).with_rule(NoTodoRule);
+        let result = validator.validate("# Title\n\n## Section\n\nTODO: write this\n");
+        assert!(!result.passed);
+        assert_eq!(result.violations.len(), 1);
+        assert_eq!(result.violations[0].constraint, "no_todo");
+        assert_eq!(result.violations[0].location, "line 5");
+    }
+
+    #[test]
+    fn test_VALID_014_custom_rules_aggregate_with_builtin() {
+        let validator = ContentValidator::new(ContentType::DetailedOutline).with_rule(NoTodoRule);
+        let result = validator.validate("# Title\n\n```\nTODO\n```\n");
+        let constraints: Vec<_> = result
+            .violations
+            .iter()
+            .map(|v| v.constraint.as_str())
+            .collect();
+        assert_eq!(constraints, vec!["code_block_language", "no_todo"]);
+
+        let clean = validator.validate("# Title\n\n## Section\n");
+        assert!(clean.passed);
+        assert!(clean.violations.is_empty());
+    }
+
+    #[test]
+    fn test_VALID_015_code_block_language_autofix() {
+        let validator = ContentValidator::new(ContentType::DetailedOutline);
+        let result =
+            validator.validate("# Title\n\n```\nfn main() {}\n```\n\n```\n$ cargo build\n```\n");
+        let fixes: Vec<_> = result
+            .autofixable()
+            .iter()
+            .map(|v| v.autofix.as_deref().unwrap())
+            .collect();
+        assert_eq!(fixes, vec!["```rust", "```bash"]);
+    }
+
+    #[test]
+    fn test_VALID_016_heading_and_unclosed_block_autofix() {
+        let validator = ContentValidator::new(ContentType::DetailedOutline);
+        let result = validator.validate("# Title\n### Deep\n\n```text\nunclosed\n");
+        let fix = |constraint: &str| {
+            result
+                .violations
+                .iter()
+                .find(|v| v.constraint == constraint)
+                .and_then(|v| v.autofix.clone())
+        };
+        assert_eq!(fix("heading_hierarchy").as_deref(), Some("## Deep"));
+        assert_eq!(fix("code_block_closed").as_deref(), Some("```"));
+        assert!(result.format_display().contains("Autofix: ## Deep"));
+    }
+
+    #[test]
+    fn test_VALID_017_autofixable_excludes_judgement_calls() {
+        let validator = ContentValidator::new(ContentType::DetailedOutline);
+        let result = validator.validate("# Title\n\nIn this chapter we cover it.\n");
+        assert!(!result.violations.is_empty());
+        assert!(result.autofixable().is_empty());
+    }
+
+    #[test]
+    fn test_VALID_018_guess_code_language() {
+        assert_eq!(guess_code_language(&["def main():", "    pass"]), "python");
+        assert_eq!(guess_code_language(&["[package]", "name = \"x\""]), "toml");
+        assert_eq!(guess_code_language(&["{\"a\": 1}"]), "json");
+        assert_eq!(guess_code_language(&["plain words"]), "text");
+    }
+
+    #[test]
+    fn test_VALID_019_diff_improved_is_not_regression() {
+        let validator = ContentValidator::new(ContentType::DetailedOutline);
+        let baseline = validator.validate("# Title\n### Skipped\n\n```\ncode\n```\n");
+        let current = validator.validate("# Title\n\n## Fixed\n\n```\ncode\n```\n");
+
+        let delta = current.diff(&baseline);
+        assert!(delta.new_violations.is_empty());
+        assert_eq!(delta.resolved_count, 1);
+        assert_eq!(delta.score_change, 25);
+        assert!(!delta.is_regression());
+    }
+
+    #[test]
+    fn test_VALID_020_diff_detects_regression() {
+        let validator = ContentValidator::new(ContentType::DetailedOutline);
+        let baseline = validator.validate("# Title\n\n```text\ncode\n```\n");
+        let current =
+            validator.validate("# Title\n\nIntro line.\n\n```text\ncode\n```\n\n```\nmore\n```\n");
+
+        let delta = current.diff(&baseline);
+        assert_eq!(delta.new_violations.len(), 1);
+        assert_eq!(delta.new_violations[0].constraint, "code_block_language");
+        assert_eq!(delta.resolved_count, 0);
+        assert_eq!(delta.score_change, -10);
+        assert!(delta.is_regression());
+    }
+
+    #[test]
+    fn test_VALID_021_diff_ignores_shifted_lines() {
+        let validator = ContentValidator::new(ContentType::DetailedOutline);
+        let baseline = validator.validate("# Title\n### Skipped\n");
+        let current = validator.validate("# Title\n### Skipped\n\nMore text.\n");
+        let shifted = validator.validate("# Title\n\nNew intro.\n### Skipped\n");
+
+        assert!(!current.diff(&baseline).is_regression());
+        assert!(!shifted.diff(&baseline).is_regression());
+    }
+
+    #[test]
+    fn test_VALID_022_section_intro_rule_is_opt_in() {
+        let content = "# Ownership\n\n## Moves\n\nA move transfers ownership.\n\n\
+                       ## Borrowing\n\n```rust\n# fn main() {}\n```\n";
+        let default = ContentValidator::new(ContentType::BookChapter).validate(content);
+        assert!(!default
+            .violations
+            .iter()
+            .any(|v| v.constraint == "section_intro"));
+
+        let validator = ContentValidator::new(ContentType::BookChapter).with_rule(SectionIntroRule);
+        let result = validator.validate(content);
+        let intros: Vec<_> = result
+            .violations
+            .iter()
+            .filter(|v| v.constraint == "section_intro")
+            .collect();
+
+        // "Moves" has an intro; "# fn main" is code, not a heading
+        assert_eq!(intros.len(), 2);
+        assert_eq!(intros[0].location, "line 1");
+        assert_eq!(intros[0].severity, ValidationSeverity::Info);
+        assert!(intros[0].suggestion.contains("\"Ownership\""));
+        assert_eq!(intros[1].text, "## Borrowing");
+        assert!(!result
+            .autofixable()
+            .iter()
+            .any(|v| v.constraint == "section_intro"));
+
+        let introduced = "# Ownership\n\nRust tracks who owns each value.\n\n## Moves\n\nA move.\n";
+        assert!(SectionIntroRule.check(introduced).is_empty());
+    }
+
     // ========================================================================
     // PromptEmitter Tests
     // ========================================================================
@@ -1732,6 +2647,58 @@ mod tests {
         assert!(prompt.contains("Accessibility"));
     }
 
+    #[test]
+    fn test_EMIT_005b_emit_api_reference() {
+        let emitter = PromptEmitter::new();
+        let config = EmitConfig::new(ContentType::ApiReference).with_title("trueno::Vector");
+        let prompt = emitter.emit(&config).unwrap();
+        assert!(prompt.contains("API Reference (API)"));
+        assert!(prompt.contains("1000-6000 words"));
+        assert!(prompt.to_lowercase().contains("signature"));
+        assert!(prompt.to_lowercase().contains("examples"));
+        assert!(prompt.contains("**Parameters**"));
+        assert!(prompt.contains("**Returns**"));
+        assert!(fences_balanced(&prompt));
+    }
+
+    /// Every opening code fence is closed by a bare fence of the same
+    /// character at least as long (CommonMark), so nested fences stay nested
+    fn fences_balanced(markdown: &str) -> bool {
+        let mut open: Option<(char, usize)> = None;
+        for line in markdown.lines() {
+            let trimmed = line.trim_start();
+            let Some(ch) = trimmed.chars().next().filter(|c| *c == '`' || *c == '~') else {
+                continue;
+            };
+            let len = trimmed.chars().take_while(|c| *c == ch).count();
+            if len < 3 {
+                continue;
+            }
+            let info = trimmed[len..].trim();
+            match open {
+                None => open = Some((ch, len)),
+                Some((open_ch, open_len)) if ch == open_ch && len >= open_len => {
+                    if !info.is_empty() {
+                        // An info string here means a fence meant to open
+                        // a nested block has closed the outer one instead
+                        return false;
+                    }
+                    open = None;
+                }
+                Some(_) => {}
+            }
+        }
+        open.is_none()
+    }
+
+    #[test]
+    fn test_fences_balanced() {
+        assert!(fences_balanced("```rust\nfn x() {}\n```\n"));
+        assert!(fences_balanced("````markdown\n```rust\nx\n```\n````\n"));
+        assert!(!fences_balanced("```markdown\n```rust\nx\n```\n```\n"));
+        assert!(!fences_balanced("```rust\nunclosed\n"));
+    }
+
     #[test]
     fn test_EMIT_006_emit_missing_content_type() {
         let emitter = PromptEmitter::new();
@@ -1784,6 +2751,76 @@ mod tests {
         assert_eq!(config.source_context_paths.len(), 1);
     }
 
+    #[test]
+    fn test_EMIT_011_structured_concatenates_to_emit() {
+        let emitter = PromptEmitter::new();
+        for ct in ContentType::all() {
+            let mut config = EmitConfig::new(ct).with_title("Ownership");
+            config.show_budget = true;
+            let structured = emitter.emit_structured(&config).unwrap();
+            assert_eq!(structured.to_prompt(), emitter.emit(&config).unwrap());
+            assert!(structured.token_budget.is_some());
+            assert!(structured.source_context.is_none());
+        }
+    }
+
+    #[test]
+    fn test_EMIT_012_structured_sections_can_be_dropped() {
+        let emitter = PromptEmitter::new();
+        let config = EmitConfig::new(ContentType::BlogPost);
+        let mut structured = emitter.emit_structured(&config).unwrap();
+        assert!(structured.header.contains("Blog Post"));
+        assert!(structured.constraints.contains("Toyota Way"));
+        assert!(structured.quality_gates.contains("Quality Gates"));
+        assert_eq!(structured.sections().len(), 5);
+
+        structured.constraints.clear();
+        assert!(!structured.to_prompt().contains("Toyota Way Constraints"));
+
+        let json = serde_json::to_value(&structured).unwrap();
+        assert!(json["structure"].is_string());
+    }
+
+    #[test]
+    fn test_EMIT_013_structured_reads_source_context() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("lib.rs");
+        std::fs::write(&path, "pub fn answer() -> u32 { 42 }\n").unwrap();
+
+        let emitter = PromptEmitter::new();
+        let config = EmitConfig::new(ContentType::BookChapter)
+            .with_source_context(path)
+            .with_load_source_files(true);
+        let structured = emitter.emit_structured(&config).unwrap();
+        let source = structured.source_context.as_deref().unwrap();
+        assert!(source.contains("pub fn answer()"));
+        assert!(emitter.emit(&config).unwrap().contains("Genchi Genbutsu"));
+
+        let missing = EmitConfig::new(ContentType::BookChapter)
+            .with_source_context(dir.path().join("missing.rs"))
+            .with_load_source_files(true);
+        assert!(matches!(
+            emitter.emit_structured(&missing),
+            Err(ContentError::SourceContextError(_))
+        ));
+    }
+
+    #[test]
+    fn test_EMIT_014_emit_does_no_io_by_default() {
+        let emitter = PromptEmitter::new();
+        let config = EmitConfig::new(ContentType::BookChapter)
+            .with_source_context(PathBuf::from("/nonexistent/batuta/lib.rs"));
+
+        let structured = emitter.emit_structured(&config).unwrap();
+        assert!(structured.source_context.is_none());
+        assert_eq!(
+            emitter.emit(&config).unwrap(),
+            emitter
+                .emit(&EmitConfig::new(ContentType::BookChapter))
+                .unwrap()
+        );
+    }
+
     // ========================================================================
     // CourseLevel Tests
     // ========================================================================
@@ -0,0 +1,463 @@
+//! Token counting for source context budgets
+//!
+//! [`BpeTokenizer`] is a byte-level BPE compatible with tiktoken rank files.
+//! tiktoken's `cl100k_base` vocabulary (MIT licensed) is bundled, so
+//! [`BpeTokenizer::cl100k`] gives exact GPT-3.5/GPT-4 counts.
+//! [`EstimateCounter`] is a vocabulary-free alternative: it applies an
+//! approximate pre-tokenization and estimates per piece, which tracks BPE
+//! closely for English prose and code but is not exact.
+
+use super::ContentError;
+use base64::Engine;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Anything that can count the tokens in a piece of text
+pub trait TokenCounter {
+    /// Number of tokens `text` encodes to
+    fn count_tokens(&self, text: &str) -> usize;
+}
+
+/// Default counter: exact cl100k BPE using the bundled vocabulary
+pub fn default_counter() -> &'static dyn TokenCounter {
+    BpeTokenizer::cl100k()
+}
+
+/// Vocabulary-free token estimate
+///
+/// Counts each pre-tokenized piece: one token per 8 ASCII letters, one per
+/// 4 bytes of other ASCII, one per 2 bytes of non-ASCII text, and one for a
+/// bare whitespace run.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EstimateCounter;
+
+impl TokenCounter for EstimateCounter {
+    fn count_tokens(&self, text: &str) -> usize {
+        pretokenize(text)
+            .iter()
+            .map(|piece| estimate_piece(piece))
+            .sum()
+    }
+}
+
+fn estimate_piece(piece: &str) -> usize {
+    let word = piece.trim_start();
+    if word.is_empty() {
+        // Whitespace runs usually merge into a single token
+        return 1;
+    }
+    if word.chars().all(|c| c.is_ascii_alphabetic()) {
+        // Common words (with their leading space) are single tokens
+        return word.len().div_ceil(8).max(1);
+    }
+    if word.is_ascii() {
+        return word.len().div_ceil(4).max(1);
+    }
+    // Non-ASCII text tends towards a token per byte pair
+    word.len().div_ceil(2).max(1)
+}
+
+/// tiktoken's cl100k_base rank file
+const CL100K_RANKS: &str = include_str!("cl100k_base.tiktoken");
+
+/// Byte-level BPE tokenizer using tiktoken merge ranks
+#[derive(Debug, Clone)]
+pub struct BpeTokenizer {
+    ranks: HashMap<Vec<u8>, u32>,
+}
+
+impl BpeTokenizer {
+    /// Build from `token bytes -> rank`; lower ranks merge first
+    ///
+    /// Every single byte must have a rank so any input can be encoded.
+    pub fn new(ranks: HashMap<Vec<u8>, u32>) -> Result<Self, ContentError> {
+        if let Some(byte) = (0..=u8::MAX).find(|b| !ranks.contains_key(&vec![*b])) {
+            return Err(ContentError::SourceContextError(format!(
+                "BPE vocabulary has no token for byte 0x{:02x}",
+                byte
+            )));
+        }
+        Ok(Self { ranks })
+    }
+
+    /// The bundled cl100k_base tokenizer (GPT-3.5/GPT-4), parsed once per process
+    pub fn cl100k() -> &'static Self {
+        static CL100K: OnceLock<BpeTokenizer> = OnceLock::new();
+        CL100K.get_or_init(|| {
+            Self::from_tiktoken(CL100K_RANKS).expect("bundled cl100k_base rank file is valid")
+        })
+    }
+
+    /// Load a tiktoken rank file (`<base64 token> <rank>` per line)
+    pub fn from_tiktoken_file(path: &Path) -> Result<Self, ContentError> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| ContentError::SourceContextError(format!("{}: {}", path.display(), e)))?;
+        Self::from_tiktoken(&content)
+    }
+
+    /// Parse tiktoken rank file contents
+    pub fn from_tiktoken(content: &str) -> Result<Self, ContentError> {
+        let mut ranks = HashMap::new();
+        for (line_num, line) in content.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let invalid = || {
+                ContentError::SourceContextError(format!(
+                    "Invalid tiktoken line {}: {}",
+                    line_num + 1,
+                    line
+                ))
+            };
+            let (token, rank) = line.split_once(' ').ok_or_else(invalid)?;
+            let token = base64::engine::general_purpose::STANDARD
+                .decode(token)
+                .map_err(|_| invalid())?;
+            let rank = rank.trim().parse().map_err(|_| invalid())?;
+            ranks.insert(token, rank);
+        }
+        Self::new(ranks)
+    }
+
+    /// Encode `text` into token ranks
+    pub fn encode(&self, text: &str) -> Vec<u32> {
+        cl100k_pieces(text)
+            .into_iter()
+            .flat_map(|piece| self.encode_piece(piece.as_bytes()))
+            .collect()
+    }
+
+    /// Merge the bytes of one pre-tokenized piece, lowest rank first
+    fn encode_piece(&self, piece: &[u8]) -> Vec<u32> {
+        if let Some(&rank) = self.ranks.get(piece) {
+            return vec![rank];
+        }
+
+        // Boundaries of the current parts, each with the rank of merging it
+        // with its right neighbour (u32::MAX when there is no such token)
+        let rank_of = |parts: &[(usize, u32)], i: usize| -> u32 {
+            match parts.get(i + 2) {
+                Some(&(end, _)) => self
+                    .ranks
+                    .get(&piece[parts[i].0..end])
+                    .copied()
+                    .unwrap_or(u32::MAX),
+                None => u32::MAX,
+            }
+        };
+        let mut parts: Vec<(usize, u32)> = (0..=piece.len()).map(|i| (i, u32::MAX)).collect();
+        for i in 0..parts.len().saturating_sub(2) {
+            parts[i].1 = rank_of(&parts, i);
+        }
+
+        while parts.len() > 1 {
+            let Some((i, rank)) = parts[..parts.len() - 1]
+                .iter()
+                .enumerate()
+                .map(|(i, &(_, rank))| (i, rank))
+                .min_by_key(|&(_, rank)| rank)
+            else {
+                break;
+            };
+            if rank == u32::MAX {
+                break;
+            }
+            parts.remove(i + 1);
+            parts[i].1 = rank_of(&parts, i);
+            if i > 0 {
+                parts[i - 1].1 = rank_of(&parts, i - 1);
+            }
+        }
+
+        parts
+            .windows(2)
+            .map(|w| self.ranks[&piece[w[0].0..w[1].0]])
+            .collect()
+    }
+}
+
+impl TokenCounter for BpeTokenizer {
+    fn count_tokens(&self, text: &str) -> usize {
+        self.encode(text).len()
+    }
+}
+
+/// cl100k_base pre-tokenizer pattern, minus its `\s+(?!\S)` lookahead
+const CL100K_PATTERN: &str = r"(?i:'s|'t|'re|'ve|'m|'ll|'d)|[^\r\n\p{L}\p{N}]?\p{L}+|\p{N}{1,3}| ?[^\s\p{L}\p{N}]+[\r\n]*|\s*[\r\n]+|\s+";
+
+/// Split text exactly as tiktoken's cl100k_base pre-tokenizer does
+fn cl100k_pieces(text: &str) -> Vec<&str> {
+    static PATTERN: OnceLock<regex::Regex> = OnceLock::new();
+    let pattern =
+        PATTERN.get_or_init(|| regex::Regex::new(CL100K_PATTERN).expect("valid cl100k pattern"));
+
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    while let Some(m) = pattern.find_at(text, start) {
+        let piece = m.as_str();
+        let mut end = m.end();
+        // `\s+(?!\S)`: a newline-free whitespace run followed by more text
+        // leaves its last character to the next piece
+        if end < text.len()
+            && piece.chars().all(char::is_whitespace)
+            && !piece.ends_with(['\r', '\n'])
+        {
+            if let Some((last, _)) = piece.char_indices().last().filter(|&(i, _)| i > 0) {
+                end = m.start() + last;
+            }
+        }
+        pieces.push(&text[m.start()..end]);
+        start = end;
+    }
+    pieces
+}
+
+/// Approximate the cl100k pre-tokenizer without Unicode category tables
+///
+/// Contractions, optionally-prefixed letter runs, up to three digits,
+/// optionally space-prefixed punctuation runs, then whitespace (a run before
+/// a word keeps its last space for the word). Combining marks count as
+/// letters here, unlike the exact regex split used by [`BpeTokenizer`].
+pub(crate) fn pretokenize(text: &str) -> Vec<&str> {
+    const CONTRACTIONS: &[&str] = &["s", "t", "re", "ve", "m", "ll", "d"];
+    let is_newline = |c: char| c == '\r' || c == '\n';
+    let is_punct = |c: char| !c.is_whitespace() && !c.is_alphanumeric();
+    // Letter-like numerals (e.g. `Ⅻ`) are alphabetic but not letters
+    let is_letter = |c: char| c.is_alphabetic() && !c.is_numeric();
+
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let n = chars.len();
+    let offset = |k: usize| chars.get(k).map_or(text.len(), |&(i, _)| i);
+    let mut pieces = Vec::new();
+    let mut k = 0;
+
+    while k < n {
+        let c = chars[k].1;
+        let next = chars.get(k + 1).map(|&(_, c)| c);
+
+        let end = if let Some(len) = (c == '\'')
+            .then(|| {
+                CONTRACTIONS.iter().find_map(|suffix| {
+                    let matches = suffix.chars().enumerate().all(|(j, s)| {
+                        chars
+                            .get(k + 1 + j)
+                            .is_some_and(|&(_, c)| c.to_ascii_lowercase() == s)
+                    });
+                    matches.then_some(suffix.len())
+                })
+            })
+            .flatten()
+        {
+            k + 1 + len
+        } else if is_letter(c)
+            || (!c.is_alphanumeric() && !is_newline(c) && next.is_some_and(is_letter))
+        {
+            let mut j = k + 1;
+            while j < n && is_letter(chars[j].1) {
+                j += 1;
+            }
+            j
+        } else if c.is_numeric() {
+            let mut j = k + 1;
+            while j < n && j - k < 3 && chars[j].1.is_numeric() {
+                j += 1;
+            }
+            j
+        } else if is_punct(c) || (c == ' ' && next.is_some_and(is_punct)) {
+            let mut j = k + 1;
+            while j < n && is_punct(chars[j].1) {
+                j += 1;
+            }
+            while j < n && is_newline(chars[j].1) {
+                j += 1;
+            }
+            j
+        } else {
+            let mut j = k;
+            while j < n && chars[j].1.is_whitespace() {
+                j += 1;
+            }
+            if let Some(last_newline) = (k..j).rev().find(|&m| is_newline(chars[m].1)) {
+                last_newline + 1
+            } else if j < n && j - k > 1 {
+                j - 1
+            } else {
+                j
+            }
+        };
+
+        pieces.push(&text[offset(k)..offset(end)]);
+        k = end;
+    }
+
+    pieces
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// All single bytes plus the given merges, ranked in order after them
+    fn vocab(merges: &[&str]) -> BpeTokenizer {
+        let mut ranks: HashMap<Vec<u8>, u32> = (0..=u8::MAX).map(|b| (vec![b], b as u32)).collect();
+        for (i, merge) in merges.iter().enumerate() {
+            ranks.insert(merge.as_bytes().to_vec(), 256 + i as u32);
+        }
+        BpeTokenizer::new(ranks).unwrap()
+    }
+
+    #[test]
+    fn test_pretokenize_words_and_punctuation() {
+        assert_eq!(
+            pretokenize("Hello, world! It's 12345 ok"),
+            vec!["Hello", ",", " world", "!", " It", "'s", " ", "123", "45", " ok"]
+        );
+    }
+
+    #[test]
+    fn test_pretokenize_whitespace() {
+        assert_eq!(pretokenize("a  b\n\nc"), vec!["a", " ", " b", "\n\n", "c"]);
+        assert_eq!(pretokenize("fn x() {}"), vec!["fn", " x", "()", " {}"]);
+    }
+
+    #[test]
+    fn test_pretokenize_letter_numerals() {
+        assert_eq!(
+            pretokenize("Chapter Ⅻ: naïve café"),
+            vec!["Chapter", " ", "Ⅻ", ":", " naïve", " café"]
+        );
+    }
+
+    /// Splits produced by tiktoken's cl100k_base regex
+    #[test]
+    fn test_cl100k_pieces_match_tiktoken() {
+        let cases: [(&str, &[&str]); 5] = [
+            (
+                "Chapter Ⅻ: naïve café",
+                &["Chapter", " ", "Ⅻ", ":", " naïve", " café"],
+            ),
+            // Combining vowel signs are marks, not letters
+            (
+                "नमस्ते दुनिया",
+                &["नमस", "\u{94d}त", "\u{947}", " द", "\u{941}न", "िय", "ा"],
+            ),
+            ("x  \n\t  y   ", &["x", "  \n", "\t ", " y", "   "]),
+            ("WE'LL SEE", &["WE", "'LL", " SEE"]),
+            (
+                "x\u{a0}\u{a0}= 1",
+                &["x", "\u{a0}", "\u{a0}", "=", " ", "1"],
+            ),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(cl100k_pieces(text), expected, "{:?}", text);
+        }
+    }
+
+    #[test]
+    fn test_pretokenize_round_trips() {
+        let text = "Ünïcödé 日本語 text\twith  odd\r\nspacing... and 'quotes'";
+        assert_eq!(pretokenize(text).concat(), text);
+    }
+
+    #[test]
+    fn test_bpe_merges_lowest_rank_first() {
+        let tokenizer = vocab(&["ab", "bc", "abc"]);
+        // "ab" outranks "bc", then "ab"+"c" -> "abc"
+        assert_eq!(tokenizer.encode("abc"), vec![258]);
+        assert_eq!(tokenizer.encode("abd"), vec![256, b'd' as u32]);
+        assert_eq!(tokenizer.encode("xbc"), vec![b'x' as u32, 257]);
+    }
+
+    #[test]
+    fn test_bpe_counts_per_piece() {
+        let tokenizer = vocab(&[
+            "he", "ll", "hell", "hello", " w", "or", " wor", "ld", " world",
+        ]);
+        assert_eq!(tokenizer.encode("hello world"), vec![259, 264]);
+        assert_eq!(
+            tokenizer.encode("hello worlds"),
+            vec![259, 264, b's' as u32]
+        );
+        assert_eq!(tokenizer.count_tokens("hello world!"), 3);
+    }
+
+    #[test]
+    fn test_bpe_requires_all_bytes() {
+        let err = BpeTokenizer::new(HashMap::new()).unwrap_err();
+        assert!(err.to_string().contains("0x00"));
+    }
+
+    #[test]
+    fn test_from_tiktoken() {
+        let mut content: String = (0..=u8::MAX)
+            .map(|b| {
+                format!(
+                    "{} {}\n",
+                    base64::engine::general_purpose::STANDARD.encode([b]),
+                    b
+                )
+            })
+            .collect();
+        content.push_str("aGk= 256\n"); // "hi"
+
+        let tokenizer = BpeTokenizer::from_tiktoken(&content).unwrap();
+        assert_eq!(tokenizer.encode("hi"), vec![256]);
+
+        assert!(BpeTokenizer::from_tiktoken("not-base64! x\n").is_err());
+    }
+
+    /// Exact cl100k_base encodings, as produced by tiktoken
+    #[test]
+    fn test_cl100k_exact_counts() {
+        let tokenizer = BpeTokenizer::cl100k();
+
+        assert_eq!(
+            tokenizer.encode("tiktoken is great!"),
+            vec![83, 1609, 5963, 374, 2294, 0]
+        );
+        assert_eq!(
+            tokenizer.encode("2 + 2 = 4"),
+            vec![17, 489, 220, 17, 284, 220, 19]
+        );
+        assert_eq!(
+            tokenizer.encode("antidisestablishmentarianism"),
+            vec![519, 85342, 34500, 479, 8997, 2191]
+        );
+
+        let cases = [
+            ("Hello, world!", 4),
+            ("The quick brown fox jumps over the lazy dog.", 10),
+            // Non-ASCII: multi-byte characters split across byte-level tokens
+            ("お誕生日おめでとう", 9),
+            // Code
+            ("fn main() {}", 4),
+            ("Chapter Ⅻ: naïve café", 8),
+            ("नमस्ते दुनिया", 13),
+            ("x  \n\t  y   ", 5),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(tokenizer.count_tokens(text), expected, "{:?}", text);
+        }
+    }
+
+    #[test]
+    fn test_estimate_close_to_cl100k() {
+        let cases = [
+            "Hello, world!",
+            "The quick brown fox jumps over the lazy dog.",
+            "fn main() {\n    println!(\"hi\");\n}",
+        ];
+        for text in cases {
+            let expected = BpeTokenizer::cl100k().count_tokens(text);
+            let estimate = EstimateCounter.count_tokens(text);
+            assert!(
+                estimate.abs_diff(expected) <= 2,
+                "{:?}: estimated {}, cl100k gives {}",
+                text,
+                estimate,
+                expected
+            );
+        }
+    }
+}
@@ -111,21 +111,30 @@ pub use serve::{
 pub use stack::{
     // Diagnostics types
     render_dashboard,
+    render_dashboard_by_layer,
+    render_dashboard_colored,
+    render_dot,
     AndonStatus,
     Anomaly,
     AnomalyCategory,
+    AnomalyTrend,
+    ComponentHistory,
     ComponentMetrics,
     ComponentNode,
     // Quality types
     ComponentQuality,
     ErrorForecaster,
     ForecastMetrics,
+    GraphDirection,
     GraphMetrics,
     HealthStatus,
     HealthSummary,
+    HealthThresholds,
     HeroImageResult,
     ImageFormat,
     IsolationForest,
+    LocalOutlierFactor,
+    MetricsSnapshot,
     QualityChecker,
     QualityGrade,
     QualityIssue,
@@ -177,6 +177,10 @@ enum Commands {
 
     /// Validate semantic equivalence (Phase 4: Validation)
     Validate {
+        /// Validate something other than the transpiled project
+        #[command(subcommand)]
+        target: Option<ValidateCommand>,
+
         /// Trace syscalls for comparison
         #[arg(long)]
         trace_syscalls: bool,
@@ -843,12 +847,26 @@ enum ExperimentCommand {
     },
 }
 
+/// Validate subcommands
+#[derive(Subcommand)]
+enum ValidateCommand {
+    /// Validate generated content with the Jidoka content validator
+    Content {
+        /// Content type (hlo, dlo, bch, blp, pdm, api)
+        #[arg(long, short = 't')]
+        r#type: String,
+
+        /// File to validate
+        file: std::path::PathBuf,
+    },
+}
+
 /// Content creation subcommands
 #[derive(Subcommand)]
 enum ContentCommand {
     /// Emit a prompt for content generation
     Emit {
-        /// Content type (hlo, dlo, bch, blp, pdm)
+        /// Content type (hlo, dlo, bch, blp, pdm, api)
         #[arg(long, short = 't')]
         r#type: String,
 
@@ -883,7 +901,7 @@ enum ContentCommand {
 
     /// Validate generated content
     Validate {
-        /// Content type (hlo, dlo, bch, blp, pdm)
+        /// Content type (hlo, dlo, bch, blp, pdm, api)
         #[arg(long, short = 't')]
         r#type: String,
 
@@ -1091,6 +1109,14 @@ fn main() -> anyhow::Result<()> {
             cmd_optimize(enable_gpu, enable_simd, profile, gpu_threshold)?;
         }
         Commands::Validate {
+            target: Some(ValidateCommand::Content { r#type, file }),
+            ..
+        } => {
+            info!("Validating content");
+            cmd_content_validate(&r#type, &file, false)?;
+        }
+        Commands::Validate {
+            target: None,
             trace_syscalls,
             diff_output,
             run_original_tests,
@@ -1612,7 +1638,14 @@ fn check_transpile_prerequisites(state: &WorkflowState) -> anyhow::Result<Batuta
         anyhow::bail!("No configuration file found");
     }
 
-    let config = BatutaConfig::load(&config_path)?;
+    let config = BatutaConfig::load_layered(&config_path)?;
+    if let Err(errors) = config.validate() {
+        println!("{}", "❌ Invalid batuta.toml:".bright_red().bold());
+        for error in &errors {
+            println!("  {} {}", "•".bright_red(), error);
+        }
+        anyhow::bail!("Invalid configuration ({} error(s))", errors.len());
+    }
     println!("{} Loaded configuration", "✓".bright_green());
     Ok(config)
 }
@@ -4421,7 +4454,9 @@ fn cmd_stack_publish_status(
     workspace: Option<PathBuf>,
     clear_cache: bool,
 ) -> anyhow::Result<()> {
-    use stack::publish_status::{format_report_json, PublishStatusCache, PublishStatusScanner};
+    use stack::publish_status::{
+        format_report_json, format_report_markdown, PublishStatusCache, PublishStatusScanner,
+    };
 
     // Workspace is parent directory (where all crates live)
     let workspace_path = workspace.unwrap_or_else(|| {
@@ -4432,7 +4467,7 @@ fn cmd_stack_publish_status(
             .unwrap_or_else(|| PathBuf::from(".."))
     });
 
-    if !matches!(format, StackOutputFormat::Json) {
+    if matches!(format, StackOutputFormat::Text) {
         println!("{}", "📦 PAIML Stack Publish Status".bright_cyan().bold());
         println!("{}", "═".repeat(65).dimmed());
         if clear_cache {
@@ -4457,7 +4492,10 @@ fn cmd_stack_publish_status(
         StackOutputFormat::Json => {
             println!("{}", format_report_json(&report)?);
         }
-        StackOutputFormat::Text | StackOutputFormat::Markdown => {
+        StackOutputFormat::Markdown => {
+            print!("{}", format_report_markdown(&report));
+        }
+        StackOutputFormat::Text => {
             // Colorized output
             println!(
                 "{:<20} {:>10} {:>10} {:>10} {:>12}",
@@ -4480,6 +4518,8 @@ fn cmd_stack_publish_status(
                     stack::PublishAction::NeedsCommit => "📝 commit".yellow(),
                     stack::PublishAction::LocalBehind => "⚠️  behind".yellow(),
                     stack::PublishAction::NotPublished => "🆕 new".cyan(),
+                    stack::PublishAction::NeedsRepublish => "🔁 REPUBLISH".bright_red().bold(),
+                    stack::PublishAction::NeedsPush => "⬆️  push first".yellow(),
                     stack::PublishAction::Error => "❌ error".red(),
                 };
 
@@ -5188,7 +5228,14 @@ fn cmd_content_validate(
     println!();
     println!("{}", result.format_display());
 
-    if result.has_critical() || result.has_errors() {
+    let failed = result.has_critical() || result.has_errors();
+    if failed {
+        println!("{}", "Result: FAIL".red().bold());
+    } else {
+        println!("{}", "Result: PASS".green().bold());
+    }
+
+    if failed {
         anyhow::bail!("Validation failed with critical errors");
     }
 
@@ -32,14 +32,24 @@
 //! let c = a.add(&b).unwrap();
 //! ```
 
-use std::collections::HashMap;
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
 
 /// NumPy operation types
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[allow(dead_code)]
 pub enum NumPyOp {
-    /// Array creation: np.array, np.zeros, np.ones
+    /// Array creation: np.array
     Array,
+    /// Zero-filled array: np.zeros
+    Zeros,
+    /// One-filled array: np.ones
+    Ones,
+    /// Evenly stepped range: np.arange
+    Arange,
+    /// Evenly spaced samples: np.linspace
+    Linspace,
     /// Element-wise addition: np.add, a + b
     Add,
     /// Element-wise subtraction: np.subtract, a - b
@@ -65,6 +75,45 @@ pub enum NumPyOp {
 }
 
 impl NumPyOp {
+    /// Parse a Python arithmetic operator
+    ///
+    /// Returns the operation and whether it is in place, so `a = a + b`
+    /// (`"+"`) and `a += b` (`"+="`) convert differently.
+    pub fn from_operator(token: &str) -> Option<(NumPyOp, bool)> {
+        let (symbol, in_place) = match token.strip_suffix('=') {
+            Some(symbol) => (symbol, true),
+            None => (token, false),
+        };
+        let op = match symbol {
+            "+" => NumPyOp::Add,
+            "-" => NumPyOp::Subtract,
+            "*" => NumPyOp::Multiply,
+            "/" => NumPyOp::Divide,
+            "@" => NumPyOp::Dot,
+            _ => return None,
+        };
+        Some((op, in_place))
+    }
+
+    /// Template placeholders filled by the operation's arguments, in order
+    pub fn params(&self) -> &'static [&'static str] {
+        match self {
+            NumPyOp::Array => &["values"],
+            NumPyOp::Zeros | NumPyOp::Ones => &["n"],
+            NumPyOp::Arange => &["start", "stop", "step"],
+            NumPyOp::Linspace => &["start", "stop", "num"],
+            NumPyOp::Add
+            | NumPyOp::Subtract
+            | NumPyOp::Multiply
+            | NumPyOp::Divide
+            | NumPyOp::Dot => &["lhs", "rhs"],
+            NumPyOp::Sum | NumPyOp::Mean | NumPyOp::Max | NumPyOp::Min | NumPyOp::Transpose => {
+                &["array"]
+            }
+            NumPyOp::Reshape => &["array", "rows", "cols"],
+        }
+    }
+
     /// Get the operation complexity for MoE routing
     pub fn complexity(&self) -> crate::backend::OpComplexity {
         use crate::backend::OpComplexity;
@@ -79,11 +128,84 @@ impl NumPyOp {
             // Dot product and matrix ops are High complexity
             NumPyOp::Dot => OpComplexity::High,
             // Structural operations don't need backend selection
-            NumPyOp::Array | NumPyOp::Reshape | NumPyOp::Transpose => OpComplexity::Low,
+            NumPyOp::Array
+            | NumPyOp::Zeros
+            | NumPyOp::Ones
+            | NumPyOp::Arange
+            | NumPyOp::Linspace
+            | NumPyOp::Reshape
+            | NumPyOp::Transpose => OpComplexity::Low,
+        }
+    }
+}
+
+/// Operand shape hint used to pick between `Vector` and `Matrix` templates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    /// 0-D value
+    Scalar,
+    /// 1-D array
+    Vector { len: usize },
+    /// 2-D array
+    Matrix { rows: usize, cols: usize },
+}
+
+impl Shape {
+    /// Dimensions, outermost first (NumPy's `.shape`)
+    fn dims(&self) -> Vec<usize> {
+        match *self {
+            Shape::Scalar => vec![],
+            Shape::Vector { len } => vec![len],
+            Shape::Matrix { rows, cols } => vec![rows, cols],
         }
     }
+
+    fn from_dims(dims: &[usize]) -> Self {
+        match *dims {
+            [] => Shape::Scalar,
+            [len] => Shape::Vector { len },
+            [rows, cols] => Shape::Matrix { rows, cols },
+            _ => unreachable!("shapes have at most two dimensions"),
+        }
+    }
+}
+
+impl std::fmt::Display for Shape {
+    /// Formats like a NumPy shape tuple: `()`, `(3,)`, `(2, 3)`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Shape::Scalar => write!(f, "()"),
+            Shape::Vector { len } => write!(f, "({},)", len),
+            Shape::Matrix { rows, cols } => write!(f, "({}, {})", rows, cols),
+        }
+    }
+}
+
+/// Nested NumPy expression, e.g. `np.sum(np.add(a, b))`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    /// Variable or literal, emitted verbatim
+    Var(String),
+    /// Operation applied to arguments ordered as in [`NumPyOp::params`]
+    Call { op: NumPyOp, args: Vec<Expr> },
+}
+
+impl Expr {
+    /// Variable or literal
+    pub fn var(name: impl Into<String>) -> Self {
+        Expr::Var(name.into())
+    }
+
+    /// Operation call
+    pub fn call(op: NumPyOp, args: Vec<Expr>) -> Self {
+        Expr::Call { op, args }
+    }
 }
 
+/// Call nesting depth beyond which [`NumPyConverter::convert_expr`] hoists
+/// subexpressions into `let` bindings
+pub const DEFAULT_MAX_EXPR_DEPTH: usize = 3;
+
 /// Trueno equivalent operation
 #[derive(Debug, Clone)]
 pub struct TruenoOp {
@@ -95,10 +217,97 @@ pub struct TruenoOp {
     pub complexity: crate::backend::OpComplexity,
 }
 
+/// Machine-readable conversion map, ordered by operation name
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConversionReport {
+    pub operations: Vec<ConversionReportEntry>,
+}
+
+/// One operation in a [`ConversionReport`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConversionReportEntry {
+    /// NumPy operation name (e.g. `"Add"`)
+    pub op: String,
+    /// MoE complexity (`"Low"`, `"Medium"`, `"High"`)
+    pub complexity: String,
+    /// Rust code template
+    pub template: String,
+    /// Required imports
+    pub imports: Vec<String>,
+}
+
+/// Placeholder substitutions for a template (`{lhs}`, `{rhs}`, `{values}`, ...)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Bindings {
+    /// Variable the result is bound to, if any
+    target: Option<String>,
+    /// Placeholder name (without braces) to replacement
+    values: HashMap<String, String>,
+    /// Operand shapes of a binary operation, if known
+    shapes: Option<(Shape, Shape)>,
+    /// Mutate `{lhs}` in place instead of producing a new value
+    in_place: bool,
+    /// Elements processed, if known, for the backend comment
+    data_size: Option<usize>,
+}
+
+impl Bindings {
+    /// Create empty bindings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bind the result to `let {target} = ...;`
+    pub fn with_target(mut self, target: impl Into<String>) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+
+    /// Substitute `{name}` with `value`
+    pub fn with(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.values.insert(name.into(), value.into());
+        self
+    }
+
+    /// Emit the in-place form (`a += b`) where the operation has one
+    pub fn with_in_place(mut self) -> Self {
+        self.in_place = true;
+        self
+    }
+
+    /// Record the `{lhs}`/`{rhs}` shapes so broadcasting can be diagnosed
+    pub fn with_shapes(mut self, lhs: Shape, rhs: Shape) -> Self {
+        self.shapes = Some((lhs, rhs));
+        self
+    }
+
+    /// Record how many elements the op processes, adding a comment that
+    /// explains the backend choice
+    pub fn with_data_size(mut self, data_size: usize) -> Self {
+        self.data_size = Some(data_size);
+        self
+    }
+
+    /// Fill this binding's placeholders into `template`
+    pub fn render(&self, template: &str) -> String {
+        self.values
+            .iter()
+            .fold(template.to_string(), |code, (name, value)| {
+                code.replace(&format!("{{{}}}", name), value)
+            })
+    }
+}
+
 /// NumPy to Trueno converter
 pub struct NumPyConverter {
     /// Operation mapping
     op_map: HashMap<NumPyOp, TruenoOp>,
+    /// 2-D operation mapping, for ops whose matrix form differs
+    matrix_op_map: HashMap<NumPyOp, TruenoOp>,
+    /// In-place operation mapping (`a += b`), mutating `{lhs}`
+    in_place_op_map: HashMap<NumPyOp, TruenoOp>,
+    /// Maximum call nesting kept inline by `convert_expr`
+    max_expr_depth: usize,
     /// Backend selector for MoE routing
     backend_selector: crate::backend::BackendSelector,
 }
@@ -124,6 +333,56 @@ impl NumPyConverter {
             },
         );
 
+        op_map.insert(
+            NumPyOp::Zeros,
+            TruenoOp {
+                code_template: "Vector::zeros({n})".to_string(),
+                imports: vec!["use trueno::Vector;".to_string()],
+                complexity: crate::backend::OpComplexity::Low,
+            },
+        );
+
+        op_map.insert(
+            NumPyOp::Ones,
+            TruenoOp {
+                code_template: "Vector::ones({n})".to_string(),
+                imports: vec!["use trueno::Vector;".to_string()],
+                complexity: crate::backend::OpComplexity::Low,
+            },
+        );
+
+        // Range fill: ceil((stop - start) / step) elements
+        op_map.insert(
+            NumPyOp::Arange,
+            TruenoOp {
+                code_template: "Vector::from_slice(\
+                    &(0..((({stop}) as f32 - ({start}) as f32) / ({step}) as f32).ceil() as usize)\
+                    .map(|i| ({start}) as f32 + i as f32 * ({step}) as f32)\
+                    .collect::<Vec<f32>>())"
+                    .to_string(),
+                imports: vec!["use trueno::Vector;".to_string()],
+                complexity: crate::backend::OpComplexity::Low,
+            },
+        );
+
+        // Both endpoints included, as in NumPy's default `endpoint=True`
+        op_map.insert(
+            NumPyOp::Linspace,
+            TruenoOp {
+                code_template: "{\n\
+                    let mut values = Vec::with_capacity({num});\n\
+                    let step = (({stop}) as f32 - ({start}) as f32) / (({num}) as f32 - 1.0).max(1.0);\n\
+                    for i in 0..{num} {\n\
+                    values.push(({start}) as f32 + i as f32 * step);\n\
+                    }\n\
+                    Vector::from_slice(&values)\n\
+                    }"
+                    .to_string(),
+                imports: vec!["use trueno::Vector;".to_string()],
+                complexity: crate::backend::OpComplexity::Low,
+            },
+        );
+
         // Element-wise operations
         op_map.insert(
             NumPyOp::Add,
@@ -152,6 +411,15 @@ impl NumPyConverter {
             },
         );
 
+        op_map.insert(
+            NumPyOp::Divide,
+            TruenoOp {
+                code_template: "{lhs}.div(&{rhs}).unwrap()".to_string(),
+                imports: vec!["use trueno::Vector;".to_string()],
+                complexity: crate::backend::OpComplexity::Low,
+            },
+        );
+
         // Reductions
         op_map.insert(
             NumPyOp::Sum,
@@ -162,6 +430,61 @@ impl NumPyConverter {
             },
         );
 
+        op_map.insert(
+            NumPyOp::Mean,
+            TruenoOp {
+                code_template: "{array}.mean()".to_string(),
+                imports: vec!["use trueno::Vector;".to_string()],
+                complexity: crate::backend::OpComplexity::Medium,
+            },
+        );
+
+        op_map.insert(
+            NumPyOp::Max,
+            TruenoOp {
+                code_template: "{array}.max()".to_string(),
+                imports: vec!["use trueno::Vector;".to_string()],
+                complexity: crate::backend::OpComplexity::Medium,
+            },
+        );
+
+        op_map.insert(
+            NumPyOp::Min,
+            TruenoOp {
+                code_template: "{array}.min()".to_string(),
+                imports: vec!["use trueno::Vector;".to_string()],
+                complexity: crate::backend::OpComplexity::Medium,
+            },
+        );
+
+        // Structural operations
+        op_map.insert(
+            NumPyOp::Reshape,
+            TruenoOp {
+                code_template:
+                    "Matrix::from_vec({rows}, {cols}, {array}.as_slice().to_vec()).unwrap()"
+                        .to_string(),
+                imports: vec![
+                    "use trueno::Matrix;".to_string(),
+                    "use trueno::Vector;".to_string(),
+                ],
+                complexity: crate::backend::OpComplexity::Low,
+            },
+        );
+
+        op_map.insert(
+            NumPyOp::Transpose,
+            TruenoOp {
+                code_template: "{array}.transpose()".to_string(),
+                imports: vec![
+                    "use trueno::Matrix;".to_string(),
+                    "use trueno::Vector;".to_string(),
+                ],
+                complexity: crate::backend::OpComplexity::Low,
+            },
+        );
+
+        // Matrix operations
         op_map.insert(
             NumPyOp::Dot,
             TruenoOp {
@@ -171,21 +494,352 @@ impl NumPyConverter {
             },
         );
 
+        let mut matrix_op_map = HashMap::new();
+
+        matrix_op_map.insert(
+            NumPyOp::Array,
+            TruenoOp {
+                code_template: "Matrix::from_slice({rows}, {cols}, &[{values}]).unwrap()"
+                    .to_string(),
+                imports: vec!["use trueno::Matrix;".to_string()],
+                complexity: crate::backend::OpComplexity::Low,
+            },
+        );
+
+        matrix_op_map.insert(
+            NumPyOp::Dot,
+            TruenoOp {
+                code_template: "{lhs}.matmul(&{rhs}).unwrap()".to_string(),
+                imports: vec!["use trueno::Matrix;".to_string()],
+                complexity: crate::backend::OpComplexity::High,
+            },
+        );
+
+        matrix_op_map.insert(
+            NumPyOp::Transpose,
+            TruenoOp {
+                code_template: "{array}.transpose()".to_string(),
+                imports: vec!["use trueno::Matrix;".to_string()],
+                complexity: crate::backend::OpComplexity::Low,
+            },
+        );
+
+        // In-place element-wise operations: no new allocation
+        let mut in_place_op_map = HashMap::new();
+        for (op, method) in [
+            (NumPyOp::Add, "add_assign"),
+            (NumPyOp::Subtract, "sub_assign"),
+            (NumPyOp::Multiply, "mul_assign"),
+            (NumPyOp::Divide, "div_assign"),
+        ] {
+            in_place_op_map.insert(
+                op,
+                TruenoOp {
+                    code_template: format!("{{lhs}}.{}(&{{rhs}}).unwrap()", method),
+                    imports: vec!["use trueno::Vector;".to_string()],
+                    complexity: crate::backend::OpComplexity::Low,
+                },
+            );
+        }
+
         Self {
             op_map,
+            matrix_op_map,
+            in_place_op_map,
+            max_expr_depth: DEFAULT_MAX_EXPR_DEPTH,
             backend_selector: crate::backend::BackendSelector::new(),
         }
     }
 
+    /// Set the call nesting depth kept inline by [`Self::convert_expr`]
+    pub fn with_max_expr_depth(mut self, depth: usize) -> Self {
+        self.max_expr_depth = depth.max(1);
+        self
+    }
+
+    /// Convert a nested expression into chained Trueno calls
+    ///
+    /// `np.sum(np.add(a, b))` becomes `a.add(&b).unwrap().sum()`. Once a
+    /// subexpression reaches the maximum depth it is hoisted into a
+    /// `let {op}_{n} = ...;` line so long expressions stay readable.
+    pub fn convert_expr(&self, expr: &Expr) -> Result<String> {
+        let mut lets = Vec::new();
+        let (code, _) = self.emit_expr(expr, &mut lets)?;
+        lets.push(code);
+        Ok(lets.join("\n"))
+    }
+
+    /// Emit `expr`, returning its code and call depth
+    fn emit_expr(&self, expr: &Expr, lets: &mut Vec<String>) -> Result<(String, usize)> {
+        let (op, args) = match expr {
+            Expr::Var(name) => return Ok((name.clone(), 0)),
+            Expr::Call { op, args } => (op, args),
+        };
+
+        let Some(trueno_op) = self.convert(op) else {
+            bail!("unsupported NumPy operation: {:?}", op);
+        };
+        let params = op.params();
+        if args.len() != params.len() {
+            bail!(
+                "{:?} takes {} argument(s) ({}), got {}",
+                op,
+                params.len(),
+                params.join(", "),
+                args.len()
+            );
+        }
+
+        let mut bindings = Bindings::new();
+        let mut depth = 0;
+        for (param, arg) in params.iter().zip(args) {
+            let (mut code, arg_depth) = self.emit_expr(arg, lets)?;
+            if arg_depth >= self.max_expr_depth {
+                let name = match arg {
+                    Expr::Call { op, .. } => format!("{:?}_{}", op, lets.len()).to_lowercase(),
+                    Expr::Var(_) => unreachable!("variables have depth 0"),
+                };
+                lets.push(format!("let {} = {};", name, code));
+                code = name;
+            } else {
+                depth = depth.max(arg_depth);
+            }
+            bindings = bindings.with(*param, code);
+        }
+
+        Ok((bindings.render(&trueno_op.code_template), depth + 1))
+    }
+
     /// Convert a NumPy operation to Trueno
     pub fn convert(&self, op: &NumPyOp) -> Option<&TruenoOp> {
         self.op_map.get(op)
     }
 
-    /// Get recommended backend for an operation
+    /// Convert an in-place NumPy operation (`a += b`) to Trueno
+    ///
+    /// Only element-wise arithmetic has an in-place form.
+    pub fn convert_in_place(&self, op: &NumPyOp) -> Option<&TruenoOp> {
+        self.in_place_op_map.get(op)
+    }
+
+    /// Convert a NumPy operation for operands of the given shape
+    ///
+    /// 2-D operands select the `Matrix` template (e.g. `matmul` for `Dot`)
+    /// with `{rows}`/`{cols}` filled in; ops without a matrix form fall back
+    /// to the `Vector` template.
+    pub fn convert_with_shape(&self, op: &NumPyOp, shape: Shape) -> Option<TruenoOp> {
+        match shape {
+            Shape::Scalar | Shape::Vector { .. } => self.convert(op).cloned(),
+            Shape::Matrix { rows, cols } => {
+                let trueno_op = self.matrix_op_map.get(op).or_else(|| self.op_map.get(op))?;
+                Some(TruenoOp {
+                    code_template: trueno_op
+                        .code_template
+                        .replace("{rows}", &rows.to_string())
+                        .replace("{cols}", &cols.to_string()),
+                    ..trueno_op.clone()
+                })
+            }
+        }
+    }
+
+    /// Result shape of an element-wise op, following NumPy broadcasting rules
+    ///
+    /// Trailing dimensions are compared pairwise; each pair must be equal or
+    /// contain a 1, and missing leading dimensions count as 1.
+    pub fn check_broadcast(lhs: &Shape, rhs: &Shape) -> Result<Shape, String> {
+        let (lhs_dims, rhs_dims) = (lhs.dims(), rhs.dims());
+        let ndim = lhs_dims.len().max(rhs_dims.len());
+        let dim = |dims: &[usize], i: usize| {
+            (i + dims.len())
+                .checked_sub(ndim)
+                .map_or(1, |index| dims[index])
+        };
+
+        let mut result = Vec::with_capacity(ndim);
+        for i in 0..ndim {
+            let (l, r) = (dim(&lhs_dims, i), dim(&rhs_dims, i));
+            if l != r && l != 1 && r != 1 {
+                return Err(format!(
+                    "operands could not be broadcast together with shapes {} {}: \
+                     dimension {} is {} vs {}",
+                    lhs, rhs, i, l, r
+                ));
+            }
+            result.push(l.max(r));
+        }
+        Ok(Shape::from_dims(&result))
+    }
+
+    /// Render a sequence of operations as one Rust snippet
+    ///
+    /// Imports from all operations are collected into a single sorted,
+    /// deduplicated header. Operations without a template are emitted as
+    /// comments so the output still lines up with the input. Element-wise
+    /// ops whose bindings carry shapes get a warning comment when NumPy would
+    /// broadcast them, since Trueno requires matching lengths. Bindings with a
+    /// data size get a comment explaining the backend choice. In-place
+    /// bindings mutate `{lhs}` and ignore any target.
+    pub fn emit_module(&self, ops: &[(NumPyOp, Bindings)]) -> String {
+        let mut imports = BTreeSet::new();
+        let mut body = Vec::with_capacity(ops.len());
+
+        for (op, bindings) in ops {
+            let in_place = bindings
+                .in_place
+                .then(|| self.convert_in_place(op))
+                .flatten();
+            let Some(trueno_op) = in_place.or_else(|| self.convert(op)) else {
+                body.push(format!("// unsupported NumPy operation: {:?}", op));
+                continue;
+            };
+            imports.extend(trueno_op.imports.iter().map(String::as_str));
+
+            if let Some(data_size) = bindings.data_size {
+                let reason =
+                    self.explain_recommendation(op, data_size, crate::backend::DEFAULT_DTYPE_BYTES);
+                body.push(format!("// Backend: {}", reason));
+            }
+            if let Some(warning) = Self::broadcast_warning(op, bindings) {
+                body.push(warning);
+            }
+            let expr = bindings.render(&trueno_op.code_template);
+            body.push(match &bindings.target {
+                Some(target) if in_place.is_none() => format!("let {} = {};", target, expr),
+                _ => format!("{};", expr),
+            });
+        }
+
+        let mut module = String::new();
+        for import in &imports {
+            module.push_str(import);
+            module.push('\n');
+        }
+        if !imports.is_empty() {
+            module.push('\n');
+        }
+        for line in body {
+            module.push_str(&line);
+            module.push('\n');
+        }
+        module
+    }
+
+    /// Comment describing implicit broadcasting in an element-wise op
+    fn broadcast_warning(op: &NumPyOp, bindings: &Bindings) -> Option<String> {
+        let element_wise = matches!(
+            op,
+            NumPyOp::Add | NumPyOp::Subtract | NumPyOp::Multiply | NumPyOp::Divide
+        );
+        let (lhs, rhs) = bindings.shapes.filter(|_| element_wise)?;
+        match Self::check_broadcast(&lhs, &rhs) {
+            Ok(_) if lhs == rhs => None,
+            Ok(result) => Some(format!(
+                "// WARNING: NumPy broadcasts {} with {} to {}; Trueno requires matching shapes",
+                lhs, rhs, result
+            )),
+            Err(e) => Some(format!("// ERROR: {}", e)),
+        }
+    }
+
+    /// Get recommended backend for an operation on f64 data
     pub fn recommend_backend(&self, op: &NumPyOp, data_size: usize) -> crate::backend::Backend {
-        self.backend_selector
-            .select_with_moe(op.complexity(), data_size)
+        self.recommend_backend_for_dtype(op, data_size, crate::backend::DEFAULT_DTYPE_BYTES)
+    }
+
+    /// Get recommended backend for an operation on `dtype_bytes`-wide elements
+    ///
+    /// e.g. 8 for `float64`, 4 for `float32`, 1 for `int8`.
+    pub fn recommend_backend_for_dtype(
+        &self,
+        op: &NumPyOp,
+        data_size: usize,
+        dtype_bytes: usize,
+    ) -> crate::backend::Backend {
+        self.backend_selector.select_with_moe_bytes(
+            op.complexity(),
+            data_size,
+            data_size.saturating_mul(dtype_bytes),
+        )
+    }
+
+    /// Explain [`Self::recommend_backend_for_dtype`] in terms of the MoE
+    /// thresholds it applies, e.g. `GPU: 1000000 elements > 100000 GPU threshold`
+    pub fn explain_recommendation(
+        &self,
+        op: &NumPyOp,
+        data_size: usize,
+        dtype_bytes: usize,
+    ) -> String {
+        let complexity = op.complexity();
+        let thresholds = self.backend_selector.moe_thresholds(complexity);
+        let backend = self.recommend_backend_for_dtype(op, data_size, dtype_bytes);
+
+        let reason = if backend == crate::backend::Backend::Scalar {
+            format!(
+                "{} elements <= {} SIMD threshold",
+                data_size, thresholds.simd
+            )
+        } else {
+            match thresholds.gpu {
+                None => format!(
+                    "{} elements > {} SIMD threshold; {:?} ops never use the GPU",
+                    data_size, thresholds.simd, complexity
+                ),
+                Some(gpu) if backend != crate::backend::Backend::SIMD && data_size > gpu => {
+                    format!("{} elements > {} GPU threshold", data_size, gpu)
+                }
+                // Narrower elements move fewer bytes per FLOP, so they reach
+                // the GPU threshold early; wider ones fall short of it
+                Some(gpu) if backend != crate::backend::Backend::SIMD => format!(
+                    "{} elements of {} bytes reach the {} GPU threshold",
+                    data_size, dtype_bytes, gpu
+                ),
+                Some(gpu) if data_size > gpu => format!(
+                    "{} elements of {} bytes fall short of the {} GPU threshold",
+                    data_size, dtype_bytes, gpu
+                ),
+                Some(gpu) => format!(
+                    "{} elements > {} SIMD threshold, <= {} GPU threshold",
+                    data_size, thresholds.simd, gpu
+                ),
+            }
+        };
+        format!("{}: {}", backend, reason)
+    }
+
+    /// Explain the cost-model backend choice for an operation
+    ///
+    /// This is the transfer/compute view of [`crate::backend::BackendSelector::explain`];
+    /// [`Self::recommend_backend`] routes by MoE thresholds instead, so the
+    /// two can disagree (see [`Self::explain_recommendation`]).
+    ///
+    /// Transfer covers every operand plus the output at `dtype_bytes` per
+    /// element. Compute is one FLOP per element for Low complexity, two for
+    /// Medium, and `2·n^1.5` for High (a square matmul over `n` elements).
+    pub fn explain_backend(
+        &self,
+        op: &NumPyOp,
+        data_size: usize,
+        dtype_bytes: usize,
+    ) -> crate::backend::BackendDecision {
+        use crate::backend::OpComplexity;
+
+        let operands = op
+            .params()
+            .iter()
+            .filter(|p| matches!(**p, "lhs" | "rhs" | "array"))
+            .count();
+        let data_bytes = data_size
+            .saturating_mul(dtype_bytes)
+            .saturating_mul(operands + 1);
+        let n = data_size as f64;
+        let flops = match op.complexity() {
+            OpComplexity::Low => n,
+            OpComplexity::Medium => 2.0 * n,
+            OpComplexity::High => 2.0 * n.powf(1.5),
+        };
+        self.backend_selector.explain(data_bytes, flops as u64)
     }
 
     /// Get all available conversions
@@ -198,15 +852,37 @@ impl NumPyConverter {
         let mut report = String::from("NumPy → Trueno Conversion Map\n");
         report.push_str("================================\n\n");
 
-        for (op, trueno_op) in &self.op_map {
-            report.push_str(&format!("{:?}:\n", op));
-            report.push_str(&format!("  Complexity: {:?}\n", trueno_op.complexity));
-            report.push_str(&format!("  Template: {}\n", trueno_op.code_template));
-            report.push_str(&format!("  Imports: {}\n\n", trueno_op.imports.join(", ")));
+        for entry in self.report().operations {
+            report.push_str(&format!("{}:\n", entry.op));
+            report.push_str(&format!("  Complexity: {}\n", entry.complexity));
+            report.push_str(&format!("  Template: {}\n", entry.template));
+            report.push_str(&format!("  Imports: {}\n\n", entry.imports.join(", ")));
         }
 
         report
     }
+
+    /// Build the conversion map as structured data, sorted by operation name
+    pub fn report(&self) -> ConversionReport {
+        let mut operations: Vec<ConversionReportEntry> = self
+            .op_map
+            .iter()
+            .map(|(op, trueno_op)| ConversionReportEntry {
+                op: format!("{:?}", op),
+                complexity: format!("{:?}", trueno_op.complexity),
+                template: trueno_op.code_template.clone(),
+                imports: trueno_op.imports.clone(),
+            })
+            .collect();
+        operations.sort_by(|a, b| a.op.cmp(&b.op));
+
+        ConversionReport { operations }
+    }
+
+    /// Conversion map as JSON, for tooling
+    pub fn report_json(&self) -> serde_json::Value {
+        serde_json::to_value(self.report()).expect("ConversionReport is always serializable")
+    }
 }
 
 #[cfg(test)]
@@ -270,11 +946,14 @@ mod tests {
     // NUMPY OP ENUM TESTS
     // ============================================================================
 
-    #[test]
-    fn test_all_numpy_ops_exist() {
-        // Test all 13 variants can be constructed
-        let ops = vec![
+    /// Every `NumPyOp` variant
+    fn all_ops() -> Vec<NumPyOp> {
+        vec![
             NumPyOp::Array,
+            NumPyOp::Zeros,
+            NumPyOp::Ones,
+            NumPyOp::Arange,
+            NumPyOp::Linspace,
             NumPyOp::Add,
             NumPyOp::Subtract,
             NumPyOp::Multiply,
@@ -286,8 +965,13 @@ mod tests {
             NumPyOp::Min,
             NumPyOp::Reshape,
             NumPyOp::Transpose,
-        ];
-        assert_eq!(ops.len(), 12); // 12 operations tested
+        ]
+    }
+
+    #[test]
+    fn test_all_numpy_ops_exist() {
+        // Test all 16 variants can be constructed
+        assert_eq!(all_ops().len(), 16);
     }
 
     #[test]
@@ -311,6 +995,10 @@ mod tests {
             NumPyOp::Multiply,
             NumPyOp::Divide,
             NumPyOp::Array,
+            NumPyOp::Zeros,
+            NumPyOp::Ones,
+            NumPyOp::Arange,
+            NumPyOp::Linspace,
             NumPyOp::Reshape,
             NumPyOp::Transpose,
         ];
@@ -383,17 +1071,8 @@ mod tests {
     fn test_convert_all_mapped_ops() {
         let converter = NumPyConverter::new();
 
-        // Test all operations that should have mappings
-        let mapped_ops = vec![
-            NumPyOp::Array,
-            NumPyOp::Add,
-            NumPyOp::Subtract,
-            NumPyOp::Multiply,
-            NumPyOp::Sum,
-            NumPyOp::Dot,
-        ];
-
-        for op in mapped_ops {
+        // Every operation should have a mapping
+        for op in all_ops() {
             assert!(
                 converter.convert(&op).is_some(),
                 "Missing mapping for {:?}",
@@ -403,14 +1082,41 @@ mod tests {
     }
 
     #[test]
-    fn test_convert_unmapped_op() {
+    fn test_divide_conversion() {
+        let converter = NumPyConverter::new();
+        let op = converter.convert(&NumPyOp::Divide).unwrap();
+
+        assert_eq!(op.code_template, "{lhs}.div(&{rhs}).unwrap()");
+        assert_eq!(op.complexity, crate::backend::OpComplexity::Low);
+    }
+
+    #[test]
+    fn test_reduction_conversions() {
+        let converter = NumPyConverter::new();
+
+        for (op, template) in [
+            (NumPyOp::Mean, "{array}.mean()"),
+            (NumPyOp::Max, "{array}.max()"),
+            (NumPyOp::Min, "{array}.min()"),
+        ] {
+            let trueno_op = converter.convert(&op).unwrap();
+            assert_eq!(trueno_op.code_template, template);
+            assert_eq!(trueno_op.complexity, crate::backend::OpComplexity::Medium);
+        }
+    }
+
+    #[test]
+    fn test_structural_conversions() {
         let converter = NumPyConverter::new();
 
-        // Divide, Mean, etc. might not be mapped
-        // Just verify the function handles missing ops gracefully
-        let result = converter.convert(&NumPyOp::Divide);
-        // It's ok if this is None - we're testing the API works
-        let _ = result;
+        let reshape = converter.convert(&NumPyOp::Reshape).unwrap();
+        assert!(reshape.code_template.contains("{rows}"));
+        assert!(reshape.code_template.contains("{cols}"));
+        assert!(reshape.imports.iter().any(|i| i.contains("Matrix")));
+
+        let transpose = converter.convert(&NumPyOp::Transpose).unwrap();
+        assert!(transpose.code_template.contains("transpose"));
+        assert!(transpose.imports.iter().any(|i| i.contains("Matrix")));
     }
 
     #[test]
@@ -424,6 +1130,44 @@ mod tests {
         assert_eq!(op.complexity, crate::backend::OpComplexity::Low);
     }
 
+    #[test]
+    fn test_zeros_ones_conversion() {
+        let converter = NumPyConverter::new();
+
+        let zeros = converter.convert(&NumPyOp::Zeros).unwrap();
+        assert_eq!(zeros.code_template, "Vector::zeros({n})");
+        assert!(zeros.imports.contains(&"use trueno::Vector;".to_string()));
+
+        let ones = converter.convert(&NumPyOp::Ones).unwrap();
+        assert_eq!(ones.code_template, "Vector::ones({n})");
+        assert!(ones.imports.contains(&"use trueno::Vector;".to_string()));
+    }
+
+    #[test]
+    fn test_arange_conversion() {
+        let converter = NumPyConverter::new();
+        let op = converter.convert(&NumPyOp::Arange).unwrap();
+
+        assert!(op.code_template.starts_with("Vector::from_slice("));
+        for param in ["{start}", "{stop}", "{step}"] {
+            assert!(op.code_template.contains(param), "missing {}", param);
+        }
+        assert!(op.imports.contains(&"use trueno::Vector;".to_string()));
+    }
+
+    #[test]
+    fn test_linspace_conversion() {
+        let converter = NumPyConverter::new();
+        let op = converter.convert(&NumPyOp::Linspace).unwrap();
+
+        assert!(op.code_template.contains("for i in 0..{num}"));
+        assert!(op.code_template.contains("Vector::from_slice(&values)"));
+        for param in ["{start}", "{stop}", "{num}"] {
+            assert!(op.code_template.contains(param), "missing {}", param);
+        }
+        assert!(op.imports.contains(&"use trueno::Vector;".to_string()));
+    }
+
     #[test]
     fn test_subtract_conversion() {
         let converter = NumPyConverter::new();
@@ -466,9 +1210,455 @@ mod tests {
         let converter = NumPyConverter::new();
         let ops = converter.available_ops();
 
-        assert!(!ops.is_empty());
-        // Should have at least the mapped operations
-        assert!(ops.len() >= 6);
+        // Every variant has exactly one template
+        assert_eq!(ops.len(), all_ops().len());
+        for op in all_ops() {
+            assert!(ops.contains(&&op), "Missing template for {:?}", op);
+        }
+    }
+
+    #[test]
+    fn test_shape_display() {
+        assert_eq!(Shape::Scalar.to_string(), "()");
+        assert_eq!(Shape::Vector { len: 3 }.to_string(), "(3,)");
+        assert_eq!(Shape::Matrix { rows: 2, cols: 3 }.to_string(), "(2, 3)");
+    }
+
+    #[test]
+    fn test_check_broadcast_same_shape() {
+        let shape = Shape::Matrix { rows: 2, cols: 3 };
+        assert_eq!(NumPyConverter::check_broadcast(&shape, &shape), Ok(shape));
+    }
+
+    #[test]
+    fn test_check_broadcast_scalar() {
+        let vector = Shape::Vector { len: 4 };
+        assert_eq!(
+            NumPyConverter::check_broadcast(&Shape::Scalar, &vector),
+            Ok(vector)
+        );
+        assert_eq!(
+            NumPyConverter::check_broadcast(&vector, &Shape::Scalar),
+            Ok(vector)
+        );
+    }
+
+    #[test]
+    fn test_check_broadcast_row() {
+        // (2, 3) + (3,) broadcasts the row across both rows
+        let matrix = Shape::Matrix { rows: 2, cols: 3 };
+        let row = Shape::Vector { len: 3 };
+        assert_eq!(NumPyConverter::check_broadcast(&matrix, &row), Ok(matrix));
+
+        // (2, 1) + (1, 3) -> (2, 3)
+        let column = Shape::Matrix { rows: 2, cols: 1 };
+        let row = Shape::Matrix { rows: 1, cols: 3 };
+        assert_eq!(
+            NumPyConverter::check_broadcast(&column, &row),
+            Ok(Shape::Matrix { rows: 2, cols: 3 })
+        );
+    }
+
+    #[test]
+    fn test_check_broadcast_incompatible() {
+        let err =
+            NumPyConverter::check_broadcast(&Shape::Vector { len: 3 }, &Shape::Vector { len: 4 })
+                .unwrap_err();
+        assert!(err.contains("(3,)"), "{}", err);
+        assert!(err.contains("(4,)"), "{}", err);
+
+        // (2, 3) + (2,) fails on the trailing dimension
+        assert!(NumPyConverter::check_broadcast(
+            &Shape::Matrix { rows: 2, cols: 3 },
+            &Shape::Vector { len: 2 },
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_explain_backend() {
+        let converter = NumPyConverter::new();
+
+        // 125K f64 elements, 3 × 1 MB moved, 125K FLOPs: nowhere near 5×
+        let add = converter.explain_backend(&NumPyOp::Add, 125_000, 8);
+        assert_eq!(add.backend, crate::backend::Backend::SIMD);
+        assert!((add.transfer_s - 3_000_000.0 / 32e9).abs() < 1e-12);
+        assert!(add.ratio < add.min_dispatch_ratio);
+
+        // 4M-element matmul: 2 × 2048³ FLOPs over 96 MB
+        let dot = converter.explain_backend(&NumPyOp::Dot, 2048 * 2048, 8);
+        assert_eq!(
+            dot.backend,
+            converter
+                .backend_selector
+                .select_backend(2048 * 2048 * 8 * 3, 2 * 2048 * 2048 * 2048)
+        );
+    }
+
+    #[test]
+    fn test_emit_module_backend_comment() {
+        let converter = NumPyConverter::new();
+        let sum = |bindings: Bindings| (NumPyOp::Sum, bindings.with("array", "a"));
+
+        let plain = converter.emit_module(&[sum(Bindings::new().with_target("s"))]);
+        assert!(!plain.contains("// Backend"));
+
+        let module = converter.emit_module(&[sum(Bindings::new()
+            .with_target("s")
+            .with_data_size(1_000_000))]);
+        let lines: Vec<&str> = module.lines().collect();
+        let comment = lines
+            .iter()
+            .position(|l| *l == "// Backend: GPU: 1000000 elements > 100000 GPU threshold")
+            .expect("backend comment");
+        assert_eq!(lines[comment + 1], "let s = a.sum();");
+    }
+
+    #[test]
+    fn test_explain_recommendation_matches_recommend_backend() {
+        let converter = NumPyConverter::new();
+        let ops = [NumPyOp::Add, NumPyOp::Sum, NumPyOp::Dot];
+        let sizes = [0, 500, 5_000, 50_000, 500_000, 5_000_000];
+
+        // The emitted comment names the backend the converter recommends
+        for op in &ops {
+            for &size in &sizes {
+                let bindings = Bindings::new()
+                    .with("lhs", "a")
+                    .with("rhs", "b")
+                    .with("array", "a")
+                    .with_data_size(size);
+                let module = converter.emit_module(&[(op.clone(), bindings)]);
+                let expected = format!("// Backend: {}: ", converter.recommend_backend(op, size));
+                assert!(
+                    module.lines().any(|l| l.starts_with(&expected)),
+                    "{:?} x {}: {}",
+                    op,
+                    size,
+                    module
+                );
+            }
+        }
+
+        for op in &ops {
+            for &size in &sizes {
+                for dtype_bytes in [1, 4, 16] {
+                    let backend = converter.recommend_backend_for_dtype(op, size, dtype_bytes);
+                    let reason = converter.explain_recommendation(op, size, dtype_bytes);
+                    assert!(reason.starts_with(&format!("{}: ", backend)), "{}", reason);
+                }
+            }
+        }
+
+        assert_eq!(
+            converter.explain_recommendation(&NumPyOp::Add, 2_000_000, 8),
+            "SIMD: 2000000 elements > 1000000 SIMD threshold; Low ops never use the GPU"
+        );
+        assert_eq!(
+            converter.explain_recommendation(&NumPyOp::Sum, 50_000, 1),
+            "GPU: 50000 elements of 1 bytes reach the 100000 GPU threshold"
+        );
+        assert_eq!(
+            converter.explain_recommendation(&NumPyOp::Sum, 150_000, 16),
+            "SIMD: 150000 elements of 16 bytes fall short of the 100000 GPU threshold"
+        );
+        assert_eq!(
+            converter.explain_recommendation(&NumPyOp::Sum, 500, 8),
+            "Scalar: 500 elements <= 10000 SIMD threshold"
+        );
+    }
+
+    #[test]
+    fn test_emit_module_broadcast_warning() {
+        let converter = NumPyConverter::new();
+        let add = |lhs, rhs| {
+            (
+                NumPyOp::Add,
+                Bindings::new()
+                    .with("lhs", "a")
+                    .with("rhs", "b")
+                    .with_shapes(lhs, rhs),
+            )
+        };
+        let vector = Shape::Vector { len: 3 };
+
+        let same = converter.emit_module(&[add(vector, vector)]);
+        assert!(!same.contains("//"));
+
+        let broadcast = converter.emit_module(&[add(Shape::Matrix { rows: 2, cols: 3 }, vector)]);
+        assert!(broadcast.contains("// WARNING: NumPy broadcasts (2, 3) with (3,) to (2, 3)"));
+        assert!(broadcast.contains("a.add(&b).unwrap();"));
+
+        let incompatible = converter.emit_module(&[add(vector, Shape::Vector { len: 4 })]);
+        assert!(incompatible.contains("// ERROR: operands could not be broadcast"));
+    }
+
+    #[test]
+    fn test_convert_with_shape_vector() {
+        let converter = NumPyConverter::new();
+
+        let array = converter
+            .convert_with_shape(&NumPyOp::Array, Shape::Vector { len: 3 })
+            .unwrap();
+        assert!(array.code_template.contains("Vector::from_slice"));
+
+        let dot = converter
+            .convert_with_shape(&NumPyOp::Dot, Shape::Vector { len: 3 })
+            .unwrap();
+        assert_eq!(dot.code_template, "{lhs}.dot(&{rhs}).unwrap()");
+    }
+
+    #[test]
+    fn test_convert_with_shape_matrix_array() {
+        let converter = NumPyConverter::new();
+        let shape = Shape::Matrix { rows: 2, cols: 2 };
+
+        let op = converter
+            .convert_with_shape(&NumPyOp::Array, shape)
+            .unwrap();
+
+        assert_eq!(
+            op.code_template,
+            "Matrix::from_slice(2, 2, &[{values}]).unwrap()"
+        );
+        assert!(op.imports.iter().any(|i| i.contains("Matrix")));
+        assert!(!op.imports.iter().any(|i| i.contains("Vector")));
+    }
+
+    #[test]
+    fn test_convert_with_shape_matrix_dot_is_matmul() {
+        let converter = NumPyConverter::new();
+        let shape = Shape::Matrix { rows: 3, cols: 4 };
+
+        let op = converter.convert_with_shape(&NumPyOp::Dot, shape).unwrap();
+
+        assert_eq!(op.code_template, "{lhs}.matmul(&{rhs}).unwrap()");
+        assert_eq!(op.complexity, crate::backend::OpComplexity::High);
+    }
+
+    #[test]
+    fn test_convert_with_shape_matrix_fallback() {
+        let converter = NumPyConverter::new();
+        let shape = Shape::Matrix { rows: 2, cols: 3 };
+
+        // Ops without a matrix form keep their vector template
+        let sum = converter.convert_with_shape(&NumPyOp::Sum, shape).unwrap();
+        assert_eq!(sum.code_template, "{array}.sum()");
+
+        // Reshape takes its target dimensions from the shape
+        let reshape = converter
+            .convert_with_shape(&NumPyOp::Reshape, shape)
+            .unwrap();
+        assert!(reshape.code_template.starts_with("Matrix::from_vec(2, 3,"));
+    }
+
+    #[test]
+    fn test_bindings_render() {
+        let bindings = Bindings::new().with("lhs", "a").with("rhs", "b");
+        assert_eq!(
+            bindings.render("{lhs}.add(&{rhs}).unwrap()"),
+            "a.add(&b).unwrap()"
+        );
+        // Unbound placeholders are left as-is
+        assert_eq!(bindings.render("{array}.sum()"), "{array}.sum()");
+    }
+
+    #[test]
+    fn test_emit_module_deduplicates_imports() {
+        let converter = NumPyConverter::new();
+        let ops = vec![
+            (
+                NumPyOp::Array,
+                Bindings::new().with_target("a").with("values", "1.0, 2.0"),
+            ),
+            (
+                NumPyOp::Add,
+                Bindings::new()
+                    .with_target("c")
+                    .with("lhs", "a")
+                    .with("rhs", "a"),
+            ),
+            (
+                NumPyOp::Sum,
+                Bindings::new().with_target("total").with("array", "c"),
+            ),
+        ];
+
+        let module = converter.emit_module(&ops);
+
+        assert_eq!(module.matches("use trueno::Vector;").count(), 1);
+        assert_eq!(
+            module,
+            "use trueno::Vector;\n\n\
+             let a = Vector::from_slice(&[1.0, 2.0]);\n\
+             let c = a.add(&a).unwrap();\n\
+             let total = c.sum();\n"
+        );
+    }
+
+    #[test]
+    fn test_emit_module_merges_distinct_imports() {
+        let converter = NumPyConverter::new();
+        let ops = vec![
+            (NumPyOp::Zeros, Bindings::new().with("n", "4")),
+            (NumPyOp::Transpose, Bindings::new().with("array", "m")),
+        ];
+
+        let module = converter.emit_module(&ops);
+
+        assert!(module.starts_with("use trueno::Matrix;\nuse trueno::Vector;\n\n"));
+        assert!(module.contains("Vector::zeros(4);"));
+        assert!(module.contains("m.transpose();"));
+    }
+
+    #[test]
+    fn test_emit_module_empty() {
+        let converter = NumPyConverter::new();
+        assert!(converter.emit_module(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_from_operator() {
+        assert_eq!(NumPyOp::from_operator("+"), Some((NumPyOp::Add, false)));
+        assert_eq!(NumPyOp::from_operator("+="), Some((NumPyOp::Add, true)));
+        assert_eq!(
+            NumPyOp::from_operator("*="),
+            Some((NumPyOp::Multiply, true))
+        );
+        assert_eq!(NumPyOp::from_operator("@"), Some((NumPyOp::Dot, false)));
+        assert_eq!(NumPyOp::from_operator("%"), None);
+        assert_eq!(NumPyOp::from_operator("=="), None);
+    }
+
+    #[test]
+    fn test_convert_in_place() {
+        let converter = NumPyConverter::new();
+
+        let add = converter.convert_in_place(&NumPyOp::Add).unwrap();
+        assert_eq!(add.code_template, "{lhs}.add_assign(&{rhs}).unwrap()");
+
+        let mul = converter.convert_in_place(&NumPyOp::Multiply).unwrap();
+        assert_eq!(mul.code_template, "{lhs}.mul_assign(&{rhs}).unwrap()");
+
+        // Reductions have no in-place form
+        assert!(converter.convert_in_place(&NumPyOp::Sum).is_none());
+    }
+
+    #[test]
+    fn test_emit_module_in_place_add() {
+        let converter = NumPyConverter::new();
+        let emit = |token| {
+            let (op, in_place) = NumPyOp::from_operator(token).unwrap();
+            let bindings = Bindings::new()
+                .with_target("a")
+                .with("lhs", "a")
+                .with("rhs", "b");
+            let bindings = if in_place {
+                bindings.with_in_place()
+            } else {
+                bindings
+            };
+            converter.emit_module(&[(op, bindings)])
+        };
+
+        // a = a + b
+        assert!(emit("+").ends_with("let a = a.add(&b).unwrap();\n"));
+        // a += b
+        assert!(emit("+=").ends_with("\na.add_assign(&b).unwrap();\n"));
+    }
+
+    #[test]
+    fn test_emit_module_in_place_multiply() {
+        let converter = NumPyConverter::new();
+        let bindings = Bindings::new()
+            .with("lhs", "x")
+            .with("rhs", "y")
+            .with_in_place();
+
+        let module = converter.emit_module(&[(NumPyOp::Multiply, bindings)]);
+
+        assert_eq!(
+            module,
+            "use trueno::Vector;\n\nx.mul_assign(&y).unwrap();\n"
+        );
+    }
+
+    #[test]
+    fn test_emit_module_in_place_fallback() {
+        let converter = NumPyConverter::new();
+        let bindings = Bindings::new()
+            .with_target("total")
+            .with("array", "a")
+            .with_in_place();
+
+        // Ops without an in-place form convert normally
+        let module = converter.emit_module(&[(NumPyOp::Sum, bindings)]);
+        assert!(module.ends_with("let total = a.sum();\n"));
+    }
+
+    #[test]
+    fn test_params_match_templates() {
+        let converter = NumPyConverter::new();
+
+        for op in all_ops() {
+            let template = &converter.convert(&op).unwrap().code_template;
+            for param in op.params() {
+                assert!(
+                    template.contains(&format!("{{{}}}", param)),
+                    "{:?} template lacks {{{}}}",
+                    op,
+                    param
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_convert_expr_nested() {
+        let converter = NumPyConverter::new();
+        // np.sum(np.add(a, b))
+        let expr = Expr::call(
+            NumPyOp::Sum,
+            vec![Expr::call(
+                NumPyOp::Add,
+                vec![Expr::var("a"), Expr::var("b")],
+            )],
+        );
+
+        assert_eq!(
+            converter.convert_expr(&expr).unwrap(),
+            "a.add(&b).unwrap().sum()"
+        );
+    }
+
+    #[test]
+    fn test_convert_expr_hoists_deep_subexpressions() {
+        let converter = NumPyConverter::new().with_max_expr_depth(2);
+        // np.sum(np.multiply(np.add(a, b), c))
+        let expr = Expr::call(
+            NumPyOp::Sum,
+            vec![Expr::call(
+                NumPyOp::Multiply,
+                vec![
+                    Expr::call(NumPyOp::Add, vec![Expr::var("a"), Expr::var("b")]),
+                    Expr::var("c"),
+                ],
+            )],
+        );
+
+        assert_eq!(
+            converter.convert_expr(&expr).unwrap(),
+            "let multiply_0 = a.add(&b).unwrap().mul(&c).unwrap();\nmultiply_0.sum()"
+        );
+    }
+
+    #[test]
+    fn test_convert_expr_arity_error() {
+        let converter = NumPyConverter::new();
+        let expr = Expr::call(NumPyOp::Add, vec![Expr::var("a")]);
+
+        let err = converter.convert_expr(&expr).unwrap_err().to_string();
+        assert!(err.contains("Add takes 2 argument(s)"), "{}", err);
     }
 
     #[test]
@@ -507,6 +1697,23 @@ mod tests {
         assert_eq!(backend, crate::backend::Backend::GPU);
     }
 
+    #[test]
+    fn test_recommend_backend_respects_dtype() {
+        let converter = NumPyConverter::new();
+
+        // Same element count, different widths: the i8 array is cheap enough
+        // to ship to the GPU, the f64 array is not
+        let f64_backend = converter.recommend_backend_for_dtype(&NumPyOp::Sum, 50_000, 8);
+        let i8_backend = converter.recommend_backend_for_dtype(&NumPyOp::Sum, 50_000, 1);
+
+        assert_eq!(f64_backend, crate::backend::Backend::SIMD);
+        assert_eq!(i8_backend, crate::backend::Backend::GPU);
+        assert_eq!(
+            converter.recommend_backend(&NumPyOp::Sum, 50_000),
+            f64_backend
+        );
+    }
+
     #[test]
     fn test_recommend_backend_dot_product() {
         let converter = NumPyConverter::new();
@@ -538,6 +1745,44 @@ mod tests {
         assert!(report.contains("Add") || report.contains("Sum") || report.contains("Dot"));
     }
 
+    #[test]
+    fn test_report_json_round_trip() {
+        let converter = NumPyConverter::new();
+        let json = converter.report_json();
+
+        let report: ConversionReport = serde_json::from_value(json).unwrap();
+        let add = report
+            .operations
+            .iter()
+            .find(|entry| entry.op == "Add")
+            .unwrap();
+
+        assert_eq!(add.complexity, "Low");
+        assert_eq!(add.template, "{lhs}.add(&{rhs}).unwrap()");
+        assert_eq!(add.imports, vec!["use trueno::Vector;".to_string()]);
+        assert_eq!(report.operations.len(), all_ops().len());
+    }
+
+    #[test]
+    fn test_report_is_sorted_and_deterministic() {
+        let report = NumPyConverter::new().report();
+        let names: Vec<&str> = report.operations.iter().map(|e| e.op.as_str()).collect();
+
+        let mut sorted = names.clone();
+        sorted.sort_unstable();
+        assert_eq!(names, sorted);
+
+        // Separate converters have independently seeded HashMaps
+        assert_eq!(
+            NumPyConverter::new().report_json(),
+            NumPyConverter::new().report_json()
+        );
+        assert_eq!(
+            NumPyConverter::new().conversion_report(),
+            NumPyConverter::new().conversion_report()
+        );
+    }
+
     #[test]
     fn test_all_conversions_not_empty() {
         let converter = NumPyConverter::new();
@@ -595,7 +1840,12 @@ mod tests {
     fn test_element_wise_ops_have_unwrap() {
         let converter = NumPyConverter::new();
 
-        let element_wise = vec![NumPyOp::Add, NumPyOp::Subtract, NumPyOp::Multiply];
+        let element_wise = vec![
+            NumPyOp::Add,
+            NumPyOp::Subtract,
+            NumPyOp::Multiply,
+            NumPyOp::Divide,
+        ];
 
         for op in element_wise {
             if let Some(trueno_op) = converter.convert(&op) {
@@ -613,16 +1863,9 @@ mod tests {
         let converter = NumPyConverter::new();
 
         // Test that TruenoOp complexity matches NumPyOp complexity
-        if let Some(add_op) = converter.convert(&NumPyOp::Add) {
-            assert_eq!(add_op.complexity, NumPyOp::Add.complexity());
-        }
-
-        if let Some(sum_op) = converter.convert(&NumPyOp::Sum) {
-            assert_eq!(sum_op.complexity, NumPyOp::Sum.complexity());
-        }
-
-        if let Some(dot_op) = converter.convert(&NumPyOp::Dot) {
-            assert_eq!(dot_op.complexity, NumPyOp::Dot.complexity());
+        for op in all_ops() {
+            let trueno_op = converter.convert(&op).unwrap();
+            assert_eq!(trueno_op.complexity, op.complexity(), "{:?}", op);
         }
     }
 }
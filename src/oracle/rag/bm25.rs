@@ -0,0 +1,191 @@
+//! BM25 Lexical Index over Chunks
+//!
+//! Okapi BM25 (Robertson & Zaragoza 2009) scoring of [`Chunk`]s, for exact
+//! identifier matches that dense retrieval misses. Complements the
+//! [`VectorStore`](super::VectorStore) in hybrid retrieval.
+
+use super::chunker::Chunk;
+use super::retriever::tokenize;
+use super::types::Bm25Config;
+use std::collections::HashMap;
+
+/// In-memory BM25 index of chunks
+#[derive(Debug, Clone, Default)]
+pub struct Bm25Index {
+    config: Bm25Config,
+    docs: Vec<IndexedChunk>,
+    /// Term -> number of chunks containing it
+    doc_freqs: HashMap<String, usize>,
+    /// Sum of all chunk lengths in tokens
+    total_length: usize,
+}
+
+#[derive(Debug, Clone)]
+struct IndexedChunk {
+    chunk: Chunk,
+    term_freqs: HashMap<String, usize>,
+    length: usize,
+}
+
+impl Bm25Index {
+    /// Create an empty index with default k1/b
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an empty index with custom k1/b
+    pub fn with_config(config: Bm25Config) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+
+    /// Index a chunk's content
+    pub fn add(&mut self, chunk: Chunk) {
+        let tokens = tokenize(&chunk.content);
+        let length = tokens.len();
+        let mut term_freqs: HashMap<String, usize> = HashMap::new();
+        for token in tokens {
+            *term_freqs.entry(token).or_insert(0) += 1;
+        }
+        for term in term_freqs.keys() {
+            *self.doc_freqs.entry(term.clone()).or_insert(0) += 1;
+        }
+
+        self.total_length += length;
+        self.docs.push(IndexedChunk {
+            chunk,
+            term_freqs,
+            length,
+        });
+    }
+
+    /// BM25 score of every chunk for `query_terms`, in insertion order
+    ///
+    /// Terms are matched case-insensitively; out-of-vocabulary terms
+    /// contribute nothing.
+    pub fn score<S: AsRef<str>>(&self, query_terms: &[S]) -> Vec<f32> {
+        let n = self.docs.len() as f32;
+        let avg_length = (self.total_length as f32 / n.max(1.0)).max(1.0);
+        let (k1, b) = (self.config.k1, self.config.b);
+
+        let idfs: Vec<(String, f32)> = query_terms
+            .iter()
+            .filter_map(|term| {
+                let term = term.as_ref().to_lowercase();
+                let df = *self.doc_freqs.get(&term)? as f32;
+                // Non-negative IDF: ln((N - df + 0.5) / (df + 0.5) + 1)
+                Some((term, ((n - df + 0.5) / (df + 0.5) + 1.0).ln()))
+            })
+            .collect();
+
+        self.docs
+            .iter()
+            .map(|doc| {
+                let length_norm = 1.0 - b + b * doc.length as f32 / avg_length;
+                idfs.iter()
+                    .filter_map(|(term, idf)| {
+                        let tf = *doc.term_freqs.get(term)? as f32;
+                        Some(idf * tf * (k1 + 1.0) / (tf + k1 * length_norm))
+                    })
+                    .sum()
+            })
+            .collect()
+    }
+
+    /// Top `k` chunks for a free-text query, highest score first
+    ///
+    /// Chunks sharing no term with the query are never returned, so an empty
+    /// or entirely out-of-vocabulary query yields nothing.
+    pub fn search(&self, query: &str, k: usize) -> Vec<(f32, &Chunk)> {
+        let mut results: Vec<(f32, &Chunk)> = self
+            .score(&tokenize(query))
+            .into_iter()
+            .zip(&self.docs)
+            .filter(|(score, _)| *score > 0.0)
+            .map(|(score, doc)| (score, &doc.chunk))
+            .collect();
+
+        results.sort_by(|a, b| b.0.total_cmp(&a.0));
+        results.truncate(k);
+        results
+    }
+
+    /// Number of indexed chunks
+    pub fn len(&self) -> usize {
+        self.docs.len()
+    }
+
+    /// Whether no chunks are indexed
+    pub fn is_empty(&self) -> bool {
+        self.docs.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index(contents: &[&str]) -> Bm25Index {
+        let mut index = Bm25Index::new();
+        for content in contents {
+            index.add(Chunk::for_test(content));
+        }
+        index
+    }
+
+    #[test]
+    fn test_rare_exact_term_outranks_similar_chunk() {
+        let index = index(&[
+            "dense embedding vectors come from a neural encoder model",
+            "let embedder = HashEmbedder::new(256);",
+            "the embedding cache stores embedding vectors on disk",
+        ]);
+
+        let results = index.search("HashEmbedder embedding", 3);
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            results[0].1.content,
+            "let embedder = HashEmbedder::new(256);"
+        );
+        assert!(results.windows(2).all(|w| w[0].0 >= w[1].0));
+    }
+
+    #[test]
+    fn test_empty_and_out_of_vocabulary_queries() {
+        let index = index(&["vector addition", "matrix multiply"]);
+        assert!(index.search("", 5).is_empty());
+        assert!(index.search("tokenizer", 5).is_empty());
+        assert_eq!(index.score::<&str>(&[]), vec![0.0, 0.0]);
+        assert_eq!(index.score(&["tokenizer"]), vec![0.0, 0.0]);
+        assert!(Bm25Index::new().search("vector", 5).is_empty());
+        assert!(Bm25Index::new().is_empty());
+    }
+
+    #[test]
+    fn test_score_case_insensitive_and_top_k() {
+        let index = index(&["Vector add", "vector vector dot", "matrix multiply"]);
+        assert_eq!(index.len(), 3);
+
+        let scores = index.score(&["VECTOR"]);
+        assert!(scores[0] > 0.0 && scores[1] > scores[0]);
+        assert_eq!(scores[2], 0.0);
+        assert_eq!(index.search("vector", 1)[0].1.content, "vector vector dot");
+    }
+
+    #[test]
+    fn test_custom_config() {
+        let contents = ["vector", "vector padding padding padding padding"];
+        let plain = index(&contents);
+        let mut no_length_norm = Bm25Index::with_config(Bm25Config { k1: 1.2, b: 0.0 });
+        for content in contents {
+            no_length_norm.add(Chunk::for_test(content));
+        }
+
+        let scores = plain.score(&["vector"]);
+        assert!(scores[0] > scores[1]);
+        let scores = no_length_norm.score(&["vector"]);
+        assert!((scores[0] - scores[1]).abs() < 1e-6);
+    }
+}
@@ -4,6 +4,23 @@
 //! Based on LangChain text splitter patterns [1] and Chen et al. (2017) [21].
 
 use super::fingerprint::ChunkerConfig;
+use serde::{Deserialize, Serialize};
+use std::io::BufRead;
+
+/// Source language used to pick chunk separators
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    /// Rust source
+    Rust,
+    /// Markdown documentation
+    Markdown,
+    /// Python source
+    Python,
+    /// JavaScript source
+    JavaScript,
+    /// TypeScript source
+    TypeScript,
+}
 
 /// Semantic chunker with code-aware splitting
 ///
@@ -15,6 +32,8 @@ pub struct SemanticChunker {
     chunk_size: usize,
     /// Overlap between chunks for context preservation
     chunk_overlap: usize,
+    /// Measure `chunk_overlap` in tokens instead of bytes
+    overlap_in_tokens: bool,
     /// Code-aware separators ordered by priority (highest to lowest)
     separators: Vec<String>,
 }
@@ -25,19 +44,80 @@ impl SemanticChunker {
         Self {
             chunk_size,
             chunk_overlap,
+            overlap_in_tokens: false,
             separators,
         }
     }
 
+    /// Measure `chunk_overlap` in tokens rather than bytes
+    ///
+    /// Each chunk then shares the same number of trailing tokens with the
+    /// next, regardless of how many bytes those tokens take (e.g. CJK text).
+    /// A token is a whitespace-delimited word or a single CJK character.
+    pub fn with_token_overlap(mut self) -> Self {
+        self.overlap_in_tokens = true;
+        self
+    }
+
     /// Create from a ChunkerConfig
     pub fn from_config(config: &ChunkerConfig) -> Self {
         Self {
             chunk_size: config.chunk_size,
             chunk_overlap: config.chunk_overlap,
+            overlap_in_tokens: false,
             separators: Self::default_separators(),
         }
     }
 
+    /// Create a chunker with separators tuned for a source language
+    pub fn for_language(language: Language, chunk_size: usize, chunk_overlap: usize) -> Self {
+        Self::new(
+            chunk_size,
+            chunk_overlap,
+            Self::language_separators(language),
+        )
+    }
+
+    /// Separators for a language, ordered by priority (highest to lowest)
+    fn language_separators(language: Language) -> Vec<String> {
+        let separators: &[&str] = match language {
+            Language::Rust | Language::Markdown => return Self::default_separators(),
+            Language::Python => &[
+                "\nclass ",         // Class definition
+                "\ndef ",           // Top-level function
+                "\nasync def ",     // Top-level coroutine
+                "\n    def ",       // Method
+                "\n    async def ", // Async method
+                "\n\n",             // Paragraph
+                "\n",               // Line
+                " ",                // Word
+            ],
+            Language::JavaScript => &[
+                "\nexport ",         // Exported declaration
+                "\nclass ",          // Class
+                "\nfunction ",       // Function
+                "\nasync function ", // Async function
+                "\nconst ",          // Top-level binding
+                "\n\n",              // Paragraph
+                "\n",                // Line
+                " ",                 // Word
+            ],
+            Language::TypeScript => &[
+                "\nexport ",         // Exported declaration
+                "\ninterface ",      // Interface
+                "\ntype ",           // Type alias
+                "\nclass ",          // Class
+                "\nfunction ",       // Function
+                "\nasync function ", // Async function
+                "\nconst ",          // Top-level binding
+                "\n\n",              // Paragraph
+                "\n",                // Line
+                " ",                 // Word
+            ],
+        };
+        separators.iter().map(|s| s.to_string()).collect()
+    }
+
     /// Default separators for Rust/Markdown content
     fn default_separators() -> Vec<String> {
         vec![
@@ -58,43 +138,201 @@ impl SemanticChunker {
     }
 
     /// Split text into chunks
+    ///
+    /// Whitespace-only stretches are folded into a neighbouring chunk rather
+    /// than dropped, so the chunks always cover the whole text (see
+    /// [`Self::reconstruct`]) unless the text is entirely whitespace.
     pub fn split(&self, text: &str) -> Vec<Chunk> {
         let mut chunks = Vec::new();
         let mut current_pos = 0;
+        let fences = Self::fenced_blocks(text);
 
         while current_pos < text.len() {
-            let (chunk_text, end_pos) = self.extract_chunk(text, current_pos);
+            let (chunk_text, end_pos) = self.extract_chunk(text, current_pos, &fences);
 
             if !chunk_text.trim().is_empty() {
-                let start_line = text[..current_pos].matches('\n').count() + 1;
-                let end_line = start_line + chunk_text.matches('\n').count();
+                // Start earlier if a skipped whitespace-only chunk left a gap
+                let covered = chunks.last().map_or(0, |c: &Chunk| c.end_offset);
+                let chunk_start = current_pos.min(covered);
+                let content = &text[chunk_start..end_pos];
+                let start_line = text[..chunk_start].matches('\n').count() + 1;
+                let end_line = start_line + content.matches('\n').count();
 
                 chunks.push(Chunk {
-                    content: chunk_text.to_string(),
-                    start_offset: current_pos,
+                    content: content.to_string(),
+                    start_offset: chunk_start,
                     end_offset: end_pos,
                     start_line,
                     end_line,
+                    kind: ChunkKind::classify(content),
                 });
             }
 
-            // Move forward, accounting for overlap
-            let advance = if end_pos - current_pos > self.chunk_overlap {
-                end_pos - current_pos - self.chunk_overlap
-            } else {
-                end_pos - current_pos
-            };
+            current_pos = self.next_start(text, &fences, current_pos, end_pos);
+        }
 
-            // Advance at least 1, ensuring we land on a char boundary
-            let new_pos = current_pos + advance.max(1);
-            current_pos = Self::find_next_char_boundary(text, new_pos);
+        // Trailing whitespace-only chunks belong to the last chunk
+        if let Some(last) = chunks.last_mut() {
+            if last.end_offset < text.len() {
+                last.content.push_str(&text[last.end_offset..]);
+                last.end_line += text[last.end_offset..].matches('\n').count();
+                last.end_offset = text.len();
+            }
         }
 
         chunks
     }
 
+    /// Split many `(id, text)` documents, one [`Self::split`] per document
+    ///
+    /// Documents are chunked in parallel under the `native` feature. Results
+    /// keep the input order and offsets are relative to each document.
+    pub fn split_many(&self, docs: &[(String, String)]) -> Vec<(String, Vec<Chunk>)> {
+        #[cfg(feature = "native")]
+        {
+            use rayon::prelude::*;
+            docs.par_iter()
+                .map(|(id, text)| (id.clone(), self.split(text)))
+                .collect()
+        }
+        #[cfg(not(feature = "native"))]
+        {
+            docs.iter()
+                .map(|(id, text)| (id.clone(), self.split(text)))
+                .collect()
+        }
+    }
+
+    /// Split a stream incrementally, producing the same chunks as [`Self::split`]
+    ///
+    /// Only the current chunk window (plus overlap and any open code fence) is
+    /// kept in memory. Offsets are byte positions in the stream. Reading stops
+    /// at the first I/O error (including invalid UTF-8), as if the stream ended.
+    pub fn split_reader<R: BufRead>(&self, reader: R) -> impl Iterator<Item = Chunk> {
+        ChunkStream {
+            chunker: self.clone(),
+            reader,
+            buf: String::new(),
+            base: 0,
+            base_line: 0,
+            current_pos: 0,
+            covered: 0,
+            pending: None,
+            eof: false,
+        }
+    }
+
+    /// Reassemble the original text from chunks produced by [`Self::split`]
+    ///
+    /// Uses `start_offset`/`end_offset` to drop the overlapping regions, so
+    /// `reconstruct(&split(text)) == text` for any text that is not entirely
+    /// whitespace.
+    pub fn reconstruct(chunks: &[Chunk]) -> String {
+        let mut ordered: Vec<&Chunk> = chunks.iter().collect();
+        ordered.sort_by_key(|c| (c.start_offset, c.end_offset));
+
+        let mut text = String::new();
+        let mut covered = ordered.first().map_or(0, |c| c.start_offset);
+        for chunk in ordered {
+            if chunk.end_offset <= covered {
+                continue;
+            }
+            let skip = covered.saturating_sub(chunk.start_offset);
+            text.push_str(&chunk.content[skip..]);
+            covered = chunk.end_offset;
+        }
+        text
+    }
+
+    /// Start of the chunk following `text[start..end]`, accounting for overlap
+    ///
+    /// Always moves forward (by at least one character), never lands inside a
+    /// code fence, and stays on a char boundary.
+    fn next_start(&self, text: &str, fences: &[(usize, usize)], start: usize, end: usize) -> usize {
+        let new_pos = if self.overlap_in_tokens {
+            let token_starts = Self::token_starts(&text[start..end]);
+            match token_starts.len().checked_sub(self.chunk_overlap) {
+                Some(keep) if self.chunk_overlap > 0 && keep > 0 => start + token_starts[keep],
+                _ => end,
+            }
+        } else if end - start > self.chunk_overlap {
+            end - self.chunk_overlap
+        } else {
+            end
+        };
+
+        // Degenerate-loop guard: advance at least 1
+        let new_pos = new_pos.max(start + 1);
+        // Never start inside a code fence; skip the overlap past it instead
+        let new_pos = match Self::enclosing_fence(fences, new_pos) {
+            Some((_, close_end)) => close_end,
+            None => new_pos,
+        };
+        Self::find_next_char_boundary(text, new_pos)
+    }
+
+    /// Byte offsets where tokens begin: words, plus each CJK character
+    fn token_starts(text: &str) -> Vec<usize> {
+        let mut starts = Vec::new();
+        let mut prev: Option<char> = None;
+        for (i, c) in text.char_indices() {
+            let starts_token = !c.is_whitespace()
+                && match prev {
+                    None => true,
+                    Some(p) => p.is_whitespace() || is_cjk(c) || is_cjk(p),
+                };
+            if starts_token {
+                starts.push(i);
+            }
+            prev = Some(c);
+        }
+        starts
+    }
+
+    /// Locate fenced code blocks as `(open, close_end)` byte ranges
+    ///
+    /// `open` is the start of the opening fence line and `close_end` the end
+    /// of the closing fence line (newline included). An unclosed fence runs
+    /// to the end of the text.
+    fn fenced_blocks(text: &str) -> Vec<(usize, usize)> {
+        let mut blocks = Vec::new();
+        let mut open = None;
+        let mut offset = 0;
+
+        for line in text.split_inclusive('\n') {
+            if line.trim_start().starts_with("```") {
+                match open.take() {
+                    Some(start) => blocks.push((start, offset + line.len())),
+                    None => open = Some(offset),
+                }
+            }
+            offset += line.len();
+        }
+
+        if let Some(start) = open {
+            blocks.push((start, text.len()));
+        }
+        blocks
+    }
+
+    /// Find the fenced block strictly containing `pos`, if any
+    fn enclosing_fence(fences: &[(usize, usize)], pos: usize) -> Option<(usize, usize)> {
+        fences
+            .iter()
+            .copied()
+            .find(|&(open, close_end)| open < pos && pos < close_end)
+    }
+
     /// Extract a single chunk starting at position
-    fn extract_chunk(&self, text: &str, start: usize) -> (String, usize) {
+    ///
+    /// A boundary that would cut through a code fence is moved before the
+    /// opening fence, or past the closing fence when the block starts the chunk.
+    fn extract_chunk(
+        &self,
+        text: &str,
+        start: usize,
+        fences: &[(usize, usize)],
+    ) -> (String, usize) {
         let remaining = &text[start..];
         let target_end = Self::find_char_boundary(text, (start + self.chunk_size).min(text.len()));
 
@@ -106,18 +344,22 @@ impl SemanticChunker {
         // Find the best split point using separators
         let search_region = &text[start..target_end];
 
-        for separator in &self.separators {
-            if let Some(pos) = search_region.rfind(separator.as_str()) {
-                if pos > 0 {
-                    // Include the separator in the chunk
-                    let end = start + pos + separator.len();
-                    return (text[start..end].to_string(), end);
-                }
-            }
-        }
-
-        // No separator found, hard cut at nearest char boundary
-        (text[start..target_end].to_string(), target_end)
+        // Include the separator in the chunk; otherwise hard cut at nearest char boundary
+        let end = self
+            .separators
+            .iter()
+            .find_map(|separator| match search_region.rfind(separator.as_str()) {
+                Some(pos) if pos > 0 => Some(start + pos + separator.len()),
+                _ => None,
+            })
+            .unwrap_or(target_end);
+
+        let end = match Self::enclosing_fence(fences, end) {
+            Some((open, _)) if open > start => open,
+            Some((_, close_end)) => close_end,
+            None => end,
+        };
+        (text[start..end].to_string(), end)
     }
 
     /// Find the nearest valid UTF-8 character boundary at or before the given position
@@ -153,18 +395,139 @@ impl SemanticChunker {
     }
 }
 
+/// CJK scripts are written without spaces, so each character is a token
+fn is_cjk(c: char) -> bool {
+    matches!(
+        c,
+        '\u{3040}'..='\u{30FF}' // Hiragana, Katakana
+            | '\u{3400}'..='\u{4DBF}' // CJK Extension A
+            | '\u{4E00}'..='\u{9FFF}' // CJK Unified Ideographs
+            | '\u{AC00}'..='\u{D7AF}' // Hangul syllables
+            | '\u{F900}'..='\u{FAFF}' // CJK Compatibility Ideographs
+    )
+}
+
+/// Incremental chunker state behind [`SemanticChunker::split_reader`]
+///
+/// Mirrors the loop in [`SemanticChunker::split`] over a sliding buffer that
+/// always starts at a line boundary outside any code fence.
+struct ChunkStream<R> {
+    chunker: SemanticChunker,
+    reader: R,
+    /// Buffered text starting at byte offset `base` of the stream
+    buf: String,
+    base: usize,
+    /// Newlines before `base`
+    base_line: usize,
+    current_pos: usize,
+    /// End offset of the last produced chunk
+    covered: usize,
+    /// Last produced chunk, held back in case trailing whitespace extends it
+    pending: Option<Chunk>,
+    eof: bool,
+}
+
+impl<R: BufRead> ChunkStream<R> {
+    /// Read whole lines until the buffer extends past `until` with no open fence
+    fn fill(&mut self, until: usize) {
+        while !self.eof && (self.base + self.buf.len() <= until || Self::has_open_fence(&self.buf))
+        {
+            match self.reader.read_line(&mut self.buf) {
+                Ok(0) | Err(_) => self.eof = true,
+                Ok(_) => {}
+            }
+        }
+    }
+
+    fn has_open_fence(text: &str) -> bool {
+        text.lines()
+            .filter(|line| line.trim_start().starts_with("```"))
+            .count()
+            % 2
+            == 1
+    }
+
+    /// Drop buffered lines that no future chunk can start in
+    fn trim(&mut self) {
+        let keep_from = self.current_pos.min(self.covered) - self.base;
+        let line_start = self.buf[..keep_from].rfind('\n').map_or(0, |i| i + 1);
+        self.base_line += self.buf[..line_start].matches('\n').count();
+        self.buf.drain(..line_start);
+        self.base += line_start;
+    }
+
+    /// Emit the held-back chunk, extended over any trailing whitespace
+    fn finish(&mut self) -> Option<Chunk> {
+        let mut last = self.pending.take()?;
+        let end = self.base + self.buf.len();
+        if last.end_offset < end {
+            let tail = &self.buf[last.end_offset - self.base..];
+            last.content.push_str(tail);
+            last.end_line += tail.matches('\n').count();
+            last.end_offset = end;
+        }
+        Some(last)
+    }
+}
+
+impl<R: BufRead> Iterator for ChunkStream<R> {
+    type Item = Chunk;
+
+    fn next(&mut self) -> Option<Chunk> {
+        loop {
+            self.fill(self.current_pos + self.chunker.chunk_size);
+            if self.current_pos >= self.base + self.buf.len() {
+                return self.finish();
+            }
+
+            let text = self.buf.as_str();
+            let fences = SemanticChunker::fenced_blocks(text);
+            let start = self.current_pos - self.base;
+            let (chunk_text, end) = self.chunker.extract_chunk(text, start, &fences);
+
+            let mut produced = None;
+            if !chunk_text.trim().is_empty() {
+                let chunk_start = start.min(self.covered - self.base);
+                let content = &text[chunk_start..end];
+                let start_line = self.base_line + text[..chunk_start].matches('\n').count() + 1;
+
+                produced = Some(Chunk {
+                    content: content.to_string(),
+                    start_offset: self.base + chunk_start,
+                    end_offset: self.base + end,
+                    start_line,
+                    end_line: start_line + content.matches('\n').count(),
+                    kind: ChunkKind::classify(content),
+                });
+                self.covered = self.base + end;
+            }
+
+            self.current_pos = self.base + self.chunker.next_start(text, &fences, start, end);
+
+            if let Some(chunk) = produced {
+                let ready = self.pending.replace(chunk);
+                self.trim();
+                if ready.is_some() {
+                    return ready;
+                }
+            }
+        }
+    }
+}
+
 impl Default for SemanticChunker {
     fn default() -> Self {
         Self {
             chunk_size: 512,
             chunk_overlap: 64,
+            overlap_in_tokens: false,
             separators: Self::default_separators(),
         }
     }
 }
 
 /// A text chunk with position metadata
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Chunk {
     /// Chunk content
     pub content: String,
@@ -176,23 +539,120 @@ pub struct Chunk {
     pub start_line: usize,
     /// End line number (1-indexed)
     pub end_line: usize,
+    /// Whether the chunk is predominantly code or prose
+    pub kind: ChunkKind,
+}
+
+/// Share of code lines at or above which a chunk counts as code
+const CODE_LINE_RATIO: f64 = 0.8;
+/// Share of code lines at or below which a chunk counts as prose
+const PROSE_LINE_RATIO: f64 = 0.2;
+
+/// Leading tokens that mark a line as source code
+const CODE_LINE_PREFIXES: &[&str] = &[
+    "fn ",
+    "pub ",
+    "struct ",
+    "enum ",
+    "impl ",
+    "mod ",
+    "use ",
+    "let ",
+    "trait ",
+    "#[",
+    "def ",
+    "class ",
+    "import ",
+    "from ",
+    "function ",
+    "const ",
+    "export ",
+    "return ",
+    "//",
+];
+
+/// Code vs prose classification of a chunk, for retrieval weighting
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ChunkKind {
+    /// Mostly source code (fenced blocks, definitions, indented lines)
+    Code,
+    /// Mostly natural-language text
+    Prose,
+    /// Substantial amounts of both
+    Mixed,
+}
+
+impl ChunkKind {
+    /// Classify content by the share of non-blank lines that look like code
+    ///
+    /// A line counts as code when it is inside (or delimits) a fenced block,
+    /// starts with a definition keyword, is indented by 4+ spaces or a tab,
+    /// or ends in `;`, `{` or `}`.
+    pub fn classify(content: &str) -> Self {
+        let mut in_fence = false;
+        let mut code = 0usize;
+        let mut total = 0usize;
+
+        for line in content.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+            total += 1;
+
+            if trimmed.starts_with("```") {
+                in_fence = !in_fence;
+                code += 1;
+                continue;
+            }
+
+            let indented = line.starts_with("    ") || line.starts_with('\t');
+            let keyword = CODE_LINE_PREFIXES.iter().any(|p| trimmed.starts_with(p));
+            let punctuated =
+                trimmed.ends_with(';') || trimmed.ends_with('{') || trimmed.ends_with('}');
+            if in_fence || indented || keyword || punctuated {
+                code += 1;
+            }
+        }
+
+        if total == 0 {
+            return Self::Prose;
+        }
+        let ratio = code as f64 / total as f64;
+        if ratio >= CODE_LINE_RATIO {
+            Self::Code
+        } else if ratio <= PROSE_LINE_RATIO {
+            Self::Prose
+        } else {
+            Self::Mixed
+        }
+    }
 }
 
 impl Chunk {
     /// Get content hash for deduplication
+    ///
+    /// BLAKE3 of `content` only, so reclassifying `kind` never affects dedup.
+    /// This replaced an FNV-based hash with weak collision resistance; hashes
+    /// stored by older versions no longer match, which intentionally forces
+    /// cached chunks to be rebuilt.
     pub fn content_hash(&self) -> [u8; 32] {
-        // Use same hash function as fingerprint
-        let mut hash = [0u8; 32];
-        let mut state: u64 = 0xcbf2_9ce4_8422_2325;
-        for &byte in self.content.as_bytes() {
-            state ^= byte as u64;
-            state = state.wrapping_mul(0x0100_0000_01b3);
-        }
-        for i in 0..4 {
-            let chunk = state.wrapping_add(i as u64).to_le_bytes();
-            hash[i * 8..(i + 1) * 8].copy_from_slice(&chunk);
+        blake3::hash(self.content.as_bytes()).into()
+    }
+}
+
+#[cfg(test)]
+impl Chunk {
+    /// Single-line chunk spanning all of `content`, for index and search tests
+    pub(crate) fn for_test(content: &str) -> Self {
+        Self {
+            content: content.to_string(),
+            start_offset: 0,
+            end_offset: content.len(),
+            start_line: 1,
+            end_line: 1,
+            kind: ChunkKind::classify(content),
         }
-        hash
     }
 }
 
@@ -293,6 +753,7 @@ fn baz() {
             end_offset: 12,
             start_line: 1,
             end_line: 1,
+            kind: ChunkKind::Prose,
         };
         let chunk2 = Chunk {
             content: "test content".to_string(),
@@ -300,11 +761,29 @@ fn baz() {
             end_offset: 112,
             start_line: 5,
             end_line: 5,
+            kind: ChunkKind::Prose,
         };
 
         assert_eq!(chunk1.content_hash(), chunk2.content_hash());
     }
 
+    #[test]
+    fn test_chunk_content_hash_is_blake3() {
+        let chunk = Chunk {
+            content: "test content".to_string(),
+            start_offset: 0,
+            end_offset: 12,
+            start_line: 1,
+            end_line: 1,
+            kind: ChunkKind::Prose,
+        };
+
+        assert_eq!(
+            chunk.content_hash(),
+            *blake3::hash(b"test content").as_bytes()
+        );
+    }
+
     #[test]
     fn test_chunk_content_hash_different() {
         let chunk1 = Chunk {
@@ -313,6 +792,7 @@ fn baz() {
             end_offset: 9,
             start_line: 1,
             end_line: 1,
+            kind: ChunkKind::Prose,
         };
         let chunk2 = Chunk {
             content: "content 2".to_string(),
@@ -320,11 +800,64 @@ fn baz() {
             end_offset: 9,
             start_line: 1,
             end_line: 1,
+            kind: ChunkKind::Prose,
         };
 
         assert_ne!(chunk1.content_hash(), chunk2.content_hash());
     }
 
+    #[test]
+    fn test_chunk_kind_code() {
+        let code = "fn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n";
+        assert_eq!(ChunkKind::classify(code), ChunkKind::Code);
+
+        let fenced = "```python\nresult = compute(data)\nprint(result)\n```\n";
+        assert_eq!(ChunkKind::classify(fenced), ChunkKind::Code);
+    }
+
+    #[test]
+    fn test_chunk_kind_prose() {
+        let prose = "The stack orchestrates several crates.\nEach one has a focused role,\nand they release together.\n";
+        assert_eq!(ChunkKind::classify(prose), ChunkKind::Prose);
+        assert_eq!(ChunkKind::classify("  \n\n"), ChunkKind::Prose);
+    }
+
+    #[test]
+    fn test_chunk_kind_mixed() {
+        let mixed = "Call the helper to add numbers:\n\n```rust\nlet sum = add(1, 2);\n```\n\nThe result is printed below.\nIt should equal three.\n";
+        assert_eq!(ChunkKind::classify(mixed), ChunkKind::Mixed);
+    }
+
+    #[test]
+    fn test_split_sets_chunk_kind() {
+        let chunker = SemanticChunker::new(60, 0, vec!["\n\n".to_string()]);
+        let text =
+            "Plain words describe the module here.\n\nfn main() {\n    println!(\"hi\");\n}\n";
+
+        let chunks = chunker.split(text);
+
+        assert_eq!(chunks.first().unwrap().kind, ChunkKind::Prose);
+        assert_eq!(chunks.last().unwrap().kind, ChunkKind::Code);
+    }
+
+    #[test]
+    fn test_content_hash_ignores_kind() {
+        let code = Chunk {
+            content: "let x = 1;".to_string(),
+            start_offset: 0,
+            end_offset: 10,
+            start_line: 1,
+            end_line: 1,
+            kind: ChunkKind::Code,
+        };
+        let prose = Chunk {
+            kind: ChunkKind::Prose,
+            ..code.clone()
+        };
+
+        assert_eq!(code.content_hash(), prose.content_hash());
+    }
+
     #[test]
     fn test_empty_text() {
         let chunker = SemanticChunker::default();
@@ -339,6 +872,234 @@ fn baz() {
         assert!(chunks.is_empty());
     }
 
+    #[test]
+    fn test_code_fence_never_split() {
+        let chunker = SemanticChunker::new(80, 10, SemanticChunker::default_separators());
+        let mut text = String::from("Intro paragraph before the example.\n\n```rust\n");
+        for i in 0..20 {
+            text.push_str(&format!("let value_{i} = compute({i});\n"));
+        }
+        text.push_str("```\n\nClosing paragraph after the example.\n");
+
+        let chunks = chunker.split(&text);
+
+        assert!(chunks.len() > 1);
+        for chunk in &chunks {
+            assert_eq!(
+                chunk.content.matches("```").count() % 2,
+                0,
+                "unbalanced fence in chunk: {:?}",
+                chunk.content
+            );
+        }
+        // The whole block lands in a single chunk
+        assert!(chunks
+            .iter()
+            .any(|c| c.content.contains("```rust") && c.content.contains("let value_19")));
+    }
+
+    #[test]
+    fn test_fenced_blocks_unclosed() {
+        let text = "a\n```\ncode\n```\nb\n```\nopen";
+        assert_eq!(
+            SemanticChunker::fenced_blocks(text),
+            vec![(2, 15), (17, text.len())]
+        );
+    }
+
+    #[test]
+    fn test_for_language_python_splits_on_def() {
+        let chunker = SemanticChunker::for_language(Language::Python, 120, 0);
+        let mut text = String::from("import os\n");
+        for name in ["load", "parse", "render", "save"] {
+            text.push_str(&format!(
+                "\ndef {name}(path):\n    data = os.path.join(path, '{name}')\n    return data\n"
+            ));
+        }
+
+        let chunks = chunker.split(&text);
+
+        assert!(chunks.len() > 1);
+        for chunk in &chunks[..chunks.len() - 1] {
+            assert!(
+                chunk.content.ends_with("\ndef "),
+                "chunk not cut at def: {:?}",
+                chunk.content
+            );
+        }
+    }
+
+    #[test]
+    fn test_for_language_separators() {
+        let rust = SemanticChunker::for_language(Language::Rust, 256, 32);
+        assert_eq!(rust.separators, SemanticChunker::default_separators());
+        assert_eq!(rust.chunk_size, 256);
+        assert_eq!(rust.chunk_overlap, 32);
+
+        let js = SemanticChunker::for_language(Language::JavaScript, 256, 32);
+        assert!(js.separators.contains(&"\nfunction ".to_string()));
+        assert!(js.separators.contains(&"\nexport ".to_string()));
+
+        let ts = SemanticChunker::for_language(Language::TypeScript, 256, 32);
+        assert!(ts.separators.contains(&"\ninterface ".to_string()));
+    }
+
+    #[test]
+    fn test_reconstruct_removes_overlap() {
+        let chunker = SemanticChunker::new(20, 5, vec![" ".to_string()]);
+        let text = "alpha beta gamma delta epsilon zeta eta theta iota kappa";
+
+        let chunks = chunker.split(text);
+
+        assert!(chunks.len() > 1);
+        assert!(chunks
+            .windows(2)
+            .any(|w| w[1].start_offset < w[0].end_offset));
+        assert_eq!(SemanticChunker::reconstruct(&chunks), text);
+    }
+
+    #[test]
+    fn test_reconstruct_keeps_whitespace_runs() {
+        let chunker = SemanticChunker::new(16, 4, vec!["\n".to_string()]);
+        let text = format!(
+            "head line\n{}tail line\n{}",
+            " ".repeat(40),
+            "\n".repeat(30)
+        );
+
+        let chunks = chunker.split(&text);
+
+        assert_eq!(chunks.last().unwrap().end_offset, text.len());
+        assert_eq!(SemanticChunker::reconstruct(&chunks), text);
+    }
+
+    #[test]
+    fn test_reconstruct_empty() {
+        assert_eq!(SemanticChunker::reconstruct(&[]), "");
+    }
+
+    #[test]
+    fn test_split_many_matches_serial_split() {
+        let chunker = SemanticChunker::new(40, 8, SemanticChunker::default_separators());
+        let docs = vec![
+            (
+                "a.rs".to_string(),
+                "fn main() {\n    println!(\"hi\");\n}\n\nfn other() {}\n".repeat(4),
+            ),
+            (
+                "b.md".to_string(),
+                "# Title\n\nSome prose here.\n\n```rust\nlet x = 1;\n```\n".repeat(3),
+            ),
+            ("empty".to_string(), String::new()),
+        ];
+
+        let results = chunker.split_many(&docs);
+
+        assert_eq!(results.len(), docs.len());
+        for ((id, text), (result_id, chunks)) in docs.iter().zip(&results) {
+            assert_eq!(id, result_id);
+            assert_eq!(chunks, &chunker.split(text));
+        }
+        assert!(results[2].1.is_empty());
+    }
+
+    #[test]
+    fn test_split_reader_matches_split() {
+        let mut text = String::new();
+        for i in 0..400 {
+            text.push_str(&format!(
+                "\n## Section {i}\n\nParagraph {i} with some prose.\n"
+            ));
+            if i % 7 == 0 {
+                text.push_str("\n```rust\n");
+                for j in 0..(i % 30) {
+                    text.push_str(&format!("let v{j} = {j} * {i};\n"));
+                }
+                text.push_str("```\n");
+            }
+            if i % 11 == 0 {
+                text.push_str(&" ".repeat(300));
+                text.push_str("\n\n\n");
+            }
+        }
+        text.push_str("\n\n   \n");
+        assert!(text.len() > 40_000);
+
+        for chunker in [
+            SemanticChunker::default(),
+            SemanticChunker::new(128, 32, SemanticChunker::default_separators()),
+            SemanticChunker::new(64, 0, vec![" ".to_string()]),
+        ] {
+            let expected = chunker.split(&text);
+            let reader = std::io::BufReader::with_capacity(17, text.as_bytes());
+            let streamed: Vec<Chunk> = chunker.split_reader(reader).collect();
+
+            assert_eq!(streamed.len(), expected.len());
+            assert_eq!(streamed, expected);
+        }
+    }
+
+    #[test]
+    fn test_split_reader_empty_and_whitespace() {
+        let chunker = SemanticChunker::default();
+        assert_eq!(chunker.split_reader("".as_bytes()).count(), 0);
+        assert_eq!(chunker.split_reader("  \n\t \n".as_bytes()).count(), 0);
+    }
+
+    #[test]
+    fn test_token_overlap_cjk() {
+        let chunker = SemanticChunker::new(30, 3, vec!["。".to_string()]).with_token_overlap();
+        let text = "日本語の文章を分割します。東京は大きな都市です。漢字とかなが混ざっています。";
+
+        let chunks = chunker.split(text);
+
+        assert!(chunks.len() > 1);
+        for pair in chunks.windows(2) {
+            let (prev, next) = (&pair[0], &pair[1]);
+            assert!(next.start_offset > prev.start_offset);
+            if next.start_offset < prev.end_offset {
+                let shared = &text[next.start_offset..prev.end_offset];
+                assert_eq!(shared.chars().count(), 3, "shared: {:?}", shared);
+            }
+        }
+        assert_eq!(SemanticChunker::reconstruct(&chunks), text);
+    }
+
+    #[test]
+    fn test_token_overlap_words() {
+        let chunker = SemanticChunker::new(24, 2, vec![" ".to_string()]).with_token_overlap();
+        let text = "alpha beta gamma delta epsilon zeta eta theta iota kappa lambda";
+
+        let chunks = chunker.split(text);
+
+        assert!(chunks.len() > 1);
+        let (first, second) = (&chunks[0], &chunks[1]);
+        let shared = &text[second.start_offset..first.end_offset];
+        assert_eq!(shared.split_whitespace().count(), 2, "shared: {:?}", shared);
+    }
+
+    #[test]
+    fn test_token_overlap_larger_than_chunk() {
+        // Overlap covering the whole chunk must still make progress
+        let chunker = SemanticChunker::new(8, 100, vec![" ".to_string()]).with_token_overlap();
+        let text = "一二三四五六七八九十 and some words";
+
+        let chunks = chunker.split(text);
+
+        assert!(!chunks.is_empty());
+        assert!(chunks
+            .windows(2)
+            .all(|w| w[1].start_offset > w[0].start_offset));
+        assert_eq!(SemanticChunker::reconstruct(&chunks), text);
+    }
+
+    #[test]
+    fn test_token_starts() {
+        assert_eq!(SemanticChunker::token_starts("ab  cd"), vec![0, 4]);
+        assert_eq!(SemanticChunker::token_starts("東京 x"), vec![0, 3, 7]);
+        assert!(SemanticChunker::token_starts("   ").is_empty());
+    }
+
     #[test]
     fn test_config_round_trip() {
         let chunker = SemanticChunker::new(256, 32, vec!["\n".to_string()]);
@@ -440,6 +1201,7 @@ fn baz() {
                     end_offset: content.len(),
                     start_line: 1,
                     end_line: 1,
+                    kind: ChunkKind::Prose,
                 };
 
                 let hash1 = chunk.content_hash();
@@ -461,6 +1223,7 @@ fn baz() {
                         end_offset: content1.len(),
                         start_line: 1,
                         end_line: 1,
+                        kind: ChunkKind::Prose,
                     };
                     let chunk2 = Chunk {
                         content: content2.clone(),
@@ -468,12 +1231,52 @@ fn baz() {
                         end_offset: content2.len(),
                         start_line: 1,
                         end_line: 1,
+                        kind: ChunkKind::Prose,
                     };
 
                     prop_assert_ne!(chunk1.content_hash(), chunk2.content_hash());
                 }
             }
 
+            /// Property: split followed by reconstruct returns the original text
+            #[test]
+            fn prop_split_reconstruct_round_trip(
+                text in "(?s).{1,600}",
+                chunk_size in 8usize..=128,
+                overlap_ratio in 0usize..=2,
+            ) {
+                prop_assume!(!text.trim().is_empty());
+                let overlap = chunk_size * overlap_ratio / 4;
+                let chunker = SemanticChunker::new(chunk_size, overlap, SemanticChunker::default_separators());
+
+                let chunks = chunker.split(&text);
+                prop_assert_eq!(SemanticChunker::reconstruct(&chunks), text);
+            }
+
+            /// Property: round trip also holds for whitespace-heavy text
+            #[test]
+            fn prop_split_reconstruct_whitespace_runs(
+                text in "[a \n]{1,600}",
+                chunk_size in 8usize..=64,
+            ) {
+                prop_assume!(!text.trim().is_empty());
+                let chunker = SemanticChunker::new(chunk_size, chunk_size / 4, vec!["\n".to_string()]);
+
+                let chunks = chunker.split(&text);
+                prop_assert_eq!(SemanticChunker::reconstruct(&chunks), text);
+            }
+
+            /// Property: streaming and in-memory splitting agree
+            #[test]
+            fn prop_split_reader_matches_split(
+                text in "(?s)([a-z ]{0,12}(\n|\n```\n|\n## )?){0,60}",
+                chunk_size in 8usize..=96,
+            ) {
+                let chunker = SemanticChunker::new(chunk_size, chunk_size / 4, SemanticChunker::default_separators());
+                let streamed: Vec<Chunk> = chunker.split_reader(text.as_bytes()).collect();
+                prop_assert_eq!(streamed, chunker.split(&text));
+            }
+
             /// Property: Custom chunk sizes are respected (approximately)
             #[test]
             fn prop_chunk_size_respected(
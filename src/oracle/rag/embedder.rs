@@ -0,0 +1,121 @@
+//! Hash-based Embedding Fallback
+//!
+//! Feature hashing (Weinberger et al. 2009) of chunk tokens into a fixed
+//! dimension, for running the [`VectorStore`](super::VectorStore) without a
+//! trained embedding model. Texts that share vocabulary share dimensions, so
+//! cosine similarity tracks lexical overlap.
+
+use super::retriever::tokenize;
+
+/// Default embedding dimension
+pub const DEFAULT_HASH_DIMENSION: usize = 256;
+
+/// Deterministic bag-of-tokens embedder
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HashEmbedder {
+    dimension: usize,
+}
+
+impl HashEmbedder {
+    /// Create an embedder producing `dimension`-length vectors (at least 1)
+    pub fn new(dimension: usize) -> Self {
+        Self {
+            dimension: dimension.max(1),
+        }
+    }
+
+    /// Embedding dimension
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// Embed `text` as an L2-normalized feature-hashed token vector
+    ///
+    /// Each token is BLAKE3-hashed to a bucket and a sign (the sign keeps
+    /// collisions from only ever adding up). Text with no tokens embeds to
+    /// the zero vector.
+    pub fn embed(&self, text: &str) -> Vec<f32> {
+        let mut vector = vec![0.0f32; self.dimension];
+        for token in tokenize(text) {
+            let hash = blake3::hash(token.as_bytes());
+            let bytes = hash.as_bytes();
+            let bucket = u64::from_le_bytes(bytes[..8].try_into().unwrap_or_default());
+            let sign = if bytes[8] & 1 == 0 { 1.0 } else { -1.0 };
+            vector[(bucket % self.dimension as u64) as usize] += sign;
+        }
+
+        let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            vector.iter_mut().for_each(|x| *x /= norm);
+        }
+        vector
+    }
+}
+
+impl Default for HashEmbedder {
+    fn default() -> Self {
+        Self::new(DEFAULT_HASH_DIMENSION)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cosine(a: &[f32], b: &[f32]) -> f32 {
+        a.iter().zip(b).map(|(x, y)| x * y).sum()
+    }
+
+    #[test]
+    fn test_embed_deterministic() {
+        let embedder = HashEmbedder::new(64);
+        let text = "SIMD vector addition with trueno";
+        assert_eq!(embedder.embed(text), embedder.embed(text));
+        assert_eq!(embedder.embed(text), HashEmbedder::new(64).embed(text));
+        assert_eq!(embedder.embed(text).len(), 64);
+    }
+
+    #[test]
+    fn test_shared_vocabulary_scores_higher() {
+        let embedder = HashEmbedder::default();
+        let a = embedder.embed("trueno accelerates vector math with SIMD kernels");
+        let b = embedder.embed("vector math kernels run faster with SIMD in trueno");
+        let c = embedder.embed("the oracle recommends components for sovereign stacks");
+
+        assert!(cosine(&a, &b) > cosine(&a, &c));
+        assert!((cosine(&a, &a) - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_empty_text_is_zero_vector() {
+        let embedder = HashEmbedder::new(0);
+        assert_eq!(embedder.dimension(), 1);
+        assert_eq!(embedder.embed(""), vec![0.0]);
+        assert!(HashEmbedder::default()
+            .embed("  ,;  ")
+            .iter()
+            .all(|x| *x == 0.0));
+    }
+
+    #[test]
+    fn test_embeds_into_vector_store() {
+        use crate::oracle::rag::{Chunk, ChunkKind, VectorStore};
+
+        let embedder = HashEmbedder::default();
+        let mut store = VectorStore::new();
+        for content in ["matrix multiply on the GPU", "parse python imports"] {
+            let chunk = Chunk {
+                content: content.to_string(),
+                start_offset: 0,
+                end_offset: content.len(),
+                start_line: 1,
+                end_line: 1,
+                kind: ChunkKind::Prose,
+            };
+            store.add(chunk, embedder.embed(content));
+        }
+
+        let results = store.search(&embedder.embed("GPU matrix multiply"), 1);
+        assert_eq!(results[0].1.content, "matrix multiply on the GPU");
+    }
+}
@@ -0,0 +1,177 @@
+//! Hybrid Chunk Retrieval - VectorStore + Bm25Index with weighted RRF
+//!
+//! Runs dense ([`VectorStore`]) and lexical ([`Bm25Index`]) search over the
+//! same chunks and fuses the two rankings with Reciprocal Rank Fusion
+//! (Cormack et al. 2009), so chunks that rank well in both lists rise above
+//! ones that only one retriever likes.
+
+use super::bm25::Bm25Index;
+use super::chunker::Chunk;
+use super::types::RrfConfig;
+use super::vector_store::VectorStore;
+use std::collections::HashMap;
+
+/// Default share of the fused score given to dense retrieval
+pub const DEFAULT_DENSE_WEIGHT: f32 = 0.5;
+
+/// Fuses [`VectorStore`] and [`Bm25Index`] results into one ranking
+///
+/// Both indexes are expected to hold the same chunks; results are joined on
+/// chunk equality.
+#[derive(Debug, Clone, Copy)]
+pub struct HybridChunkRetriever<'a> {
+    store: &'a VectorStore,
+    bm25: &'a Bm25Index,
+    rrf_config: RrfConfig,
+    dense_weight: f32,
+}
+
+impl<'a> HybridChunkRetriever<'a> {
+    /// Create a retriever over a vector store and a BM25 index
+    pub fn new(store: &'a VectorStore, bm25: &'a Bm25Index) -> Self {
+        Self {
+            store,
+            bm25,
+            rrf_config: RrfConfig::default(),
+            dense_weight: DEFAULT_DENSE_WEIGHT,
+        }
+    }
+
+    /// Set the dense share of the fused score (clamped to `[0, 1]`)
+    ///
+    /// BM25 gets `1 - weight`; `1.0` ranks purely by embedding similarity,
+    /// `0.0` purely lexically.
+    pub fn with_dense_weight(mut self, weight: f32) -> Self {
+        self.dense_weight = weight.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the RRF rank constant
+    pub fn with_rrf_config(mut self, rrf_config: RrfConfig) -> Self {
+        self.rrf_config = rrf_config;
+        self
+    }
+
+    /// Dense share of the fused score
+    pub fn dense_weight(&self) -> f32 {
+        self.dense_weight
+    }
+
+    /// Top `k` chunks by fused score, highest first
+    ///
+    /// A chunk at rank `r` (1-based) of a list earns `weight / (rrf_k + r)`
+    /// from it. Scores are normalized to `[0, 1]` by the best possible score
+    /// (rank 1 in both lists). A list with weight 0 is not searched, so its
+    /// hits never appear at score 0.
+    pub fn search(&self, query: &str, query_embedding: &[f32], k: usize) -> Vec<(f32, &'a Chunk)> {
+        let rrf_k = self.rrf_config.k as f32;
+        let sparse_weight = 1.0 - self.dense_weight;
+        let dense = if self.dense_weight > 0.0 {
+            self.store.search(query_embedding, self.store.len())
+        } else {
+            Vec::new()
+        };
+        let sparse = if sparse_weight > 0.0 {
+            self.bm25.search(query, self.bm25.len())
+        } else {
+            Vec::new()
+        };
+
+        let mut fused: HashMap<&'a Chunk, f32> = HashMap::new();
+        for (weight, results) in [(self.dense_weight, dense), (sparse_weight, sparse)] {
+            for (rank, (_, chunk)) in results.into_iter().enumerate() {
+                *fused.entry(chunk).or_insert(0.0) += weight / (rrf_k + rank as f32 + 1.0);
+            }
+        }
+
+        let max_score = 1.0 / (rrf_k + 1.0);
+        let mut results: Vec<(f32, &'a Chunk)> = fused
+            .into_iter()
+            .map(|(chunk, score)| ((score / max_score).min(1.0), chunk))
+            .collect();
+
+        // Ties broken by position for a deterministic order
+        results.sort_by(|a, b| {
+            b.0.total_cmp(&a.0)
+                .then_with(|| a.1.start_offset.cmp(&b.1.start_offset))
+                .then_with(|| a.1.content.cmp(&b.1.content))
+        });
+        results.truncate(k);
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Dense ranks: alpha, beta, mid. BM25 ranks: lexical, lexical2, mid.
+    fn indexes() -> (VectorStore, Bm25Index) {
+        let mut store = VectorStore::new();
+        let mut bm25 = Bm25Index::new();
+        for (content, embedding) in [
+            ("alpha", [1.0, 0.0]),
+            ("beta", [0.9, 0.2]),
+            ("mid: notes on the gpu kernel launch path", [0.8, 0.4]),
+            ("gpu kernel", [0.0, 0.0]),
+            ("gpu kernel tuning", [0.0, 0.0]),
+        ] {
+            store.add(Chunk::for_test(content), embedding.to_vec());
+            bm25.add(Chunk::for_test(content));
+        }
+        (store, bm25)
+    }
+
+    #[test]
+    fn test_consistently_relevant_chunk_rises_to_top() {
+        let (store, bm25) = indexes();
+        let query = ("gpu kernel", [1.0, 0.0]);
+
+        let dense = store.search(&query.1, 5);
+        assert_eq!(
+            dense[2].1.content,
+            "mid: notes on the gpu kernel launch path"
+        );
+        let sparse = bm25.search(query.0, 5);
+        assert_eq!(sparse.len(), 3);
+        assert_eq!(
+            sparse[2].1.content,
+            "mid: notes on the gpu kernel launch path"
+        );
+
+        let retriever = HybridChunkRetriever::new(&store, &bm25);
+        let fused = retriever.search(query.0, &query.1, 3);
+        assert_eq!(
+            fused[0].1.content,
+            "mid: notes on the gpu kernel launch path"
+        );
+        assert!(fused.iter().all(|(score, _)| (0.0..=1.0).contains(score)));
+        assert!(fused.windows(2).all(|w| w[0].0 >= w[1].0));
+    }
+
+    #[test]
+    fn test_dense_weight_extremes() {
+        let (store, bm25) = indexes();
+        let dense_only = HybridChunkRetriever::new(&store, &bm25).with_dense_weight(2.0);
+        assert_eq!(dense_only.dense_weight(), 1.0);
+        let top = dense_only.search("gpu kernel", &[1.0, 0.0], 1);
+        assert_eq!(top[0].1.content, "alpha");
+        assert!((top[0].0 - 1.0).abs() < 1e-6);
+
+        let sparse_only = HybridChunkRetriever::new(&store, &bm25).with_dense_weight(0.0);
+        let top = sparse_only.search("gpu kernel", &[1.0, 0.0], 1);
+        assert_eq!(top[0].1.content, "gpu kernel");
+
+        // Dense-only hits are dropped rather than listed at score 0
+        let all = sparse_only.search("gpu kernel", &[1.0, 0.0], 5);
+        assert_eq!(all.len(), 3);
+        assert!(all.iter().all(|(score, _)| *score > 0.0));
+    }
+
+    #[test]
+    fn test_empty_indexes() {
+        let (store, bm25) = (VectorStore::new(), Bm25Index::new());
+        let retriever = HybridChunkRetriever::new(&store, &bm25);
+        assert!(retriever.search("gpu", &[1.0], 5).is_empty());
+    }
+}
@@ -18,10 +18,16 @@
 // Allow dead code and unused imports for library implementation
 // Full integration will use all exported types
 #[allow(dead_code)]
+mod bm25;
+#[allow(dead_code)]
 mod chunker;
 #[allow(dead_code)]
+mod embedder;
+#[allow(dead_code)]
 mod fingerprint;
 #[allow(dead_code)]
+mod hybrid;
+#[allow(dead_code)]
 mod indexer;
 #[allow(dead_code)]
 mod retriever;
@@ -30,12 +36,20 @@ pub mod tui;
 mod types;
 #[allow(dead_code)]
 mod validator;
+#[allow(dead_code)]
+mod vector_store;
 
 #[allow(unused_imports)]
-pub use chunker::SemanticChunker;
+pub use bm25::Bm25Index;
+#[allow(unused_imports)]
+pub use chunker::{Chunk, ChunkKind, Language, SemanticChunker};
+#[allow(unused_imports)]
+pub use embedder::HashEmbedder;
 #[allow(unused_imports)]
 pub use fingerprint::{ChunkerConfig, DocumentFingerprint};
 #[allow(unused_imports)]
+pub use hybrid::HybridChunkRetriever;
+#[allow(unused_imports)]
 pub use indexer::HeijunkaReindexer;
 #[allow(unused_imports)]
 pub use retriever::HybridRetriever;
@@ -45,6 +59,8 @@ pub use types::RetrievalResult;
 pub use types::*;
 #[allow(unused_imports)]
 pub use validator::JidokaIndexValidator;
+#[allow(unused_imports)]
+pub use vector_store::VectorStore;
 
 use std::collections::HashMap;
 use std::path::PathBuf;
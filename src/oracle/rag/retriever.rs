@@ -278,7 +278,7 @@ impl InvertedIndex {
 }
 
 /// Simple tokenizer for text
-fn tokenize(text: &str) -> Vec<String> {
+pub(super) fn tokenize(text: &str) -> Vec<String> {
     text.to_lowercase()
         .split(|c: char| !c.is_alphanumeric() && c != '_')
         .filter(|s| !s.is_empty() && s.len() > 1)
@@ -0,0 +1,364 @@
+//! In-memory Vector Store for Dense Retrieval
+//!
+//! Holds chunk embeddings and ranks them against a query embedding by
+//! cosine similarity (brute force, exact). Stores persist as versioned JSON
+//! so a corpus is embedded once, not on every run.
+
+use super::chunker::Chunk;
+use anyhow::{bail, Context};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// On-disk format version written by [`VectorStore::save`]
+pub const VECTOR_STORE_FORMAT_VERSION: u32 = 1;
+
+/// In-memory store of `(chunk, embedding)` pairs
+#[derive(Debug, Clone, Default)]
+pub struct VectorStore {
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    chunk: Chunk,
+    embedding: Vec<f32>,
+    /// Cached L2 norm of `embedding`
+    norm: f32,
+}
+
+impl VectorStore {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a chunk with its embedding
+    pub fn add(&mut self, chunk: Chunk, embedding: Vec<f32>) {
+        let norm = l2_norm(&embedding);
+        self.entries.push(Entry {
+            chunk,
+            embedding,
+            norm,
+        });
+    }
+
+    /// Top `k` chunks by cosine similarity to `query`, most similar first
+    ///
+    /// Zero-norm vectors have no direction: a zero query returns nothing and
+    /// zero-norm entries are never returned. Entries whose dimension differs
+    /// from the query's are skipped.
+    pub fn search(&self, query: &[f32], k: usize) -> Vec<(f32, &Chunk)> {
+        if k == 0 {
+            return Vec::new();
+        }
+
+        let mut results: Vec<(f32, &Chunk)> = self
+            .relevance(query)
+            .into_iter()
+            .map(|(score, entry)| (score, &entry.chunk))
+            .collect();
+
+        results.sort_by(|a, b| b.0.total_cmp(&a.0));
+        results.truncate(k);
+        results
+    }
+
+    /// Top `k` chunks by Maximal Marginal Relevance (Carbonell & Goldstein 1998)
+    ///
+    /// Each pick maximizes `lambda × sim(query, d) − (1 − lambda) × max sim(d, picked)`,
+    /// trading relevance for diversity from the chunks already picked;
+    /// `lambda = 1.0` is plain [`Self::search`]. `lambda` is clamped to
+    /// `[0, 1]`. Scores returned are the cosine similarity to the query, in
+    /// pick order.
+    pub fn search_mmr(&self, query: &[f32], k: usize, lambda: f32) -> Vec<(f32, &Chunk)> {
+        let lambda = lambda.clamp(0.0, 1.0);
+        let mut candidates = self.relevance(query);
+        let mut picked: Vec<(f32, &Entry)> = Vec::with_capacity(k.min(candidates.len()));
+
+        while picked.len() < k && !candidates.is_empty() {
+            let mmr = |&(relevance, entry): &(f32, &Entry)| {
+                let redundancy = picked
+                    .iter()
+                    .map(|(_, other)| entry.cosine(other))
+                    .fold(0.0, f32::max);
+                lambda * relevance - (1.0 - lambda) * redundancy
+            };
+            let mut best = 0;
+            let mut best_score = mmr(&candidates[0]);
+            for (i, candidate) in candidates.iter().enumerate().skip(1) {
+                let score = mmr(candidate);
+                if score > best_score {
+                    (best, best_score) = (i, score);
+                }
+            }
+            picked.push(candidates.remove(best));
+        }
+
+        picked
+            .into_iter()
+            .map(|(score, entry)| (score, &entry.chunk))
+            .collect()
+    }
+
+    /// Cosine similarity of every comparable entry to `query`, in insertion order
+    fn relevance(&self, query: &[f32]) -> Vec<(f32, &Entry)> {
+        let query_norm = l2_norm(query);
+        if query_norm == 0.0 {
+            return Vec::new();
+        }
+        self.entries
+            .iter()
+            .filter(|entry| entry.norm > 0.0 && entry.embedding.len() == query.len())
+            .map(|entry| {
+                (
+                    dot(&entry.embedding, query) / (entry.norm * query_norm),
+                    entry,
+                )
+            })
+            .collect()
+    }
+
+    /// Number of stored chunks
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the store holds no chunks
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Save chunks and embeddings as JSON
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let file = StoreFile {
+            version: VECTOR_STORE_FORMAT_VERSION,
+            dimension: self.entries.first().map(|e| e.embedding.len()),
+            entries: self
+                .entries
+                .iter()
+                .map(|e| StoredEntry {
+                    chunk: e.chunk.clone(),
+                    embedding: e.embedding.clone(),
+                })
+                .collect(),
+        };
+        let json = serde_json::to_string(&file)?;
+        std::fs::write(path, json)
+            .with_context(|| format!("Failed to write vector store {}", path.display()))?;
+        Ok(())
+    }
+
+    /// Load a store written by [`Self::save`]
+    ///
+    /// Fails on an unknown format version or if any embedding's dimension
+    /// differs from the one recorded in the header.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read vector store {}", path.display()))?;
+        let file: StoreFile = serde_json::from_str(&json)
+            .with_context(|| format!("Invalid vector store {}", path.display()))?;
+        if file.version != VECTOR_STORE_FORMAT_VERSION {
+            bail!(
+                "Unsupported vector store format version {} in {} (expected {})",
+                file.version,
+                path.display(),
+                VECTOR_STORE_FORMAT_VERSION
+            );
+        }
+
+        let mut store = Self::new();
+        for (i, entry) in file.entries.into_iter().enumerate() {
+            if Some(entry.embedding.len()) != file.dimension {
+                bail!(
+                    "Embedding dimension mismatch in {}: entry {} has {} dimensions, header says {}",
+                    path.display(),
+                    i,
+                    entry.embedding.len(),
+                    file.dimension.map_or("none".to_string(), |d| d.to_string())
+                );
+            }
+            store.add(entry.chunk, entry.embedding);
+        }
+        Ok(store)
+    }
+}
+
+/// Serialized form of a [`VectorStore`]
+#[derive(Serialize, Deserialize)]
+struct StoreFile {
+    version: u32,
+    /// Embedding dimension shared by all entries (`None` for an empty store)
+    dimension: Option<usize>,
+    entries: Vec<StoredEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredEntry {
+    chunk: Chunk,
+    embedding: Vec<f32>,
+}
+
+impl Entry {
+    fn cosine(&self, other: &Entry) -> f32 {
+        if self.norm == 0.0 || other.norm == 0.0 {
+            return 0.0;
+        }
+        dot(&self.embedding, &other.embedding) / (self.norm * other.norm)
+    }
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn l2_norm(v: &[f32]) -> f32 {
+    v.iter().map(|x| x * x).sum::<f32>().sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_store_returns_nothing() {
+        let store = VectorStore::new();
+        assert!(store.is_empty());
+        assert_eq!(store.len(), 0);
+        assert!(store.search(&[1.0, 0.0], 5).is_empty());
+    }
+
+    #[test]
+    fn test_search_top_k_ordering() {
+        let mut store = VectorStore::new();
+        store.add(Chunk::for_test("orthogonal"), vec![0.0, 1.0]);
+        store.add(Chunk::for_test("exact"), vec![2.0, 0.0]);
+        store.add(Chunk::for_test("opposite"), vec![-1.0, 0.0]);
+        store.add(Chunk::for_test("close"), vec![1.0, 0.5]);
+        assert_eq!(store.len(), 4);
+
+        let results = store.search(&[1.0, 0.0], 3);
+        let contents: Vec<&str> = results.iter().map(|(_, c)| c.content.as_str()).collect();
+        assert_eq!(contents, ["exact", "close", "orthogonal"]);
+        assert!((results[0].0 - 1.0).abs() < 1e-6);
+        assert!(results.windows(2).all(|w| w[0].0 >= w[1].0));
+
+        assert_eq!(store.search(&[1.0, 0.0], 10).len(), 4);
+        assert!(store.search(&[1.0, 0.0], 0).is_empty());
+    }
+
+    #[test]
+    fn test_zero_norm_vectors() {
+        let mut store = VectorStore::new();
+        store.add(Chunk::for_test("zero"), vec![0.0, 0.0]);
+        store.add(Chunk::for_test("unit"), vec![1.0, 0.0]);
+
+        assert!(store.search(&[0.0, 0.0], 5).is_empty());
+
+        let results = store.search(&[1.0, 1.0], 5);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].1.content, "unit");
+        assert!(results[0].0.is_finite());
+    }
+
+    #[test]
+    fn test_mmr_prefers_distinct_over_duplicate() {
+        let mut store = VectorStore::new();
+        store.add(Chunk::for_test("original"), vec![1.0, 0.0, 0.0]);
+        store.add(Chunk::for_test("duplicate"), vec![0.99, 0.01, 0.0]);
+        store.add(Chunk::for_test("distinct"), vec![0.6, 0.0, 0.8]);
+
+        let query = [1.0, 0.0, 0.3];
+        let top: Vec<&str> = store
+            .search(&query, 2)
+            .iter()
+            .map(|(_, c)| c.content.as_str())
+            .collect();
+        assert_eq!(top, ["original", "duplicate"]);
+
+        let mmr = store.search_mmr(&query, 2, 0.5);
+        let picked: Vec<&str> = mmr.iter().map(|(_, c)| c.content.as_str()).collect();
+        assert_eq!(picked, ["original", "distinct"]);
+    }
+
+    #[test]
+    fn test_mmr_lambda_one_is_top_k() {
+        let mut store = VectorStore::new();
+        store.add(Chunk::for_test("orthogonal"), vec![0.0, 1.0]);
+        store.add(Chunk::for_test("exact"), vec![2.0, 0.0]);
+        store.add(Chunk::for_test("zero"), vec![0.0, 0.0]);
+        store.add(Chunk::for_test("close"), vec![1.0, 0.5]);
+        store.add(Chunk::for_test("near"), vec![1.0, 0.6]);
+
+        for k in 0..=5 {
+            assert_eq!(
+                store.search_mmr(&[1.0, 0.1], k, 1.0),
+                store.search(&[1.0, 0.1], k)
+            );
+        }
+        assert!(store.search_mmr(&[0.0, 0.0], 3, 0.5).is_empty());
+        assert!(VectorStore::new().search_mmr(&[1.0], 3, 0.5).is_empty());
+    }
+
+    #[test]
+    fn test_save_load_round_trip() {
+        let mut store = VectorStore::new();
+        store.add(Chunk::for_test("exact"), vec![2.0, 0.0, 0.1]);
+        store.add(Chunk::for_test("close"), vec![1.0, 0.5, -0.3]);
+        store.add(Chunk::for_test("far"), vec![-1.0, 0.2, 0.7]);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("store.json");
+        store.save(&path).unwrap();
+        let loaded = VectorStore::load(&path).unwrap();
+
+        assert_eq!(loaded.len(), store.len());
+        let query = [0.9, 0.1, 0.2];
+        assert_eq!(loaded.search(&query, 3), store.search(&query, 3));
+
+        let empty = dir.path().join("empty.json");
+        VectorStore::new().save(&empty).unwrap();
+        assert!(VectorStore::load(&empty).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_load_rejects_bad_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("store.json");
+        let entry = |dims: &str| {
+            format!(
+                r#"{{"chunk":{{"content":"x","start_offset":0,"end_offset":1,"start_line":1,"end_line":1,"kind":"Prose"}},"embedding":[{}]}}"#,
+                dims
+            )
+        };
+
+        std::fs::write(
+            &path,
+            format!(
+                r#"{{"version":1,"dimension":2,"entries":[{},{}]}}"#,
+                entry("1.0,0.0"),
+                entry("1.0,0.0,0.0")
+            ),
+        )
+        .unwrap();
+        let err = VectorStore::load(&path).unwrap_err().to_string();
+        assert!(err.contains("dimension mismatch"), "{}", err);
+        assert!(err.contains("entry 1 has 3"), "{}", err);
+
+        std::fs::write(&path, r#"{"version":99,"dimension":null,"entries":[]}"#).unwrap();
+        let err = VectorStore::load(&path).unwrap_err().to_string();
+        assert!(err.contains("format version 99"), "{}", err);
+
+        assert!(VectorStore::load(&dir.path().join("missing.json")).is_err());
+    }
+
+    #[test]
+    fn test_dimension_mismatch_skipped() {
+        let mut store = VectorStore::new();
+        store.add(Chunk::for_test("3d"), vec![1.0, 0.0, 0.0]);
+        store.add(Chunk::for_test("2d"), vec![1.0, 0.0]);
+
+        let results = store.search(&[1.0, 0.0], 5);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].1.content, "2d");
+    }
+}
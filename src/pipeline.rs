@@ -282,9 +282,19 @@ impl TranspilationStage {
                                     ("np.add", NumPyOp::Add),
                                     ("np.subtract", NumPyOp::Subtract),
                                     ("np.multiply", NumPyOp::Multiply),
+                                    ("np.divide", NumPyOp::Divide),
                                     ("np.dot", NumPyOp::Dot),
                                     ("np.sum", NumPyOp::Sum),
+                                    ("np.mean", NumPyOp::Mean),
+                                    ("np.max(", NumPyOp::Max),
+                                    ("np.min(", NumPyOp::Min),
                                     ("np.array", NumPyOp::Array),
+                                    ("np.zeros", NumPyOp::Zeros),
+                                    ("np.ones", NumPyOp::Ones),
+                                    ("np.arange", NumPyOp::Arange),
+                                    ("np.linspace", NumPyOp::Linspace),
+                                    ("np.reshape", NumPyOp::Reshape),
+                                    ("np.transpose", NumPyOp::Transpose),
                                 ];
 
                                 for (pattern, op) in operations {
@@ -16,6 +16,47 @@ use std::fs;
 use std::path::PathBuf;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
+/// Error returned when crates.io has no crate with the requested name
+///
+/// Distinguishes an unpublished crate from transient network failures;
+/// check with [`is_not_found`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Crate '{0}' not found on crates.io")]
+pub struct CrateNotFound(pub String);
+
+/// Check whether a crates.io lookup failed because the crate does not exist
+pub fn is_not_found(err: &anyhow::Error) -> bool {
+    err.is::<CrateNotFound>()
+}
+
+/// Error returned when a crates.io lookup fails for a reason other than a
+/// missing crate; check with [`is_retryable`] whether to try again
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum CrateFetchError {
+    /// Offline mode and the crate is not cached
+    #[error("Crate '{0}' not found in cache (offline mode)")]
+    Offline(String),
+    /// The request got no response (connection failure, timeout)
+    #[error("Failed to fetch crate {name}: {message}")]
+    Transport { name: String, message: String },
+    /// crates.io answered with a non-success status other than 404
+    #[error("Failed to fetch crate {name}: HTTP {status}")]
+    Status { name: String, status: u16 },
+}
+
+/// Check whether a failed crates.io lookup may succeed if retried
+///
+/// Transport failures, 5xx responses and 429 rate limiting are transient.
+/// A missing crate, an offline cache miss, other statuses and unparseable
+/// responses will fail the same way again.
+pub fn is_retryable(err: &anyhow::Error) -> bool {
+    match err.downcast_ref::<CrateFetchError>() {
+        Some(CrateFetchError::Transport { .. }) => true,
+        Some(CrateFetchError::Status { status, .. }) => *status >= 500 || *status == 429,
+        Some(CrateFetchError::Offline(_)) | None => false,
+    }
+}
+
 /// Cache entry with TTL
 #[derive(Debug, Clone)]
 struct CacheEntry<T> {
@@ -176,6 +217,22 @@ pub struct VersionData {
     pub created_at: String,
 }
 
+impl CrateResponse {
+    /// Check whether the crate's `max_version` has been yanked
+    ///
+    /// crates.io leaves yanked releases out of `max_version` unless every
+    /// release is yanked, so this is rarely true; prefer
+    /// [`Self::is_version_yanked`] for a specific version.
+    pub fn is_max_version_yanked(&self) -> bool {
+        self.is_version_yanked(&self.krate.max_version)
+    }
+
+    /// Check whether `version` was published and then yanked
+    pub fn is_version_yanked(&self, version: &str) -> bool {
+        self.versions.iter().any(|v| v.num == version && v.yanked)
+    }
+}
+
 impl CratesIoClient {
     /// Create a new crates.io client
     #[cfg(feature = "native")]
@@ -243,53 +300,79 @@ impl CratesIoClient {
             }
         }
 
+        let crate_response = self.fetch_crate(name).await?;
+        self.cache_crate(name, &crate_response);
+
+        Ok(crate_response)
+    }
+
+    /// Look up crate info in the in-memory and persistent caches
+    pub fn cached_crate(&self, name: &str) -> Option<CrateResponse> {
+        self.cache
+            .get(name)
+            .filter(|entry| !entry.is_expired())
+            .map(|entry| entry.value.clone())
+            .or_else(|| {
+                self.persistent_cache
+                    .as_ref()
+                    .and_then(|persistent| persistent.get(name).cloned())
+            })
+    }
+
+    /// Fetch crate info from the crates.io API, bypassing caches
+    ///
+    /// Takes `&self` so several fetches can run concurrently; store the
+    /// result with [`Self::cache_crate`].
+    #[cfg(feature = "native")]
+    pub async fn fetch_crate(&self, name: &str) -> Result<CrateResponse> {
         // In offline mode, return error if not in cache
         if self.offline {
-            return Err(anyhow!(
-                "Crate '{}' not found in cache (offline mode)",
-                name
-            ));
+            return Err(CrateFetchError::Offline(name.to_string()).into());
         }
 
         // Fetch from API
         let url = format!("https://crates.io/api/v1/crates/{}", name);
-        let response = self
-            .client
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| anyhow!("Failed to fetch crate {}: {}", name, e))?;
+        let response =
+            self.client
+                .get(&url)
+                .send()
+                .await
+                .map_err(|e| CrateFetchError::Transport {
+                    name: name.to_string(),
+                    message: e.to_string(),
+                })?;
 
         if response.status() == reqwest::StatusCode::NOT_FOUND {
-            return Err(anyhow!("Crate '{}' not found on crates.io", name));
+            return Err(CrateNotFound(name.to_string()).into());
         }
 
         if !response.status().is_success() {
-            return Err(anyhow!(
-                "Failed to fetch crate {}: HTTP {}",
-                name,
-                response.status()
-            ));
+            return Err(CrateFetchError::Status {
+                name: name.to_string(),
+                status: response.status().as_u16(),
+            }
+            .into());
         }
 
-        let crate_response: CrateResponse = response
+        response
             .json()
             .await
-            .map_err(|e| anyhow!("Failed to parse crate response: {}", e))?;
+            .map_err(|e| anyhow!("Failed to parse crate response: {}", e))
+    }
 
+    /// Store crate info in the in-memory and persistent caches
+    pub fn cache_crate(&mut self, name: &str, response: &CrateResponse) {
         // Cache the result in memory
         self.cache.insert(
             name.to_string(),
-            CacheEntry::new(crate_response.clone(), self.cache_ttl),
+            CacheEntry::new(response.clone(), self.cache_ttl),
         );
 
         // Also save to persistent cache
         if let Some(ref mut persistent) = self.persistent_cache {
-            persistent.insert(name.to_string(), crate_response.clone(), self.cache_ttl);
+            persistent.insert(name.to_string(), response.clone(), self.cache_ttl);
             let _ = persistent.save(); // Ignore save errors
         }
-
-        Ok(crate_response)
     }
 
     /// Get the latest version of a crate
@@ -438,8 +521,7 @@ impl MockCratesIoClient {
     pub fn get_crate(&self, name: &str) -> Result<CrateResponse> {
         match self.responses.get(name) {
             Some(Ok(response)) => Ok(response.clone()),
-            Some(Err(e)) => Err(anyhow!("{}", e)),
-            None => Err(anyhow!("Crate '{}' not found", name)),
+            Some(Err(_)) | None => Err(CrateNotFound(name.to_string()).into()),
         }
     }
 
@@ -558,7 +640,12 @@ mod tests {
         let mut mock = MockCratesIoClient::new();
         mock.add_not_found("broken-crate");
 
-        assert!(mock.get_crate("broken-crate").is_err());
+        let err = mock.get_crate("broken-crate").unwrap_err();
+        assert!(is_not_found(&err));
+        assert_eq!(
+            err.to_string(),
+            "Crate 'broken-crate' not found on crates.io"
+        );
     }
 
     #[test]
@@ -825,6 +912,57 @@ mod tests {
         assert_eq!(response.krate.max_stable_version, Some("0.9.0".to_string()));
     }
 
+    /// Test that non-404 errors are not reported as missing crates
+    #[test]
+    fn test_CRATES_004_is_not_found_other_error() {
+        assert!(!is_not_found(&anyhow!(
+            "Failed to fetch crate trueno: timeout"
+        )));
+        assert!(is_not_found(&CrateNotFound("trueno".to_string()).into()));
+    }
+
+    /// Only transport errors, 5xx and 429 are worth retrying
+    #[test]
+    fn test_CRATES_004_is_retryable() {
+        let status = |status| -> anyhow::Error {
+            CrateFetchError::Status {
+                name: "trueno".to_string(),
+                status,
+            }
+            .into()
+        };
+        assert!(is_retryable(&status(503)));
+        assert!(is_retryable(&status(429)));
+        assert!(!is_retryable(&status(403)));
+        assert!(is_retryable(
+            &CrateFetchError::Transport {
+                name: "trueno".to_string(),
+                message: "timeout".to_string(),
+            }
+            .into()
+        ));
+
+        assert!(!is_retryable(
+            &CrateFetchError::Offline("trueno".to_string()).into()
+        ));
+        assert!(!is_retryable(&CrateNotFound("trueno".to_string()).into()));
+        assert!(!is_retryable(&anyhow!("Failed to parse crate response")));
+    }
+
+    /// Offline cache misses fail with a typed, non-retryable error
+    #[cfg(feature = "native")]
+    #[tokio::test]
+    async fn test_CRATES_004_offline_miss_not_retryable() {
+        let mut client = CratesIoClient::new();
+        client.set_offline(true);
+        let err = client.fetch_crate("trueno").await.unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<CrateFetchError>(),
+            Some(&CrateFetchError::Offline("trueno".to_string()))
+        );
+        assert!(!is_retryable(&err));
+    }
+
     /// RED PHASE: Test deserialization with yanked versions
     #[test]
     fn test_CRATES_004_deserialize_yanked_versions() {
@@ -846,6 +984,45 @@ mod tests {
         let response: CrateResponse = serde_json::from_str(json).unwrap();
         assert!(!response.versions[0].yanked);
         assert!(response.versions[1].yanked);
+        assert!(!response.is_max_version_yanked());
+    }
+
+    /// Test yank detection on the max version
+    #[test]
+    fn test_CRATES_004_max_version_yanked() {
+        let mut response = MockCratesIoClient::new()
+            .add_crate("trueno", "1.0.0")
+            .get_crate("trueno")
+            .unwrap();
+        assert!(!response.is_max_version_yanked());
+
+        response.versions[0].yanked = true;
+        assert!(response.is_max_version_yanked());
+    }
+
+    /// A yanked release above `max_version` is still reported as yanked
+    #[test]
+    fn test_CRATES_004_version_yanked_above_max() {
+        let json = r#"{
+            "crate": {
+                "name": "trueno",
+                "max_version": "1.0.0",
+                "max_stable_version": "1.0.0",
+                "description": null,
+                "downloads": 1000,
+                "updated_at": "2025-12-01T00:00:00Z"
+            },
+            "versions": [
+                {"num": "1.0.1", "yanked": true, "downloads": 10, "created_at": "2025-12-01T00:00:00Z"},
+                {"num": "1.0.0", "yanked": false, "downloads": 990, "created_at": "2025-01-01T00:00:00Z"}
+            ]
+        }"#;
+
+        let response: CrateResponse = serde_json::from_str(json).unwrap();
+        assert!(!response.is_max_version_yanked());
+        assert!(response.is_version_yanked("1.0.1"));
+        assert!(!response.is_version_yanked("1.0.0"));
+        assert!(!response.is_version_yanked("1.0.2"));
     }
 
     // ============================================================================
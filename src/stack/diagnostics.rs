@@ -16,7 +16,8 @@ use crate::stack::quality::{QualityGrade, StackLayer};
 use crate::stack::DependencyGraph;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
 
 // ============================================================================
 // Simple PRNG (for reproducible isolation forest without external deps)
@@ -78,10 +79,18 @@ pub enum HealthStatus {
 impl HealthStatus {
     /// Create from quality grade
     pub fn from_grade(grade: QualityGrade) -> Self {
-        match grade {
-            QualityGrade::APlus | QualityGrade::A => Self::Green,
-            QualityGrade::AMinus | QualityGrade::BPlus => Self::Yellow,
-            _ => Self::Red,
+        Self::from_grade_with(grade, &HealthThresholds::default())
+    }
+
+    /// Create from quality grade using custom thresholds
+    pub fn from_grade_with(grade: QualityGrade, thresholds: &HealthThresholds) -> Self {
+        // QualityGrade orders best-first, so "at least" means `<=`
+        if grade <= thresholds.green_min {
+            Self::Green
+        } else if grade <= thresholds.yellow_min {
+            Self::Yellow
+        } else {
+            Self::Red
         }
     }
 
@@ -106,6 +115,37 @@ impl HealthStatus {
     }
 }
 
+/// Grade thresholds mapping `QualityGrade` ranges to `HealthStatus`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HealthThresholds {
+    /// Worst grade still considered Green
+    pub green_min: QualityGrade,
+    /// Worst grade still considered Yellow (anything below is Red)
+    pub yellow_min: QualityGrade,
+}
+
+impl HealthThresholds {
+    /// Create thresholds from the worst Green and worst Yellow grades
+    pub fn new(green_min: QualityGrade, yellow_min: QualityGrade) -> Self {
+        Self {
+            green_min,
+            yellow_min,
+        }
+    }
+
+    /// Strict preset: only A+ is Green, A- and A are Yellow
+    pub fn strict() -> Self {
+        Self::new(QualityGrade::APlus, QualityGrade::AMinus)
+    }
+}
+
+impl Default for HealthThresholds {
+    /// Standard preset: A+/A Green, A-/B+ Yellow, B and below Red
+    fn default() -> Self {
+        Self::new(QualityGrade::A, QualityGrade::BPlus)
+    }
+}
+
 impl std::fmt::Display for HealthStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.icon())
@@ -145,7 +185,12 @@ impl ComponentNode {
 
     /// Update health status from metrics
     pub fn update_health(&mut self) {
-        self.health = HealthStatus::from_grade(self.metrics.grade);
+        self.update_health_with(&HealthThresholds::default());
+    }
+
+    /// Update health status from metrics using custom thresholds
+    pub fn update_health_with(&mut self, thresholds: &HealthThresholds) {
+        self.health = HealthStatus::from_grade_with(self.metrics.grade, thresholds);
     }
 }
 
@@ -168,6 +213,9 @@ pub struct ComponentMetrics {
     pub satd_count: u32,
     /// Dead code percentage
     pub dead_code_pct: f64,
+    /// Clean build time in seconds
+    #[serde(default)]
+    pub build_time_sec: f64,
     /// Overall quality grade
     pub grade: QualityGrade,
 }
@@ -181,6 +229,7 @@ impl Default for ComponentMetrics {
             complexity_avg: 0.0,
             satd_count: 0,
             dead_code_pct: 0.0,
+            build_time_sec: 0.0,
             grade: QualityGrade::F, // Lowest grade as default
         }
     }
@@ -214,8 +263,12 @@ pub struct GraphMetrics {
     pub pagerank: HashMap<String, f64>,
     /// Betweenness centrality by node
     pub betweenness: HashMap<String, f64>,
+    /// Closeness centrality by node
+    pub closeness: HashMap<String, f64>,
     /// Clustering coefficient by node
     pub clustering: HashMap<String, f64>,
+    /// Average clustering coefficient over all nodes
+    pub avg_clustering: f64,
     /// Community assignments (node -> community_id)
     pub communities: HashMap<String, usize>,
     /// Depth from root nodes
@@ -230,6 +283,21 @@ pub struct GraphMetrics {
     pub avg_degree: f64,
     /// Maximum depth
     pub max_depth: u32,
+    /// Power iterations used by the last PageRank run (convergence stats)
+    pub pagerank_iterations: usize,
+    /// Edge direction used for betweenness centrality
+    #[serde(default)]
+    pub direction: GraphDirection,
+}
+
+/// How dependency edges are treated for path-based centrality
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GraphDirection {
+    /// Follow edges from dependent to dependency only
+    #[default]
+    Directed,
+    /// Treat every dependency edge as bidirectional
+    Undirected,
 }
 
 impl GraphMetrics {
@@ -240,6 +308,13 @@ impl GraphMetrics {
         scores.into_iter().take(n).collect()
     }
 
+    /// Get the most central components by closeness
+    pub fn top_by_closeness(&self, n: usize) -> Vec<(&String, f64)> {
+        let mut scores: Vec<_> = self.closeness.iter().map(|(k, v)| (k, *v)).collect();
+        scores.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        scores.into_iter().take(n).collect()
+    }
+
     /// Get bottleneck components (high betweenness)
     pub fn bottlenecks(&self, threshold: f64) -> Vec<&String> {
         self.betweenness
@@ -248,6 +323,68 @@ impl GraphMetrics {
             .map(|(k, _)| k)
             .collect()
     }
+
+    /// Get the number of components in each community
+    pub fn community_sizes(&self) -> HashMap<usize, usize> {
+        let mut sizes = HashMap::new();
+        for &community in self.communities.values() {
+            *sizes.entry(community).or_insert(0) += 1;
+        }
+        sizes
+    }
+
+    /// Export per-node metrics as CSV (one row per node, sorted by name)
+    ///
+    /// Columns: node, pagerank, betweenness, closeness, clustering,
+    /// community, depth. Metrics missing for a node are left empty.
+    pub fn to_csv(&self) -> String {
+        let mut nodes: Vec<&String> = self
+            .pagerank
+            .keys()
+            .chain(self.betweenness.keys())
+            .chain(self.closeness.keys())
+            .chain(self.clustering.keys())
+            .chain(self.communities.keys())
+            .chain(self.depth_map.keys())
+            .collect();
+        nodes.sort();
+        nodes.dedup();
+
+        fn cell<T: std::fmt::Display>(value: Option<&T>) -> String {
+            value.map(|v| v.to_string()).unwrap_or_default()
+        }
+
+        let mut output =
+            String::from("node,pagerank,betweenness,closeness,clustering,community,depth\n");
+        for node in nodes {
+            output.push_str(&format!(
+                "{},{},{},{},{},{},{}\n",
+                csv_escape(node),
+                cell(self.pagerank.get(node)),
+                cell(self.betweenness.get(node)),
+                cell(self.closeness.get(node)),
+                cell(self.clustering.get(node)),
+                cell(self.communities.get(node)),
+                cell(self.depth_map.get(node)),
+            ));
+        }
+        output
+    }
+
+    /// Write per-node metrics to a CSV file
+    pub fn write_csv(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, self.to_csv())?;
+        Ok(())
+    }
+}
+
+/// Quote a CSV field if it contains a delimiter, quote, or newline
+pub(crate) fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
 }
 
 // ============================================================================
@@ -265,6 +402,18 @@ pub struct StackDiagnostics {
     metrics: GraphMetrics,
     /// Detected anomalies
     anomalies: Vec<Anomaly>,
+    /// Historical anomaly scores by anomaly id (oldest first)
+    anomaly_history: HashMap<String, Vec<f64>>,
+    /// Components or edges changed since metrics were last computed
+    structure_dirty: bool,
+    /// Components whose health must be re-derived on next computation
+    dirty_components: HashSet<String>,
+    /// Number of full graph metric computations performed
+    metrics_computations: usize,
+    /// Timestamped metric snapshots per component
+    history: ComponentHistory,
+    /// Grade thresholds used when re-deriving component health
+    health_thresholds: HealthThresholds,
 }
 
 impl StackDiagnostics {
@@ -275,12 +424,45 @@ impl StackDiagnostics {
             graph: None,
             metrics: GraphMetrics::default(),
             anomalies: Vec::new(),
+            anomaly_history: HashMap::new(),
+            structure_dirty: true,
+            dirty_components: HashSet::new(),
+            metrics_computations: 0,
+            history: ComponentHistory::default(),
+            health_thresholds: HealthThresholds::default(),
         }
     }
 
+    /// Use custom grade thresholds when re-deriving component health
+    pub fn with_health_thresholds(mut self, thresholds: HealthThresholds) -> Self {
+        self.health_thresholds = thresholds;
+        self
+    }
+
+    /// Grade thresholds used when re-deriving component health
+    pub fn health_thresholds(&self) -> &HealthThresholds {
+        &self.health_thresholds
+    }
+
     /// Add a component to the knowledge graph
     pub fn add_component(&mut self, node: ComponentNode) {
-        self.components.insert(node.name.clone(), node);
+        // Replacing an existing component keeps the graph structure intact
+        if self.components.insert(node.name.clone(), node).is_none() {
+            self.structure_dirty = true;
+        }
+    }
+
+    /// Mark a component whose metrics changed without a structural change
+    ///
+    /// The next `compute_metrics` re-derives its health but reuses cached
+    /// PageRank and betweenness.
+    pub fn mark_dirty(&mut self, component: &str) {
+        self.dirty_components.insert(component.to_string());
+    }
+
+    /// Number of full graph metric computations performed so far
+    pub fn metrics_computations(&self) -> usize {
+        self.metrics_computations
     }
 
     /// Get a component by name
@@ -301,6 +483,7 @@ impl StackDiagnostics {
     /// Set the dependency graph
     pub fn set_graph(&mut self, graph: DependencyGraph) {
         self.graph = Some(graph);
+        self.structure_dirty = true;
     }
 
     /// Get the dependency graph
@@ -308,27 +491,97 @@ impl StackDiagnostics {
         self.graph.as_ref()
     }
 
+    /// Dependency edges between components: each component maps to the
+    /// components it depends on
+    ///
+    /// Only components present in the knowledge graph appear, as keys or as
+    /// targets; dependencies on crates that were never added are dropped.
+    pub fn adjacency(&self) -> HashMap<String, Vec<String>> {
+        let mut adjacency = self.build_adjacency();
+        adjacency.retain(|name, _| self.components.contains_key(name));
+        adjacency
+    }
+
+    /// Reverse dependency edges: each component maps to its dependents
+    ///
+    /// Like [`Self::adjacency`], only components present in the knowledge
+    /// graph appear. Dependents are sorted by name.
+    pub fn reverse_adjacency(&self) -> HashMap<String, Vec<String>> {
+        let mut reverse: HashMap<String, Vec<String>> = self
+            .components
+            .keys()
+            .map(|name| (name.clone(), Vec::new()))
+            .collect();
+        for (from, targets) in self.adjacency() {
+            for to in targets {
+                if let Some(dependents) = reverse.get_mut(&to) {
+                    dependents.push(from.clone());
+                }
+            }
+        }
+        for dependents in reverse.values_mut() {
+            dependents.sort();
+        }
+        reverse
+    }
+
     /// Compute graph metrics (PageRank, Betweenness, etc.)
+    ///
+    /// Graph passes are skipped when nothing structural changed since the
+    /// last run; only health of components passed to `mark_dirty` is updated.
     pub fn compute_metrics(&mut self) -> Result<&GraphMetrics> {
+        self.compute_metrics_with(GraphDirection::default())
+    }
+
+    /// Compute graph metrics with the given edge direction for betweenness
+    pub fn compute_metrics_with(&mut self, direction: GraphDirection) -> Result<&GraphMetrics> {
+        for name in std::mem::take(&mut self.dirty_components) {
+            if let Some(component) = self.components.get_mut(&name) {
+                component.update_health_with(&self.health_thresholds);
+            }
+        }
+
         let n = self.components.len();
         if n == 0 {
             return Ok(&self.metrics);
         }
 
+        if !self.structure_dirty && self.metrics.direction == direction {
+            return Ok(&self.metrics);
+        }
+        self.structure_dirty = false;
+        self.metrics_computations += 1;
+
         self.metrics.total_nodes = n;
+        self.metrics.direction = direction;
 
         // Build adjacency from dependency graph if available
-        let adjacency = self.build_adjacency();
+        let weighted = self.build_weighted_adjacency();
+        let adjacency = unweighted(&weighted);
 
-        // Compute PageRank
-        self.compute_pagerank(&adjacency, 0.85, 100);
+        // Compute PageRank (rank flows proportionally to edge weight)
+        self.compute_pagerank(&weighted, 0.85, 100);
 
         // Compute Betweenness Centrality
-        self.compute_betweenness(&adjacency);
+        match direction {
+            GraphDirection::Directed => self.compute_betweenness(&adjacency),
+            GraphDirection::Undirected => {
+                self.compute_betweenness(&undirected_adjacency(&adjacency))
+            }
+        }
+
+        // Compute Closeness Centrality
+        self.compute_closeness(&adjacency);
 
         // Compute depth from roots
         self.compute_depth(&adjacency);
 
+        // Compute local clustering coefficients
+        self.compute_clustering(&adjacency);
+
+        // Detect communities (Louvain local moving)
+        self.compute_communities(&adjacency);
+
         // Compute graph-level metrics
         self.metrics.total_edges = adjacency.values().map(|v| v.len()).sum();
         let max_edges = n * (n.saturating_sub(1));
@@ -349,7 +602,15 @@ impl StackDiagnostics {
 
     /// Build adjacency list from dependency graph
     fn build_adjacency(&self) -> HashMap<String, Vec<String>> {
-        let mut adjacency: HashMap<String, Vec<String>> = HashMap::new();
+        unweighted(&self.build_weighted_adjacency())
+    }
+
+    /// Build weighted adjacency from dependency graph
+    ///
+    /// Edge weight is the number of `paiml_dependencies` entries pointing at
+    /// the same target, so repeated dependencies indicate tighter coupling.
+    fn build_weighted_adjacency(&self) -> HashMap<String, Vec<(String, f64)>> {
+        let mut adjacency: HashMap<String, Vec<(String, f64)>> = HashMap::new();
 
         // Initialize all nodes
         for name in self.components.keys() {
@@ -362,10 +623,11 @@ impl StackDiagnostics {
                 let from = &crate_info.name;
                 for dep in &crate_info.paiml_dependencies {
                     if self.components.contains_key(&dep.name) {
-                        adjacency
-                            .entry(from.clone())
-                            .or_default()
-                            .push(dep.name.clone());
+                        let edges = adjacency.entry(from.clone()).or_default();
+                        match edges.iter_mut().find(|(to, _)| *to == dep.name) {
+                            Some((_, weight)) => *weight += 1.0,
+                            None => edges.push((dep.name.clone(), 1.0)),
+                        }
                     }
                 }
             }
@@ -377,7 +639,7 @@ impl StackDiagnostics {
     /// Compute PageRank using power iteration
     fn compute_pagerank(
         &mut self,
-        adjacency: &HashMap<String, Vec<String>>,
+        adjacency: &HashMap<String, Vec<(String, f64)>>,
         damping: f64,
         max_iter: usize,
     ) {
@@ -387,12 +649,32 @@ impl StackDiagnostics {
         }
 
         let initial = 1.0 / n as f64;
+
+        // Warm-start from previous scores when node sets overlap; new nodes
+        // get the uniform initial value
+        let previous = &self.metrics.pagerank;
+        let warm = self.components.keys().any(|k| previous.contains_key(k));
         let mut scores: HashMap<String, f64> = self
             .components
             .keys()
-            .map(|k| (k.clone(), initial))
+            .map(|k| {
+                let start = if warm {
+                    previous.get(k).copied().unwrap_or(initial)
+                } else {
+                    initial
+                };
+                (k.clone(), start)
+            })
             .collect();
 
+        // Renormalize so the warm-started vector is a distribution
+        let total: f64 = scores.values().sum();
+        if total > 0.0 {
+            for v in scores.values_mut() {
+                *v /= total;
+            }
+        }
+
         // Find dangling nodes (nodes with no outgoing edges)
         let dangling_nodes: Vec<_> = adjacency
             .iter()
@@ -401,7 +683,9 @@ impl StackDiagnostics {
             .collect();
 
         // Power iteration
+        let mut iterations = 0;
         for _ in 0..max_iter {
+            iterations += 1;
             let mut new_scores: HashMap<String, f64> = HashMap::new();
             let teleport = (1.0 - damping) / n as f64;
 
@@ -415,13 +699,13 @@ impl StackDiagnostics {
             for node in self.components.keys() {
                 let mut incoming_score = 0.0;
 
-                // Find nodes that link to this node
+                // Find nodes that link to this node, weighting by edge share
                 for (source, targets) in adjacency {
-                    if targets.contains(node) {
-                        let out_degree = targets.len();
-                        if out_degree > 0 {
+                    if let Some((_, weight)) = targets.iter().find(|(to, _)| to == node) {
+                        let out_weight: f64 = targets.iter().map(|(_, w)| w).sum();
+                        if out_weight > 0.0 {
                             incoming_score +=
-                                scores.get(source).unwrap_or(&0.0) / out_degree as f64;
+                                scores.get(source).unwrap_or(&0.0) * weight / out_weight;
                         }
                     }
                 }
@@ -446,6 +730,7 @@ impl StackDiagnostics {
         }
 
         self.metrics.pagerank = scores;
+        self.metrics.pagerank_iterations = iterations;
     }
 
     /// Compute Betweenness Centrality using Brandes algorithm (simplified)
@@ -459,44 +744,12 @@ impl StackDiagnostics {
 
         // For each source, compute shortest paths and accumulate
         for source in &nodes {
-            // BFS from source
-            let mut dist: HashMap<String, i32> = HashMap::new();
-            let mut sigma: HashMap<String, f64> = HashMap::new();
-            let mut predecessors: HashMap<String, Vec<String>> = HashMap::new();
-
-            for n in &nodes {
-                dist.insert(n.clone(), -1);
-                sigma.insert(n.clone(), 0.0);
-                predecessors.insert(n.clone(), Vec::new());
-            }
-
-            dist.insert(source.clone(), 0);
-            sigma.insert(source.clone(), 1.0);
-
-            let mut queue = vec![source.clone()];
-            let mut order = Vec::new();
-
-            while !queue.is_empty() {
-                let v = queue.remove(0);
-                order.push(v.clone());
-
-                if let Some(neighbors) = adjacency.get(&v) {
-                    for w in neighbors {
-                        let d_v = dist[&v];
-                        let d_w = dist.get(w).copied().unwrap_or(-1);
-
-                        if d_w < 0 {
-                            dist.insert(w.clone(), d_v + 1);
-                            queue.push(w.clone());
-                        }
-
-                        if dist.get(w).copied().unwrap_or(-1) == d_v + 1 {
-                            *sigma.get_mut(w).unwrap() += sigma[&v];
-                            predecessors.get_mut(w).unwrap().push(v.clone());
-                        }
-                    }
-                }
-            }
+            let ShortestPaths {
+                sigma,
+                predecessors,
+                order,
+                ..
+            } = ShortestPaths::bfs(source, &nodes, adjacency);
 
             // Back-propagation
             let mut delta: HashMap<String, f64> = nodes.iter().map(|n| (n.clone(), 0.0)).collect();
@@ -527,49 +780,210 @@ impl StackDiagnostics {
         self.metrics.betweenness = betweenness;
     }
 
-    /// Compute depth from root nodes (nodes with no incoming edges)
-    fn compute_depth(&mut self, adjacency: &HashMap<String, Vec<String>>) {
-        let mut depth: HashMap<String, u32> = HashMap::new();
+    /// Compute closeness centrality from BFS distances
+    ///
+    /// Uses the Wasserman-Faust form `(r / (n-1)) * (r / sum_d)` where `r`
+    /// is the number of nodes reachable from the source, so partially
+    /// disconnected graphs remain comparable. Nodes reaching nothing get 0.0.
+    fn compute_closeness(&mut self, adjacency: &HashMap<String, Vec<String>>) {
         let nodes: Vec<_> = self.components.keys().cloned().collect();
+        let n = nodes.len();
+
+        let closeness = nodes
+            .iter()
+            .map(|source| {
+                let paths = ShortestPaths::bfs(source, &nodes, adjacency);
+                let reachable: Vec<f64> = paths
+                    .dist
+                    .values()
+                    .filter(|&&d| d > 0)
+                    .map(|&d| d as f64)
+                    .collect();
+
+                let r = reachable.len() as f64;
+                let total: f64 = reachable.iter().sum();
+                let score = if n > 1 && total > 0.0 {
+                    (r / (n - 1) as f64) * (r / total)
+                } else {
+                    0.0
+                };
+                (source.clone(), score)
+            })
+            .collect();
+
+        self.metrics.closeness = closeness;
+    }
+
+    /// Compute depth as the longest dependency chain from a root (a node with
+    /// no incoming edges)
+    ///
+    /// Nodes are relaxed in topological order (Kahn's algorithm), so a node
+    /// reachable along several paths takes the longest one. Nodes on or behind
+    /// a cycle never become ready; they keep the deepest chain reaching them
+    /// from acyclic predecessors, or 0.
+    fn compute_depth(&mut self, adjacency: &HashMap<String, Vec<String>>) {
+        let mut nodes: Vec<_> = self.components.keys().cloned().collect();
+        nodes.sort();
 
-        // Find incoming edges for each node
-        let mut has_incoming: HashMap<String, bool> =
-            nodes.iter().map(|n| (n.clone(), false)).collect();
+        let mut in_degree: HashMap<&str, usize> = nodes.iter().map(|n| (n.as_str(), 0)).collect();
         for targets in adjacency.values() {
             for t in targets {
-                has_incoming.insert(t.clone(), true);
+                if let Some(d) = in_degree.get_mut(t.as_str()) {
+                    *d += 1;
+                }
             }
         }
 
-        // Roots are nodes with no incoming edges
-        let roots: Vec<_> = nodes
+        let mut depth: HashMap<String, u32> = nodes.iter().map(|n| (n.clone(), 0)).collect();
+        let mut ready: std::collections::VecDeque<&str> = nodes
             .iter()
-            .filter(|n| !has_incoming.get(*n).unwrap_or(&false))
-            .cloned()
+            .map(String::as_str)
+            .filter(|n| in_degree[n] == 0)
+            .collect();
+
+        while let Some(node) = ready.pop_front() {
+            let d = depth[node];
+            for neighbor in adjacency.get(node).into_iter().flatten() {
+                let Some(remaining) = in_degree.get_mut(neighbor.as_str()) else {
+                    continue;
+                };
+                if let Some(nd) = depth.get_mut(neighbor) {
+                    *nd = (*nd).max(d + 1);
+                }
+                *remaining -= 1;
+                if *remaining == 0 {
+                    ready.push_back(neighbor.as_str());
+                }
+            }
+        }
+
+        self.metrics.depth_map = depth;
+    }
+
+    /// Compute each node's local clustering coefficient and their average
+    ///
+    /// The coefficient is the fraction of a node's neighbor pairs that are
+    /// themselves connected, with edges treated as undirected. Nodes with
+    /// fewer than two neighbors score 0.
+    fn compute_clustering(&mut self, adjacency: &HashMap<String, Vec<String>>) {
+        let undirected = undirected_adjacency(adjacency);
+        let empty = Vec::new();
+
+        let clustering: HashMap<String, f64> = self
+            .components
+            .keys()
+            .map(|node| {
+                let neighbors = undirected.get(node).unwrap_or(&empty);
+                let k = neighbors.len();
+                if k < 2 {
+                    return (node.clone(), 0.0);
+                }
+                let links = neighbors
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(i, a)| neighbors[i + 1..].iter().map(move |b| (a, b)))
+                    .filter(|(a, b)| undirected.get(*a).is_some_and(|adj| adj.contains(b)))
+                    .count();
+                (node.clone(), 2.0 * links as f64 / (k * (k - 1)) as f64)
+            })
+            .collect();
+
+        self.metrics.avg_clustering = if clustering.is_empty() {
+            0.0
+        } else {
+            clustering.values().sum::<f64>() / clustering.len() as f64
+        };
+        self.metrics.clustering = clustering;
+    }
+
+    /// Detect communities with the Louvain local-moving phase
+    ///
+    /// Dependency edges are treated as undirected. Each node repeatedly moves
+    /// to the neighboring community with the largest modularity gain until no
+    /// move improves modularity. Nodes are visited in sorted order for
+    /// determinism; isolated nodes remain singleton communities.
+    fn compute_communities(&mut self, adjacency: &HashMap<String, Vec<String>>) {
+        let undirected = undirected_adjacency(adjacency);
+        let mut nodes: Vec<_> = self.components.keys().cloned().collect();
+        nodes.sort();
+
+        let index: HashMap<&str, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (n.as_str(), i))
+            .collect();
+        let neighbors: Vec<Vec<usize>> = nodes
+            .iter()
+            .map(|n| {
+                undirected
+                    .get(n)
+                    .map(|ns| {
+                        ns.iter()
+                            .filter_map(|m| index.get(m.as_str()).copied())
+                            .collect()
+                    })
+                    .unwrap_or_default()
+            })
             .collect();
 
-        // BFS from roots
-        let mut queue: Vec<(String, u32)> = roots.into_iter().map(|r| (r, 0)).collect();
+        let degree: Vec<f64> = neighbors.iter().map(|ns| ns.len() as f64).collect();
+        let two_m: f64 = degree.iter().sum();
+        let mut community: Vec<usize> = (0..nodes.len()).collect();
+        // Sum of degrees per community
+        let mut total: Vec<f64> = degree.clone();
+
+        if two_m > 0.0 {
+            for _ in 0..100 {
+                let mut moved = false;
+
+                for i in 0..nodes.len() {
+                    let current = community[i];
+                    total[current] -= degree[i];
+
+                    // Edges from node i into each neighboring community
+                    let mut links: std::collections::BTreeMap<usize, f64> =
+                        std::collections::BTreeMap::new();
+                    for &j in &neighbors[i] {
+                        *links.entry(community[j]).or_insert(0.0) += 1.0;
+                    }
+
+                    // Modularity gain (up to a constant factor) of joining c
+                    let gain = |c: usize, k_in: f64| k_in - total[c] * degree[i] / two_m;
 
-        while let Some((node, d)) = queue.pop() {
-            if let std::collections::hash_map::Entry::Vacant(e) = depth.entry(node.clone()) {
-                e.insert(d);
-                if let Some(neighbors) = adjacency.get(&node) {
-                    for neighbor in neighbors {
-                        if !depth.contains_key(neighbor) {
-                            queue.push((neighbor.clone(), d + 1));
+                    let mut best = current;
+                    let mut best_gain = gain(current, links.get(&current).copied().unwrap_or(0.0));
+                    for (&c, &k_in) in &links {
+                        let g = gain(c, k_in);
+                        if g > best_gain + 1e-12 {
+                            best = c;
+                            best_gain = g;
                         }
                     }
+
+                    community[i] = best;
+                    total[best] += degree[i];
+                    moved |= best != current;
+                }
+
+                if !moved {
+                    break;
                 }
             }
         }
 
-        // Assign depth 0 to any unreachable nodes
-        for node in &nodes {
-            depth.entry(node.clone()).or_insert(0);
-        }
+        // Renumber community ids densely in node order
+        let mut ids: HashMap<usize, usize> = HashMap::new();
+        let communities = nodes
+            .iter()
+            .zip(&community)
+            .map(|(node, c)| {
+                let next = ids.len();
+                let id = *ids.entry(*c).or_insert(next);
+                (node.clone(), id)
+            })
+            .collect();
 
-        self.metrics.depth_map = depth;
+        self.metrics.communities = communities;
     }
 
     /// Get computed metrics
@@ -582,79 +996,285 @@ impl StackDiagnostics {
         &self.anomalies
     }
 
-    /// Add an anomaly
+    /// Add an anomaly (its score is recorded in the anomaly history)
     pub fn add_anomaly(&mut self, anomaly: Anomaly) {
+        self.record_anomaly_score(anomaly.id(), anomaly.score);
         self.anomalies.push(anomaly);
     }
 
-    /// Compute stack health summary
-    pub fn health_summary(&self) -> HealthSummary {
-        let total = self.components.len();
-        let green = self
-            .components
-            .values()
-            .filter(|c| c.health == HealthStatus::Green)
-            .count();
-        let yellow = self
+    /// Add an anomaly, merging it into any existing anomaly with the same
+    /// component and category
+    ///
+    /// The merged entry keeps the higher score (with that anomaly's
+    /// description and recommendation) and the union of both evidence lists.
+    pub fn merge_anomaly(&mut self, anomaly: Anomaly) {
+        self.record_anomaly_score(anomaly.id(), anomaly.score);
+
+        let existing = self
+            .anomalies
+            .iter_mut()
+            .find(|a| a.component == anomaly.component && a.category == anomaly.category);
+
+        let Some(existing) = existing else {
+            self.anomalies.push(anomaly);
+            return;
+        };
+
+        if anomaly.score > existing.score {
+            existing.score = anomaly.score;
+            existing.description = anomaly.description;
+            existing.recommendation = anomaly.recommendation.or(existing.recommendation.take());
+        }
+        for item in anomaly.evidence {
+            if !existing.evidence.contains(&item) {
+                existing.evidence.push(item);
+            }
+        }
+    }
+
+    /// Get all anomalies reported for a component
+    pub fn anomalies_for(&self, component: &str) -> Vec<&Anomaly> {
+        self.anomalies
+            .iter()
+            .filter(|a| a.component == component)
+            .collect()
+    }
+
+    /// Record a historical score for an anomaly id
+    pub fn record_anomaly_score(&mut self, id: impl Into<String>, score: f64) {
+        self.anomaly_history
+            .entry(id.into())
+            .or_default()
+            .push(score);
+    }
+
+    /// Get recorded scores for an anomaly id (oldest first)
+    pub fn anomaly_history(&self, id: &str) -> &[f64] {
+        self.anomaly_history
+            .get(id)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// Trend of an anomaly from its last two recorded scores
+    pub fn anomaly_trend(&self, id: &str) -> Option<AnomalyTrend> {
+        match self.anomaly_history(id) {
+            [.., previous, latest] => Some(AnomalyTrend::from_scores(*previous, *latest)),
+            _ => None,
+        }
+    }
+
+    /// Snapshot every component's current metrics into the history
+    pub fn record_snapshot(&mut self) {
+        let timestamp = chrono::Utc::now();
+        for component in self.components.values() {
+            self.history
+                .record(&component.name, timestamp, component.metrics.clone());
+        }
+    }
+
+    /// Quality regressions between the two latest snapshots
+    pub fn regressions(&self) -> Vec<Anomaly> {
+        self.history.regressions()
+    }
+
+    /// Get the component metric history
+    pub fn history(&self) -> &ComponentHistory {
+        &self.history
+    }
+
+    /// Replace the component metric history (e.g. one loaded from disk)
+    pub fn set_history(&mut self, history: ComponentHistory) {
+        self.history = history;
+    }
+
+    /// Components below a coverage target, worst-covered first
+    ///
+    /// Returns `(name, gap)` pairs where gap is `target - coverage`
+    pub fn coverage_gaps(&self, target: f64) -> Vec<(String, f64)> {
+        let mut gaps: Vec<_> = self
             .components
             .values()
-            .filter(|c| c.health == HealthStatus::Yellow)
-            .count();
-        let red = self
+            .filter(|c| c.metrics.coverage < target)
+            .map(|c| (c.name.clone(), target - c.metrics.coverage))
+            .collect();
+        gaps.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.0.cmp(&b.0))
+        });
+        gaps
+    }
+
+    /// Components with no dependencies and no dependents, sorted by name
+    ///
+    /// Isolated nodes are usually dead or misconfigured (e.g. a missing
+    /// `paiml_dependencies` entry).
+    pub fn orphans(&self) -> Vec<&ComponentNode> {
+        let adjacency = self.build_adjacency();
+        let has_dependents: HashSet<&String> = adjacency.values().flatten().collect();
+        let mut orphans: Vec<&ComponentNode> = self
             .components
             .values()
-            .filter(|c| c.health == HealthStatus::Red)
-            .count();
+            .filter(|c| adjacency[&c.name].is_empty() && !has_dependents.contains(&c.name))
+            .collect();
+        orphans.sort_by(|a, b| a.name.cmp(&b.name));
+        orphans
+    }
 
-        let avg_score = if total > 0 {
-            self.components
-                .values()
-                .map(|c| c.metrics.demo_score)
-                .sum::<f64>()
-                / total as f64
-        } else {
-            0.0
-        };
+    /// Compute stack health summary
+    pub fn health_summary(&self) -> HealthSummary {
+        let mut summary = summarize_health(self.components.values());
+        summary.orphan_count = self.orphans().len();
+        summary.total_anomalies = self.anomalies.len();
+        summary.critical_anomalies = self.anomalies.iter().filter(|a| a.is_critical()).count();
+        let mut andon_status = compute_andon_status(
+            summary.green_count,
+            summary.yellow_count,
+            summary.red_count,
+            summary.total_components,
+            summary.critical_anomalies,
+        );
+        let mut notes = Vec::new();
+
+        if summary.critical_anomalies > 0 {
+            notes.push(format!(
+                "{} critical anomal{} detected",
+                summary.critical_anomalies,
+                if summary.critical_anomalies == 1 {
+                    "y"
+                } else {
+                    "ies"
+                }
+            ));
+        }
+
+        // Dependency cycles block ordered releases: at least Yellow
+        let cycles = self.detect_cycles();
+        if !cycles.is_empty() {
+            if andon_status != AndonStatus::Red {
+                andon_status = AndonStatus::Yellow;
+            }
+            for cycle in &cycles {
+                let mut path = cycle.clone();
+                path.push(cycle[0].clone());
+                notes.push(format!("Dependency cycle: {}", path.join(" -> ")));
+            }
+        }
+
+        summary.andon_status = andon_status;
+        summary.notes = notes;
+        summary
+    }
 
-        HealthSummary {
-            total_components: total,
-            green_count: green,
-            yellow_count: yellow,
-            red_count: red,
-            unknown_count: total.saturating_sub(green + yellow + red),
-            avg_demo_score: avg_score,
-            avg_coverage: self.avg_metric(|c| c.metrics.coverage),
-            andon_status: self.compute_andon_status(green, yellow, red, total),
+    /// Health summary for each stack layer present
+    pub fn health_by_layer(&self) -> HashMap<StackLayer, HealthSummary> {
+        let mut by_layer: HashMap<StackLayer, Vec<&ComponentNode>> = HashMap::new();
+        for component in self.components.values() {
+            by_layer.entry(component.layer).or_default().push(component);
         }
+        by_layer
+            .into_iter()
+            .map(|(layer, components)| (layer, summarize_health(components)))
+            .collect()
     }
 
-    fn avg_metric<F>(&self, f: F) -> f64
-    where
-        F: Fn(&ComponentNode) -> f64,
-    {
-        let total = self.components.len();
-        if total == 0 {
-            return 0.0;
+    /// Strongly connected components (Tarjan's algorithm)
+    ///
+    /// Components outside any cycle appear as singletons. Each component is
+    /// sorted by name, and components are ordered by their first member.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<String>> {
+        let adjacency = self.build_adjacency();
+        let mut nodes: Vec<_> = self.components.keys().cloned().collect();
+        nodes.sort();
+
+        let mut tarjan = Tarjan::default();
+        for node in &nodes {
+            if !tarjan.index.contains_key(node) {
+                tarjan.visit(node, &adjacency);
+            }
+        }
+
+        let mut sccs = tarjan.components;
+        for scc in &mut sccs {
+            scc.sort();
         }
-        self.components.values().map(f).sum::<f64>() / total as f64
+        sccs.sort();
+        sccs
     }
 
-    fn compute_andon_status(
-        &self,
-        green: usize,
-        yellow: usize,
-        red: usize,
-        total: usize,
-    ) -> AndonStatus {
-        if red > 0 {
-            AndonStatus::Red
-        } else if yellow > 0 {
-            AndonStatus::Yellow
-        } else if green == total && total > 0 {
-            AndonStatus::Green
+    /// Detect dependency cycles with a DFS over the adjacency
+    ///
+    /// Each back edge yields one cycle, ordered along the dependency
+    /// direction. Self-loops are reported as single-element cycles.
+    pub fn detect_cycles(&self) -> Vec<Vec<String>> {
+        let adjacency = self.build_adjacency();
+        let mut nodes: Vec<_> = adjacency.keys().cloned().collect();
+        nodes.sort();
+
+        let mut state: HashMap<String, VisitState> = HashMap::new();
+        let mut path = Vec::new();
+        let mut cycles = Vec::new();
+
+        for node in &nodes {
+            if !state.contains_key(node) {
+                find_cycles_from(node, &adjacency, &mut state, &mut path, &mut cycles);
+            }
+        }
+
+        cycles
+    }
+}
+
+/// Summarize health counts, averages, and Andon status over components
+fn summarize_health<'a>(components: impl IntoIterator<Item = &'a ComponentNode>) -> HealthSummary {
+    let components: Vec<&ComponentNode> = components.into_iter().collect();
+    let total = components.len();
+    let count = |status: HealthStatus| components.iter().filter(|c| c.health == status).count();
+    let green = count(HealthStatus::Green);
+    let yellow = count(HealthStatus::Yellow);
+    let red = count(HealthStatus::Red);
+
+    let avg = |f: fn(&ComponentNode) -> f64| {
+        if total > 0 {
+            components.iter().map(|c| f(c)).sum::<f64>() / total as f64
         } else {
-            AndonStatus::Unknown
+            0.0
         }
+    };
+
+    HealthSummary {
+        total_components: total,
+        green_count: green,
+        yellow_count: yellow,
+        red_count: red,
+        unknown_count: total.saturating_sub(green + yellow + red),
+        avg_demo_score: avg(|c| c.metrics.demo_score),
+        avg_coverage: avg(|c| c.metrics.coverage),
+        andon_status: compute_andon_status(green, yellow, red, total, 0),
+        notes: Vec::new(),
+        orphan_count: 0,
+        critical_anomalies: 0,
+        total_anomalies: 0,
+    }
+}
+
+/// Overall Andon status; any critical anomaly stops the line
+fn compute_andon_status(
+    green: usize,
+    yellow: usize,
+    red: usize,
+    total: usize,
+    critical_anomalies: usize,
+) -> AndonStatus {
+    if red > 0 || critical_anomalies > 0 {
+        AndonStatus::Red
+    } else if yellow > 0 {
+        AndonStatus::Yellow
+    } else if green == total && total > 0 {
+        AndonStatus::Green
+    } else {
+        AndonStatus::Unknown
     }
 }
 
@@ -664,29 +1284,344 @@ impl Default for StackDiagnostics {
     }
 }
 
-// ============================================================================
-// Health Summary
-// ============================================================================
+/// Tarjan's strongly connected components state
+#[derive(Debug, Default)]
+struct Tarjan {
+    /// Next DFS index
+    next_index: usize,
+    /// DFS discovery index per node
+    index: HashMap<String, usize>,
+    /// Lowest index reachable per node
+    lowlink: HashMap<String, usize>,
+    /// Nodes of the components under construction
+    stack: Vec<String>,
+    /// Completed components
+    components: Vec<Vec<String>>,
+}
 
-/// Summary of stack health for dashboard
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct HealthSummary {
-    /// Total components in stack
-    pub total_components: usize,
-    /// Components with green status
-    pub green_count: usize,
-    /// Components with yellow status
-    pub yellow_count: usize,
-    /// Components with red status
-    pub red_count: usize,
-    /// Components with unknown status
-    pub unknown_count: usize,
-    /// Average demo score
-    pub avg_demo_score: f64,
-    /// Average test coverage
+impl Tarjan {
+    /// Recursive strongconnect step
+    fn visit(&mut self, node: &str, adjacency: &HashMap<String, Vec<String>>) {
+        self.index.insert(node.to_string(), self.next_index);
+        self.lowlink.insert(node.to_string(), self.next_index);
+        self.next_index += 1;
+        self.stack.push(node.to_string());
+
+        for target in adjacency.get(node).into_iter().flatten() {
+            if !self.index.contains_key(target) {
+                self.visit(target, adjacency);
+                let low = self.lowlink[node].min(self.lowlink[target]);
+                self.lowlink.insert(node.to_string(), low);
+            } else if self.stack.contains(target) {
+                let low = self.lowlink[node].min(self.index[target]);
+                self.lowlink.insert(node.to_string(), low);
+            }
+        }
+
+        // Root of a component: pop it off the stack
+        if self.lowlink[node] == self.index[node] {
+            let mut component = Vec::new();
+            while let Some(member) = self.stack.pop() {
+                let done = member == node;
+                component.push(member);
+                if done {
+                    break;
+                }
+            }
+            self.components.push(component);
+        }
+    }
+}
+
+/// DFS visitation state for cycle detection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VisitState {
+    /// On the current DFS path
+    InProgress,
+    /// Fully explored
+    Done,
+}
+
+/// Recursive DFS step recording a cycle for every back edge
+fn find_cycles_from(
+    node: &str,
+    adjacency: &HashMap<String, Vec<String>>,
+    state: &mut HashMap<String, VisitState>,
+    path: &mut Vec<String>,
+    cycles: &mut Vec<Vec<String>>,
+) {
+    state.insert(node.to_string(), VisitState::InProgress);
+    path.push(node.to_string());
+
+    let mut targets = adjacency.get(node).cloned().unwrap_or_default();
+    targets.sort();
+    targets.dedup();
+
+    for target in &targets {
+        match state.get(target) {
+            Some(VisitState::InProgress) => {
+                if let Some(start) = path.iter().position(|n| n == target) {
+                    cycles.push(path[start..].to_vec());
+                }
+            }
+            Some(VisitState::Done) => {}
+            None => find_cycles_from(target, adjacency, state, path, cycles),
+        }
+    }
+
+    path.pop();
+    state.insert(node.to_string(), VisitState::Done);
+}
+
+/// Single-source shortest paths from a BFS (unweighted Brandes step)
+struct ShortestPaths {
+    /// Hop distance from the source (-1 if unreachable)
+    dist: HashMap<String, i32>,
+    /// Number of shortest paths from the source
+    sigma: HashMap<String, f64>,
+    /// Predecessors on shortest paths
+    predecessors: HashMap<String, Vec<String>>,
+    /// Nodes in non-decreasing distance order
+    order: Vec<String>,
+}
+
+impl ShortestPaths {
+    /// Breadth-first search from `source` over `adjacency`
+    fn bfs(source: &str, nodes: &[String], adjacency: &HashMap<String, Vec<String>>) -> Self {
+        let mut dist: HashMap<String, i32> = HashMap::new();
+        let mut sigma: HashMap<String, f64> = HashMap::new();
+        let mut predecessors: HashMap<String, Vec<String>> = HashMap::new();
+
+        for n in nodes {
+            dist.insert(n.clone(), -1);
+            sigma.insert(n.clone(), 0.0);
+            predecessors.insert(n.clone(), Vec::new());
+        }
+
+        dist.insert(source.to_string(), 0);
+        sigma.insert(source.to_string(), 1.0);
+
+        let mut queue = std::collections::VecDeque::from([source.to_string()]);
+        let mut order = Vec::new();
+
+        while let Some(v) = queue.pop_front() {
+            order.push(v.clone());
+
+            if let Some(neighbors) = adjacency.get(&v) {
+                for w in neighbors {
+                    let d_v = dist[&v];
+                    let d_w = dist.get(w).copied().unwrap_or(-1);
+
+                    if d_w < 0 {
+                        dist.insert(w.clone(), d_v + 1);
+                        queue.push_back(w.clone());
+                    }
+
+                    if dist.get(w).copied().unwrap_or(-1) == d_v + 1 {
+                        let sigma_v = sigma[&v];
+                        *sigma.entry(w.clone()).or_insert(0.0) += sigma_v;
+                        predecessors.entry(w.clone()).or_default().push(v.clone());
+                    }
+                }
+            }
+        }
+
+        Self {
+            dist,
+            sigma,
+            predecessors,
+            order,
+        }
+    }
+}
+
+/// Drop edge weights from a weighted adjacency
+fn unweighted(weighted: &HashMap<String, Vec<(String, f64)>>) -> HashMap<String, Vec<String>> {
+    weighted
+        .iter()
+        .map(|(node, edges)| {
+            (
+                node.clone(),
+                edges.iter().map(|(to, _)| to.clone()).collect(),
+            )
+        })
+        .collect()
+}
+
+/// Treat directed dependency edges as undirected (deduplicated, no self-loops)
+fn undirected_adjacency(adjacency: &HashMap<String, Vec<String>>) -> HashMap<String, Vec<String>> {
+    let mut undirected: HashMap<String, Vec<String>> = adjacency
+        .keys()
+        .map(|node| (node.clone(), Vec::new()))
+        .collect();
+
+    for (from, targets) in adjacency {
+        for to in targets {
+            if from == to {
+                continue;
+            }
+            for (a, b) in [(from, to), (to, from)] {
+                let neighbors = undirected.entry(a.clone()).or_default();
+                if !neighbors.contains(b) {
+                    neighbors.push(b.clone());
+                }
+            }
+        }
+    }
+
+    undirected
+}
+
+// ============================================================================
+// Component History (Regression Detection)
+// ============================================================================
+
+/// A component's metrics at a point in time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricsSnapshot {
+    /// When the snapshot was taken
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    /// Metrics at that time
+    pub metrics: ComponentMetrics,
+}
+
+/// Timestamped metric snapshots keyed by component name
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComponentHistory {
+    /// Snapshots per component (oldest first)
+    snapshots: HashMap<String, Vec<MetricsSnapshot>>,
+    /// Demo score drop that counts as a regression
+    regression_delta: f64,
+}
+
+impl Default for ComponentHistory {
+    fn default() -> Self {
+        Self {
+            snapshots: HashMap::new(),
+            regression_delta: 5.0,
+        }
+    }
+}
+
+impl ComponentHistory {
+    /// Set the demo score drop that counts as a regression
+    pub fn with_regression_delta(mut self, delta: f64) -> Self {
+        self.regression_delta = delta;
+        self
+    }
+
+    /// Append a snapshot for a component
+    pub fn record(
+        &mut self,
+        component: &str,
+        timestamp: chrono::DateTime<chrono::Utc>,
+        metrics: ComponentMetrics,
+    ) {
+        self.snapshots
+            .entry(component.to_string())
+            .or_default()
+            .push(MetricsSnapshot { timestamp, metrics });
+    }
+
+    /// Get snapshots for a component (oldest first)
+    pub fn snapshots(&self, component: &str) -> &[MetricsSnapshot] {
+        self.snapshots
+            .get(component)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// Emit a `QualityRegression` anomaly for each component whose demo
+    /// score dropped by more than the regression delta between its two
+    /// latest snapshots, largest drop first
+    pub fn regressions(&self) -> Vec<Anomaly> {
+        let mut regressions: Vec<Anomaly> = self
+            .snapshots
+            .iter()
+            .filter_map(|(name, snapshots)| match snapshots.as_slice() {
+                [.., previous, latest] => {
+                    let drop = previous.metrics.demo_score - latest.metrics.demo_score;
+                    (drop > self.regression_delta).then(|| {
+                        Anomaly::new(
+                            name.clone(),
+                            (drop / 100.0).clamp(0.0, 1.0),
+                            AnomalyCategory::QualityRegression,
+                            format!("Demo score dropped {:.1} points since last snapshot", drop),
+                        )
+                        .with_evidence(format!(
+                            "Demo score: {:.1} -> {:.1}",
+                            previous.metrics.demo_score, latest.metrics.demo_score
+                        ))
+                        .with_evidence(format!(
+                            "Previous snapshot: {}",
+                            previous.timestamp.format("%Y-%m-%d %H:%M:%S")
+                        ))
+                        .with_recommendation("Review changes since the previous snapshot")
+                    })
+                }
+                _ => None,
+            })
+            .collect();
+
+        regressions.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        regressions
+    }
+
+    /// Save the history as JSON
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let data = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, data)?;
+        Ok(())
+    }
+
+    /// Load history from JSON (empty history if the file does not exist)
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let data = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&data)?)
+    }
+}
+
+// ============================================================================
+// Health Summary
+// ============================================================================
+
+/// Summary of stack health for dashboard
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthSummary {
+    /// Total components in stack
+    pub total_components: usize,
+    /// Components with green status
+    pub green_count: usize,
+    /// Components with yellow status
+    pub yellow_count: usize,
+    /// Components with red status
+    pub red_count: usize,
+    /// Components with unknown status
+    pub unknown_count: usize,
+    /// Average demo score
+    pub avg_demo_score: f64,
+    /// Average test coverage
     pub avg_coverage: f64,
     /// Overall Andon status
     pub andon_status: AndonStatus,
+    /// Explanations for status adjustments (e.g., dependency cycles)
+    pub notes: Vec<String>,
+    /// Components with no dependencies and no dependents
+    pub orphan_count: usize,
+    /// Detected anomalies with a critical score
+    pub critical_anomalies: usize,
+    /// All detected anomalies
+    pub total_anomalies: usize,
 }
 
 impl HealthSummary {
@@ -800,6 +1735,48 @@ impl Anomaly {
     pub fn is_critical(&self) -> bool {
         self.score > 0.8
     }
+
+    /// Stable identifier for tracking this anomaly across runs
+    pub fn id(&self) -> String {
+        format!("{}:{:?}", self.component, self.category)
+    }
+}
+
+/// Direction of an anomaly's score between recorded runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AnomalyTrend {
+    /// Score increased - anomaly getting worse
+    Worsening,
+    /// Score decreased - anomaly getting better
+    Improving,
+    /// Score unchanged
+    Stable,
+}
+
+impl AnomalyTrend {
+    /// Minimum score change considered a trend
+    const TOLERANCE: f64 = 0.01;
+
+    /// Classify the change from a previous to the latest score
+    pub fn from_scores(previous: f64, latest: f64) -> Self {
+        let delta = latest - previous;
+        if delta > Self::TOLERANCE {
+            Self::Worsening
+        } else if delta < -Self::TOLERANCE {
+            Self::Improving
+        } else {
+            Self::Stable
+        }
+    }
+
+    /// Get trend arrow for display
+    pub fn arrow(&self) -> &'static str {
+        match self {
+            Self::Worsening => "↑",
+            Self::Improving => "↓",
+            Self::Stable => "→",
+        }
+    }
 }
 
 /// Categories of anomalies
@@ -832,197 +1809,767 @@ impl std::fmt::Display for AnomalyCategory {
     }
 }
 
+// ============================================================================
+// SARIF Export
+// ============================================================================
+
+/// SARIF version emitted by [`anomalies_to_sarif`]
+pub const SARIF_VERSION: &str = "2.1.0";
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Export anomalies as a SARIF 2.1.0 log (e.g. for GitHub code scanning)
+///
+/// One result per anomaly: the rule id is the category, the level is
+/// `error` for critical anomalies and `warning` otherwise, the message is
+/// the description, and the recommendation (if any) becomes a fix
+/// description. Components are reported as logical locations.
+pub fn anomalies_to_sarif(anomalies: &[Anomaly]) -> serde_json::Value {
+    use serde_json::json;
+
+    let mut categories: Vec<AnomalyCategory> = Vec::new();
+    for anomaly in anomalies {
+        if !categories.contains(&anomaly.category) {
+            categories.push(anomaly.category);
+        }
+    }
+
+    let rules: Vec<_> = categories
+        .iter()
+        .map(|category| {
+            json!({
+                "id": format!("{:?}", category),
+                "shortDescription": { "text": category.to_string() },
+            })
+        })
+        .collect();
+
+    let results: Vec<_> = anomalies
+        .iter()
+        .map(|anomaly| {
+            let rule_index = categories
+                .iter()
+                .position(|c| *c == anomaly.category)
+                .unwrap_or_default();
+            let mut result = json!({
+                "ruleId": format!("{:?}", anomaly.category),
+                "ruleIndex": rule_index,
+                "level": if anomaly.is_critical() { "error" } else { "warning" },
+                "message": { "text": anomaly.description },
+                "locations": [{
+                    "logicalLocations": [{
+                        "name": anomaly.component,
+                        "kind": "module",
+                    }],
+                }],
+                "properties": {
+                    "score": anomaly.score,
+                    "evidence": anomaly.evidence,
+                },
+            });
+            if let Some(recommendation) = &anomaly.recommendation {
+                result["fixes"] = json!([{ "description": { "text": recommendation } }]);
+            }
+            result
+        })
+        .collect();
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": SARIF_VERSION,
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "batuta",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": "https://github.com/paiml/Batuta",
+                    "rules": rules,
+                },
+            },
+            "results": results,
+        }],
+    })
+}
+
 // ============================================================================
 // Dashboard Renderer
 // ============================================================================
 
+/// Default dashboard width in terminal columns
+pub const DEFAULT_DASHBOARD_WIDTH: usize = 75;
+
+/// Narrowest width the dashboard is laid out at
+pub const MIN_DASHBOARD_WIDTH: usize = 32;
+
 /// Render diagnostics as ASCII dashboard
 pub fn render_dashboard(diagnostics: &StackDiagnostics) -> String {
-    let mut output = String::new();
+    render_dashboard_width(diagnostics, DEFAULT_DASHBOARD_WIDTH)
+}
+
+/// Render the dashboard at a given terminal width
+///
+/// Rows are truncated or padded to `width` columns (at least
+/// [`MIN_DASHBOARD_WIDTH`]) so the box stays aligned.
+pub fn render_dashboard_width(diagnostics: &StackDiagnostics, width: usize) -> String {
+    render_dashboard_styled(diagnostics, DashboardStyle::EMOJI, width)
+}
+
+/// Render the dashboard with ANSI-colored Andon status lines
+///
+/// Uses `HealthStatus::symbol()` glyphs instead of emoji. Pass
+/// `use_color = false` (e.g. when `NO_COLOR` is set) for plain output.
+pub fn render_dashboard_colored(diagnostics: &StackDiagnostics, use_color: bool) -> String {
+    render_dashboard_styled(
+        diagnostics,
+        DashboardStyle {
+            emoji: false,
+            color: use_color,
+        },
+        DEFAULT_DASHBOARD_WIDTH,
+    )
+}
+
+/// Glyph and color choices for dashboard rendering
+#[derive(Debug, Clone, Copy)]
+struct DashboardStyle {
+    /// Use emoji icons instead of `HealthStatus::symbol()` glyphs
+    emoji: bool,
+    /// Wrap status text in ANSI color escape codes
+    color: bool,
+}
+
+impl DashboardStyle {
+    /// Plain emoji output (the classic dashboard)
+    const EMOJI: Self = Self {
+        emoji: true,
+        color: false,
+    };
+
+    /// Status glyph for a health level
+    fn glyph(&self, status: HealthStatus) -> &'static str {
+        if self.emoji {
+            status.icon()
+        } else {
+            status.symbol()
+        }
+    }
+
+    /// Wrap text in the ANSI color for a health level
+    fn paint(&self, status: HealthStatus, text: &str) -> String {
+        if !self.color {
+            return text.to_string();
+        }
+        let code = match status {
+            HealthStatus::Green => "\x1b[32m",
+            HealthStatus::Yellow => "\x1b[33m",
+            HealthStatus::Red => "\x1b[31m",
+            HealthStatus::Unknown => "\x1b[90m",
+        };
+        format!("{}{}\x1b[0m", code, text)
+    }
+}
+
+impl From<AndonStatus> for HealthStatus {
+    fn from(status: AndonStatus) -> Self {
+        match status {
+            AndonStatus::Green => Self::Green,
+            AndonStatus::Yellow => Self::Yellow,
+            AndonStatus::Red => Self::Red,
+            AndonStatus::Unknown => Self::Unknown,
+        }
+    }
+}
+
+fn render_dashboard_styled(
+    diagnostics: &StackDiagnostics,
+    style: DashboardStyle,
+    width: usize,
+) -> String {
     let summary = diagnostics.health_summary();
+    let mut dashboard = DashboardBox::new(style, width);
+    let percent = |count: usize| {
+        if summary.total_components > 0 {
+            (count as f64 / summary.total_components as f64) * 100.0
+        } else {
+            0.0
+        }
+    };
 
     // Header
-    output
-        .push_str("┌─────────────────────────────────────────────────────────────────────────┐\n");
-    output
-        .push_str("│                  SOVEREIGN AI STACK HEALTH DASHBOARD                    │\n");
-    output.push_str(&format!(
-        "│                  Timestamp: {:40} │\n",
+    dashboard.rule("┌", "┐");
+    dashboard.centered("SOVEREIGN AI STACK HEALTH DASHBOARD");
+    dashboard.centered(&format!(
+        "Timestamp: {}",
         chrono::Utc::now().format("%Y-%m-%d %H:%M:%S")
     ));
-    output
-        .push_str("├─────────────────────────────────────────────────────────────────────────┤\n");
+    dashboard.rule("├", "┤");
 
     // Andon Status
-    output
-        .push_str("│                                                                         │\n");
-    output.push_str(&format!(
-        "│  ANDON STATUS: {} {:55}│\n",
-        summary.andon_status, ""
-    ));
-    output
-        .push_str("│                                                                         │\n");
+    dashboard.blank();
+    let andon = HealthStatus::from(summary.andon_status);
+    let andon_text = format!("{} {}", style.glyph(andon), summary.andon_status.message());
+    dashboard.row(&[("ANDON STATUS: ", None), (andon_text.as_str(), Some(andon))]);
+    dashboard.blank();
 
     // Stack Summary
-    output.push_str("│  ═══════════════════════════════════════════════════════════════════   │\n");
-    output
-        .push_str("│  STACK SUMMARY                                                          │\n");
-    output.push_str("│  ═══════════════════════════════════════════════════════════════════   │\n");
-    output
-        .push_str("│                                                                         │\n");
-    output.push_str(&format!(
-        "│  Total Components:    {:3}                                              │\n",
+    dashboard.heading("STACK SUMMARY");
+    dashboard.text(&format!(
+        "Total Components:    {:3}",
         summary.total_components
     ));
-    output.push_str(&format!(
-        "│  Healthy:             {:3} ({:.0}%)                                         │\n",
-        summary.green_count,
-        summary.health_percentage()
-    ));
-    output.push_str(&format!(
-        "│  Warnings:            {:3} ({:.0}%)                                         │\n",
-        summary.yellow_count,
-        if summary.total_components > 0 {
-            (summary.yellow_count as f64 / summary.total_components as f64) * 100.0
-        } else {
-            0.0
-        }
-    ));
-    output.push_str(&format!(
-        "│  Critical:            {:3} ({:.0}%)                                         │\n",
-        summary.red_count,
-        if summary.total_components > 0 {
-            (summary.red_count as f64 / summary.total_components as f64) * 100.0
-        } else {
-            0.0
-        }
-    ));
-    output.push_str(&format!(
-        "│  Average Demo Score:  {:.1}/100                                          │\n",
+    for (status, label, count) in [
+        (
+            HealthStatus::Green,
+            "Healthy:            ",
+            summary.green_count,
+        ),
+        (
+            HealthStatus::Yellow,
+            "Warnings:           ",
+            summary.yellow_count,
+        ),
+        (HealthStatus::Red, "Critical:           ", summary.red_count),
+    ] {
+        let value = format!(" {:3} ({:.0}%)", count, percent(count));
+        dashboard.row(&[(label, Some(status)), (value.as_str(), None)]);
+    }
+    dashboard.text(&format!(
+        "Average Demo Score:  {:.1}/100",
         summary.avg_demo_score
     ));
-    output.push_str(&format!(
-        "│  Average Coverage:    {:.1}%                                             │\n",
+    dashboard.text(&format!(
+        "Average Coverage:    {:.1}%",
         summary.avg_coverage
     ));
-    output
-        .push_str("│                                                                         │\n");
+    let coupled = diagnostics
+        .strongly_connected_components()
+        .iter()
+        .filter(|scc| scc.len() > 1)
+        .count();
+    if coupled > 0 {
+        dashboard.text(&format!(
+            "Coupled Subsystems:  {:3} (SCCs with size > 1)",
+            coupled
+        ));
+    }
+    dashboard.blank();
 
     // Anomalies
     let anomalies = diagnostics.anomalies();
     if !anomalies.is_empty() {
-        output.push_str(
-            "│  ═══════════════════════════════════════════════════════════════════   │\n",
-        );
-        output.push_str(
-            "│  ANOMALIES DETECTED                                                     │\n",
-        );
-        output.push_str(
-            "│  ═══════════════════════════════════════════════════════════════════   │\n",
-        );
-        output.push_str(
-            "│                                                                         │\n",
-        );
+        dashboard.heading("ANOMALIES DETECTED");
 
         for anomaly in anomalies.iter().take(5) {
-            let icon = if anomaly.is_critical() {
-                "🔴"
+            let status = if anomaly.is_critical() {
+                HealthStatus::Red
             } else {
-                "⚠️"
+                HealthStatus::Yellow
             };
-            output.push_str(&format!(
-                "│  {}  {}: {}                               │\n",
-                icon, anomaly.component, anomaly.description
-            ));
+            let icon = match (style.emoji, status) {
+                (true, HealthStatus::Yellow) => "⚠️",
+                _ => style.glyph(status),
+            };
+            let trend = diagnostics
+                .anomaly_trend(&anomaly.id())
+                .map(|t| t.arrow())
+                .unwrap_or(" ");
+            let line = format!(
+                "{} {} {}: {}",
+                icon, trend, anomaly.component, anomaly.description
+            );
+            dashboard.row(&[(line.as_str(), Some(status))]);
         }
-        output.push_str(
-            "│                                                                         │\n",
-        );
+        dashboard.blank();
     }
 
-    output
-        .push_str("└─────────────────────────────────────────────────────────────────────────┘\n");
-
-    output
+    dashboard.rule("└", "┘");
+    dashboard.output
 }
 
-// ============================================================================
-// Isolation Forest (ML Anomaly Detection)
-// ============================================================================
-
-/// Isolation Forest for anomaly detection
-/// Implements a simplified version of the algorithm from Liu et al. (2008)
-#[derive(Debug)]
-pub struct IsolationForest {
-    /// Number of trees in the forest
-    n_trees: usize,
-    /// Subsample size for each tree
-    sample_size: usize,
-    /// Random seed for reproducibility
-    seed: u64,
-    /// Trained isolation trees
-    trees: Vec<IsolationTree>,
-    /// Feature names for interpretation
-    feature_names: Vec<String>,
+/// Box-drawn dashboard builder that fits every row to a fixed width
+///
+/// Rows are `│  content │`; content too wide for the box is cut with an
+/// ellipsis and the rest padded so the right border lines up. Styling is applied after
+/// fitting, so ANSI escapes never count toward the width.
+struct DashboardBox {
+    style: DashboardStyle,
+    width: usize,
+    output: String,
 }
 
-impl IsolationForest {
-    /// Create a new Isolation Forest
-    pub fn new(n_trees: usize, sample_size: usize, seed: u64) -> Self {
+impl DashboardBox {
+    fn new(style: DashboardStyle, width: usize) -> Self {
         Self {
-            n_trees,
-            sample_size,
-            seed,
-            trees: Vec::new(),
-            feature_names: Vec::new(),
+            style,
+            width: width.max(MIN_DASHBOARD_WIDTH),
+            output: String::new(),
         }
     }
 
-    /// Default forest configuration
-    pub fn default_forest() -> Self {
-        Self::new(100, 256, 42)
+    /// Columns available to row content
+    fn content_width(&self) -> usize {
+        self.width - 5
     }
 
-    /// Set feature names for interpretability
-    pub fn with_feature_names(mut self, names: Vec<String>) -> Self {
-        self.feature_names = names;
-        self
+    /// Horizontal border with the given corner/junction glyphs
+    fn rule(&mut self, left: &str, right: &str) {
+        self.output.push_str(left);
+        self.output.push_str(&"─".repeat(self.width - 2));
+        self.output.push_str(right);
+        self.output.push('\n');
     }
 
-    /// Fit the forest on data points
-    /// Each row is a data point, each column is a feature
-    pub fn fit(&mut self, data: &[Vec<f64>]) {
-        if data.is_empty() {
-            return;
-        }
+    fn blank(&mut self) {
+        self.row(&[]);
+    }
 
-        let mut rng = SimpleRng::seed_from_u64(self.seed);
-        let n_samples = data.len();
-        let max_depth = (self.sample_size as f64).log2().ceil() as usize;
+    fn text(&mut self, text: &str) {
+        self.row(&[(text, None)]);
+    }
 
-        self.trees.clear();
+    fn centered(&mut self, text: &str) {
+        let pad = self.content_width().saturating_sub(display_width(text)) / 2;
+        self.text(&format!("{}{}", " ".repeat(pad), text));
+    }
 
-        for _ in 0..self.n_trees {
-            // Sample data points
-            let sample: Vec<Vec<f64>> = (0..self.sample_size.min(n_samples))
-                .map(|_| {
-                    let idx = rng.gen_range(0..n_samples);
-                    data[idx].clone()
-                })
-                .collect();
+    /// Section title between double rules, followed by a blank row
+    fn heading(&mut self, title: &str) {
+        let rule = "═".repeat(self.content_width().saturating_sub(3));
+        self.text(&rule);
+        self.text(title);
+        self.text(&rule);
+        self.blank();
+    }
 
-            // Build tree
-            let tree = IsolationTree::build(&sample, max_depth, &mut rng);
-            self.trees.push(tree);
+    /// Row of text segments, each optionally painted with a health color
+    fn row(&mut self, segments: &[(&str, Option<HealthStatus>)]) {
+        let mut remaining = self.content_width();
+        let mut line = String::from("│  ");
+        for &(text, status) in segments {
+            // Earlier segments filled the row; even an ellipsis won't fit
+            if remaining == 0 {
+                break;
+            }
+            let fitted = if display_width(text) > remaining {
+                format!("{}…", truncate_to_width(text, remaining.saturating_sub(1)))
+            } else {
+                text.to_string()
+            };
+            remaining = remaining.saturating_sub(display_width(&fitted));
+            match status {
+                Some(status) => line.push_str(&self.style.paint(status, &fitted)),
+                None => line.push_str(&fitted),
+            }
         }
+        line.push_str(&" ".repeat(remaining));
+        line.push_str(" │\n");
+        self.output.push_str(&line);
     }
+}
 
-    /// Compute anomaly scores for data points
-    /// Returns scores in [0, 1] where higher = more anomalous
-    pub fn score(&self, data: &[Vec<f64>]) -> Vec<f64> {
-        if self.trees.is_empty() || data.is_empty() {
-            return vec![0.0; data.len()];
-        }
+/// Longest prefix of `text` that fits in `max` terminal columns
+fn truncate_to_width(text: &str, max: usize) -> String {
+    let mut width = 0;
+    text.chars()
+        .take_while(|&c| {
+            width += char_width(c);
+            width <= max
+        })
+        .collect()
+}
+
+/// Terminal columns `text` occupies (ANSI escapes not handled)
+fn display_width(text: &str) -> usize {
+    text.chars().map(char_width).sum()
+}
+
+/// Terminal columns of one character
+///
+/// Emoji and East Asian wide characters take two columns; the emoji
+/// variation selector widens the preceding symbol (e.g. `⚠️`) to two.
+fn char_width(c: char) -> usize {
+    match c as u32 {
+        0x200B..=0x200D | 0xFE00..=0xFE0E => 0,
+        0xFE0F => 1,
+        0x1100..=0x115F
+        | 0x26AA..=0x26AB
+        | 0x2705
+        | 0x274C
+        | 0x2B50
+        | 0x2E80..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFF00..=0xFF60
+        | 0x1F300..=0x1FAFF => 2,
+        _ => 1,
+    }
+}
+
+/// Render the dashboard grouped by stack layer
+///
+/// Each layer header carries its own Andon rollup, so a single red
+/// component turns its layer red.
+pub fn render_dashboard_by_layer(diagnostics: &StackDiagnostics) -> String {
+    let by_layer = diagnostics.health_by_layer();
+    let mut dashboard = DashboardBox::new(DashboardStyle::EMOJI, DEFAULT_DASHBOARD_WIDTH);
+
+    dashboard.rule("┌", "┐");
+    dashboard.centered("SOVEREIGN AI STACK HEALTH BY LAYER");
+    dashboard.rule("├", "┤");
+
+    let layer_order = [
+        StackLayer::Compute,
+        StackLayer::Ml,
+        StackLayer::Training,
+        StackLayer::Transpilers,
+        StackLayer::Orchestration,
+        StackLayer::Quality,
+        StackLayer::DataMlops,
+        StackLayer::Presentation,
+    ];
+
+    for layer in layer_order {
+        let Some(summary) = by_layer.get(&layer) else {
+            continue;
+        };
+        dashboard.text(&format!(
+            "{} {} ({}/{} healthy)",
+            summary.andon_status,
+            layer.display_name(),
+            summary.green_count,
+            summary.total_components
+        ));
+
+        let mut components: Vec<_> = diagnostics
+            .components()
+            .filter(|c| c.layer == layer)
+            .collect();
+        components.sort_by(|a, b| a.name.cmp(&b.name));
+        for component in components {
+            dashboard.text(&format!(
+                "    {} {} v{}",
+                component.health, component.name, component.version
+            ));
+        }
+        dashboard.blank();
+    }
+
+    dashboard.rule("└", "┘");
+    dashboard.output
+}
+
+impl StackDiagnostics {
+    /// Render a drill-down panel for one component (`batuta stack inspect`)
+    ///
+    /// Shows metrics, health, graph metrics once computed, direct
+    /// dependencies and dependents, and the component's anomalies.
+    /// Returns `None` for unknown components.
+    pub fn component_report(&self, name: &str) -> Option<String> {
+        let component = self.components.get(name)?;
+        let style = DashboardStyle::EMOJI;
+        let mut panel = DashboardBox::new(style, DEFAULT_DASHBOARD_WIDTH);
+        let adjacency = self.build_adjacency();
+
+        panel.rule("┌", "┐");
+        panel.text(&format!(
+            "COMPONENT: {} v{}",
+            component.name, component.version
+        ));
+        panel.text(&format!(
+            "Layer: {}    Health: {} {:?}",
+            component.layer.display_name(),
+            component.health,
+            component.health
+        ));
+        panel.rule("├", "┤");
+
+        let m = &component.metrics;
+        panel.heading("METRICS");
+        panel.text(&format!("Demo Score:          {:.1}/100", m.demo_score));
+        panel.text(&format!("Coverage:            {:.1}%", m.coverage));
+        panel.text(&format!("Mutation Score:      {:.1}%", m.mutation_score));
+        panel.text(&format!("Avg Complexity:      {:.1}", m.complexity_avg));
+        panel.text(&format!("SATD Count:          {}", m.satd_count));
+        panel.text(&format!("Dead Code:           {:.1}%", m.dead_code_pct));
+        panel.text(&format!("Build Time:          {:.1}s", m.build_time_sec));
+        panel.text(&format!("Grade:               {}", m.grade));
+        panel.blank();
+
+        let graph_metrics = [
+            (
+                "PageRank:           ",
+                self.metrics.pagerank.get(name).copied(),
+            ),
+            (
+                "Betweenness:        ",
+                self.metrics.betweenness.get(name).copied(),
+            ),
+        ];
+        let depth = self.metrics.depth_map.get(name);
+        if graph_metrics.iter().any(|(_, v)| v.is_some()) || depth.is_some() {
+            panel.heading("GRAPH");
+            for (label, value) in graph_metrics {
+                if let Some(value) = value {
+                    panel.text(&format!("{} {:.4}", label, value));
+                }
+            }
+            if let Some(depth) = depth {
+                panel.text(&format!("Depth:               {}", depth));
+            }
+            panel.blank();
+        }
+
+        let mut dependencies: Vec<&str> = adjacency
+            .get(name)
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+            .collect();
+        dependencies.sort_unstable();
+        let mut dependents: Vec<&str> = adjacency
+            .iter()
+            .filter(|(_, deps)| deps.iter().any(|d| d == name))
+            .map(|(from, _)| from.as_str())
+            .collect();
+        dependents.sort_unstable();
+        for (title, names) in [
+            (
+                format!("DEPENDENCIES ({})", dependencies.len()),
+                dependencies,
+            ),
+            (format!("DEPENDENTS ({})", dependents.len()), dependents),
+        ] {
+            panel.heading(&title);
+            if names.is_empty() {
+                panel.text("(none)");
+            }
+            for dep in names {
+                let health = self
+                    .components
+                    .get(dep)
+                    .map_or(HealthStatus::Unknown, |c| c.health);
+                panel.text(&format!("{} {}", health, dep));
+            }
+            panel.blank();
+        }
+
+        let anomalies: Vec<&Anomaly> = self
+            .anomalies
+            .iter()
+            .filter(|a| a.component == name)
+            .collect();
+        panel.heading(&format!("ANOMALIES ({})", anomalies.len()));
+        if anomalies.is_empty() {
+            panel.text("(none)");
+        }
+        for anomaly in anomalies {
+            let status = if anomaly.is_critical() {
+                HealthStatus::Red
+            } else {
+                HealthStatus::Yellow
+            };
+            panel.text(&format!(
+                "{} [{}] {:.2} {}",
+                style.glyph(status),
+                anomaly.category,
+                anomaly.score,
+                anomaly.description
+            ));
+            if let Some(recommendation) = &anomaly.recommendation {
+                panel.text(&format!("   → {}", recommendation));
+            }
+        }
+        panel.blank();
+
+        panel.rule("└", "┘");
+        Some(panel.output)
+    }
+}
+
+/// Render the dependency graph as a Graphviz `digraph`
+///
+/// Nodes are colored by health status and labeled with version and, once
+/// metrics are computed, PageRank score.
+pub fn render_dot(diagnostics: &StackDiagnostics) -> String {
+    let mut output = String::from("digraph stack {\n");
+    output.push_str("    rankdir=LR;\n");
+    output.push_str("    node [shape=box, style=filled];\n");
+
+    let mut components: Vec<_> = diagnostics.components().collect();
+    components.sort_by(|a, b| a.name.cmp(&b.name));
+
+    for node in &components {
+        let color = match node.health {
+            HealthStatus::Green => "green",
+            HealthStatus::Yellow => "yellow",
+            HealthStatus::Red => "red",
+            HealthStatus::Unknown => "gray",
+        };
+        let mut label = format!("{}\\nv{}", node.name, node.version);
+        if let Some(rank) = diagnostics.metrics().pagerank.get(&node.name) {
+            label.push_str(&format!("\\nPR: {:.3}", rank));
+        }
+        output.push_str(&format!(
+            "    \"{}\" [label=\"{}\", fillcolor={}];\n",
+            dot_escape(&node.name),
+            dot_escape(&label),
+            color
+        ));
+    }
+
+    let adjacency = diagnostics.build_adjacency();
+    let mut edges: Vec<(&String, &String)> = adjacency
+        .iter()
+        .filter(|(from, _)| diagnostics.get_component(from).is_some())
+        .flat_map(|(from, targets)| targets.iter().map(move |to| (from, to)))
+        .collect();
+    edges.sort();
+    edges.dedup();
+
+    for (from, to) in edges {
+        output.push_str(&format!(
+            "    \"{}\" -> \"{}\";\n",
+            dot_escape(from),
+            dot_escape(to)
+        ));
+    }
+
+    output.push_str("}\n");
+    output
+}
+
+/// Escape double quotes for DOT string literals
+fn dot_escape(s: &str) -> String {
+    s.replace('"', "\\\"")
+}
+
+// ============================================================================
+// Isolation Forest (ML Anomaly Detection)
+// ============================================================================
+
+/// Isolation Forest for anomaly detection
+/// Implements a simplified version of the algorithm from Liu et al. (2008)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IsolationForest {
+    /// Number of trees in the forest
+    n_trees: usize,
+    /// Subsample size for each tree
+    sample_size: usize,
+    /// Random seed for reproducibility
+    seed: u64,
+    /// Trained isolation trees
+    trees: Vec<IsolationTree>,
+    /// Feature names for interpretation
+    feature_names: Vec<String>,
+    /// Per-feature means of the training data (baseline for attribution)
+    #[serde(default)]
+    feature_means: Vec<f64>,
+    /// Per-feature standard deviations of the training data
+    #[serde(default)]
+    feature_stds: Vec<f64>,
+}
+
+impl IsolationForest {
+    /// Create a new Isolation Forest
+    pub fn new(n_trees: usize, sample_size: usize, seed: u64) -> Self {
+        Self {
+            n_trees,
+            sample_size,
+            seed,
+            trees: Vec::new(),
+            feature_names: Vec::new(),
+            feature_means: Vec::new(),
+            feature_stds: Vec::new(),
+        }
+    }
+
+    /// Default forest configuration
+    pub fn default_forest() -> Self {
+        Self::new(100, 256, 42)
+    }
+
+    /// Forest sized to the data: fewer, smaller trees for tiny stacks and
+    /// more trees for large, high-variance ones
+    pub fn auto_sized(n_samples: usize) -> Self {
+        // Subsample size: the whole dataset up to the paper's 256 ceiling
+        let sample_size = n_samples.clamp(8, 256);
+        // Trees grow logarithmically with data size, within [25, 200]
+        let n_trees = ((n_samples.max(2) as f64).log2() * 10.0).round() as usize;
+        Self::new(n_trees.clamp(25, 200), sample_size, 42)
+    }
+
+    /// Set feature names for interpretability
+    pub fn with_feature_names(mut self, names: Vec<String>) -> Self {
+        self.feature_names = names;
+        self
+    }
+
+    /// Save the trained forest as JSON so it can be reused without retraining
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let data = serde_json::to_string(self)?;
+        std::fs::write(path, data)?;
+        Ok(())
+    }
+
+    /// Load a previously saved forest
+    pub fn load(path: &Path) -> Result<Self> {
+        let data = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&data)?)
+    }
+
+    /// Fit the forest on data points
+    /// Each row is a data point, each column is a feature
+    pub fn fit(&mut self, data: &[Vec<f64>]) {
+        if data.is_empty() {
+            return;
+        }
+
+        self.feature_means = feature_means(data);
+        self.feature_stds = feature_stds(data, &self.feature_means);
+        let max_depth = (self.sample_size as f64).log2().ceil() as usize;
+
+        // Each tree has its own seed, so parallel and sequential builds agree
+        #[cfg(feature = "native")]
+        {
+            use rayon::prelude::*;
+            self.trees = (0..self.n_trees)
+                .into_par_iter()
+                .map(|i| self.build_tree(data, i, max_depth))
+                .collect();
+        }
+        #[cfg(not(feature = "native"))]
+        {
+            self.trees = (0..self.n_trees)
+                .map(|i| self.build_tree(data, i, max_depth))
+                .collect();
+        }
+    }
+
+    /// Build the `index`-th tree on a subsample drawn with its own RNG
+    fn build_tree(&self, data: &[Vec<f64>], index: usize, max_depth: usize) -> IsolationTree {
+        let mut rng = SimpleRng::seed_from_u64(tree_seed(self.seed, index));
+        let n_samples = data.len();
+
+        // Sample data points
+        let sample: Vec<Vec<f64>> = (0..self.sample_size.min(n_samples))
+            .map(|_| {
+                let idx = rng.gen_range(0..n_samples);
+                data[idx].clone()
+            })
+            .collect();
+
+        IsolationTree::build(&sample, max_depth, &mut rng)
+    }
+
+    /// Compute anomaly scores for data points
+    /// Returns scores in [0, 1] where higher = more anomalous
+    pub fn score(&self, data: &[Vec<f64>]) -> Vec<f64> {
+        if self.trees.is_empty() || data.is_empty() {
+            return vec![0.0; data.len()];
+        }
 
         let n = self.sample_size as f64;
         let c_n = average_path_length(n);
@@ -1043,6 +2590,45 @@ impl IsolationForest {
             .collect()
     }
 
+    /// Attribute a point's anomaly score to its features
+    /// Each feature is moved to its training mean; the drop in score is its
+    /// contribution. Returns `(feature name, contribution)` sorted descending.
+    pub fn feature_contributions(&self, point: &[f64]) -> Vec<(String, f64)> {
+        self.ranked_contributions(point)
+            .into_iter()
+            .map(|(j, delta)| (self.feature_name(j), delta))
+            .collect()
+    }
+
+    /// Contribution per feature index, sorted descending
+    fn ranked_contributions(&self, point: &[f64]) -> Vec<(usize, f64)> {
+        let base = self.score(&[point.to_vec()])[0];
+
+        let mut contributions: Vec<(usize, f64)> = self
+            .feature_means
+            .iter()
+            .take(point.len())
+            .enumerate()
+            .map(|(j, &mean)| {
+                let mut perturbed = point.to_vec();
+                perturbed[j] = mean;
+                (j, base - self.score(&[perturbed])[0])
+            })
+            .collect();
+
+        contributions.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        contributions
+    }
+
+    /// Name of feature `index`, falling back to the component feature layout
+    fn feature_name(&self, index: usize) -> String {
+        self.feature_names
+            .get(index)
+            .cloned()
+            .or_else(|| COMPONENT_FEATURE_NAMES.get(index).map(|s| s.to_string()))
+            .unwrap_or_else(|| format!("feature_{}", index))
+    }
+
     /// Predict anomalies with threshold
     pub fn predict(&self, data: &[Vec<f64>], threshold: f64) -> Vec<bool> {
         self.score(data)
@@ -1059,41 +2645,80 @@ impl IsolationForest {
         }
 
         // Extract feature vectors
-        let data: Vec<Vec<f64>> = components
-            .iter()
-            .map(|c| {
-                vec![
-                    c.metrics.demo_score,
-                    c.metrics.coverage,
-                    c.metrics.mutation_score,
-                    c.metrics.complexity_avg,
-                    c.metrics.satd_count as f64,
-                    c.metrics.dead_code_pct,
-                ]
-            })
-            .collect();
+        let data: Vec<Vec<f64>> = components.iter().map(|c| component_features(c)).collect();
+
+        // Identical feature vectors make every path length equal, so scores
+        // carry no signal and would flag everything above a low threshold
+        if is_zero_variance(&data) {
+            tracing::debug!(
+                "Skipping anomaly detection: all {} components have identical metrics",
+                data.len()
+            );
+            return Vec::new();
+        }
 
-        let scores = self.score(&data);
-        let mut anomalies = Vec::new();
+        self.flag_components(&components, &data, threshold, "Isolation score")
+    }
 
-        for (i, (component, score)) in components.iter().zip(scores.iter()).enumerate() {
-            if *score > threshold {
-                let category = self.categorize_anomaly(&data[i]);
-                let description = self.describe_anomaly(&data[i], &category);
+    /// Detect anomalies by scoring the current components against a forest
+    /// fit on `baseline` (e.g. last quarter's healthy metrics)
+    ///
+    /// Unlike [`Self::detect_anomalies`], a stack that has degraded uniformly
+    /// still stands out, since its components are never part of the training
+    /// data. The baseline is fit with this forest's configuration; `self` is
+    /// left untouched. Rows use the [`component_features`] layout.
+    pub fn detect_anomalies_against(
+        &self,
+        baseline: &[Vec<f64>],
+        diagnostics: &StackDiagnostics,
+        threshold: f64,
+    ) -> Vec<Anomaly> {
+        let components: Vec<_> = diagnostics.components().collect();
+        if components.is_empty() {
+            return Vec::new();
+        }
 
-                let mut anomaly =
-                    Anomaly::new(component.name.clone(), *score, category, description);
+        // A constant baseline builds single-leaf trees that score every point
+        // the same, so there is nothing to compare against
+        if is_zero_variance(baseline) {
+            tracing::debug!(
+                "Skipping baseline anomaly detection: {} baseline rows have no variance",
+                baseline.len()
+            );
+            return Vec::new();
+        }
 
-                // Add evidence
-                anomaly = anomaly
-                    .with_evidence(format!("Isolation score: {:.3}", score))
-                    .with_evidence(format!("Demo score: {:.1}", component.metrics.demo_score))
-                    .with_evidence(format!("Coverage: {:.1}%", component.metrics.coverage));
+        let mut reference = Self::new(self.n_trees, self.sample_size, self.seed)
+            .with_feature_names(self.feature_names.clone());
+        reference.fit(baseline);
+
+        let data: Vec<Vec<f64>> = components.iter().map(|c| component_features(c)).collect();
+        reference.flag_components(&components, &data, threshold, "Baseline isolation score")
+    }
 
-                // Add recommendation
-                let rec = self.recommend_action(&category, &data[i]);
-                anomaly = anomaly.with_recommendation(rec);
+    /// Build anomalies for components scoring above `threshold`, highest first
+    fn flag_components(
+        &self,
+        components: &[&ComponentNode],
+        data: &[Vec<f64>],
+        threshold: f64,
+        score_label: &str,
+    ) -> Vec<Anomaly> {
+        let scores = self.score(data);
+        let mut anomalies = Vec::new();
 
+        for (i, (component, score)) in components.iter().zip(scores.iter()).enumerate() {
+            if *score > threshold {
+                let category = self.categorize_anomaly(&data[i]);
+                let mut anomaly = build_anomaly(component, *score, &data[i], category, score_label);
+                for (j, contribution) in self.ranked_contributions(&data[i]).into_iter().take(2) {
+                    anomaly = anomaly.with_evidence(format!(
+                        "Top contributor: {} = {:.1} ({:+.3})",
+                        self.feature_name(j),
+                        data[i][j],
+                        contribution
+                    ));
+                }
                 anomalies.push(anomaly);
             }
         }
@@ -1108,84 +2733,203 @@ impl IsolationForest {
     }
 
     /// Categorize the anomaly based on which features are most deviant
+    ///
+    /// A fitted forest compares against its training distribution; an
+    /// unfitted one falls back to fixed health thresholds.
     fn categorize_anomaly(&self, features: &[f64]) -> AnomalyCategory {
-        // features: [demo_score, coverage, mutation_score, complexity_avg, satd_count, dead_code_pct]
-        if features.len() < 6 {
-            return AnomalyCategory::Other;
-        }
+        categorize_by_deviation(features, &self.feature_means, &self.feature_stds)
+    }
+}
 
-        let demo_score = features[0];
-        let coverage = features[1];
-        let complexity = features[3];
-        let dead_code = features[5];
+/// Derive a per-tree seed from the forest seed (SplitMix64 finalizer)
+fn tree_seed(seed: u64, index: usize) -> u64 {
+    let mut z = seed.wrapping_add((index as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
 
-        if demo_score < 70.0 {
-            AnomalyCategory::QualityRegression
-        } else if coverage < 50.0 {
-            AnomalyCategory::CoverageDrop
-        } else if complexity > 15.0 {
-            AnomalyCategory::ComplexityIncrease
-        } else if dead_code > 10.0 {
-            AnomalyCategory::DependencyRisk
-        } else {
-            AnomalyCategory::Other
-        }
+/// Names of the features produced by [`component_features`]
+const COMPONENT_FEATURE_NAMES: &[&str] = &[
+    "demo_score",
+    "coverage",
+    "mutation_score",
+    "complexity_avg",
+    "satd_count",
+    "dead_code_pct",
+    "build_time_sec",
+];
+
+/// Feature vector for a component: `[demo_score, coverage, mutation_score,
+/// complexity_avg, satd_count, dead_code_pct, build_time_sec]`
+fn component_features(c: &ComponentNode) -> Vec<f64> {
+    vec![
+        c.metrics.demo_score,
+        c.metrics.coverage,
+        c.metrics.mutation_score,
+        c.metrics.complexity_avg,
+        c.metrics.satd_count as f64,
+        c.metrics.dead_code_pct,
+        c.metrics.build_time_sec,
+    ]
+}
+
+/// Build an anomaly with description, evidence, and recommendation
+fn build_anomaly(
+    component: &ComponentNode,
+    score: f64,
+    features: &[f64],
+    category: AnomalyCategory,
+    score_label: &str,
+) -> Anomaly {
+    let description = describe_anomaly(features, &category);
+
+    Anomaly::new(component.name.clone(), score, category, description)
+        .with_evidence(format!("{}: {:.3}", score_label, score))
+        .with_evidence(format!("Demo score: {:.1}", component.metrics.demo_score))
+        .with_evidence(format!("Coverage: {:.1}%", component.metrics.coverage))
+        .with_recommendation(recommend_action(&category, features))
+}
+
+/// Clean build time (seconds) above which a build is considered a spike
+const BUILD_TIME_SPIKE_SEC: f64 = 300.0;
+
+/// Feature index, adverse direction (+1 = higher is worse), and the category
+/// it maps to, in [`component_features`] layout
+const CATEGORY_FEATURES: &[(usize, f64, AnomalyCategory)] = &[
+    (0, -1.0, AnomalyCategory::QualityRegression),
+    (1, -1.0, AnomalyCategory::CoverageDrop),
+    (3, 1.0, AnomalyCategory::ComplexityIncrease),
+    (5, 1.0, AnomalyCategory::DependencyRisk),
+    (6, 1.0, AnomalyCategory::BuildTimeSpike),
+];
+
+/// Standard deviations in the adverse direction needed to count as deviant
+const MIN_CATEGORY_DEVIATION: f64 = 1.0;
+
+/// Categorize by the feature with the largest normalized deviation from
+/// `means`, so a dominant build time spike is not masked by a mildly low
+/// coverage. Falls back to [`categorize_features`] without statistics or
+/// when no feature deviates notably.
+fn categorize_by_deviation(features: &[f64], means: &[f64], stds: &[f64]) -> AnomalyCategory {
+    let dominant = CATEGORY_FEATURES
+        .iter()
+        .filter_map(|&(j, direction, category)| {
+            let (x, mean, std) = (*features.get(j)?, *means.get(j)?, *stds.get(j)?);
+            // A near-constant training feature would turn tiny changes into
+            // huge deviations, so the spread is floored at 5% of the mean
+            let spread = std.max(mean.abs() * 0.05).max(f64::EPSILON);
+            Some((direction * (x - mean) / spread, category))
+        })
+        .max_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+    match dominant {
+        Some((deviation, category)) if deviation >= MIN_CATEGORY_DEVIATION => category,
+        _ => categorize_features(features),
     }
+}
 
-    /// Generate human-readable description
-    fn describe_anomaly(&self, features: &[f64], category: &AnomalyCategory) -> String {
-        match category {
-            AnomalyCategory::QualityRegression => {
-                format!(
-                    "Quality score {:.1} is significantly below healthy threshold",
-                    features[0]
-                )
-            }
-            AnomalyCategory::CoverageDrop => {
-                format!("Test coverage {:.1}% is dangerously low", features[1])
-            }
-            AnomalyCategory::ComplexityIncrease => {
+/// Categorize the anomaly based on which features are most deviant
+fn categorize_features(features: &[f64]) -> AnomalyCategory {
+    // features: [demo_score, coverage, mutation_score, complexity_avg, satd_count,
+    //            dead_code_pct, build_time_sec]
+    if features.len() < 6 {
+        return AnomalyCategory::Other;
+    }
+
+    let demo_score = features[0];
+    let coverage = features[1];
+    let complexity = features[3];
+    let dead_code = features[5];
+    // Six-feature callers predate build time tracking
+    let build_time = features.get(6).copied().unwrap_or(0.0);
+
+    if demo_score < 70.0 {
+        AnomalyCategory::QualityRegression
+    } else if coverage < 50.0 {
+        AnomalyCategory::CoverageDrop
+    } else if complexity > 15.0 {
+        AnomalyCategory::ComplexityIncrease
+    } else if dead_code > 10.0 {
+        AnomalyCategory::DependencyRisk
+    } else if build_time > BUILD_TIME_SPIKE_SEC {
+        AnomalyCategory::BuildTimeSpike
+    } else {
+        AnomalyCategory::Other
+    }
+}
+
+/// Generate human-readable description
+fn describe_anomaly(features: &[f64], category: &AnomalyCategory) -> String {
+    match category {
+        AnomalyCategory::QualityRegression => {
+            format!(
+                "Quality score {:.1} is significantly below healthy threshold",
+                features[0]
+            )
+        }
+        AnomalyCategory::CoverageDrop => {
+            format!("Test coverage {:.1}% is dangerously low", features[1])
+        }
+        AnomalyCategory::ComplexityIncrease => {
+            format!(
+                "Average complexity {:.1} indicates maintainability risk",
+                features[3]
+            )
+        }
+        AnomalyCategory::DependencyRisk => {
+            format!(
+                "Dead code {:.1}% suggests technical debt accumulation",
+                features[5]
+            )
+        }
+        AnomalyCategory::BuildTimeSpike => {
+            let build_time = features.get(6).copied().unwrap_or(0.0);
+            if build_time > BUILD_TIME_SPIKE_SEC {
                 format!(
-                    "Average complexity {:.1} indicates maintainability risk",
-                    features[3]
+                    "Build time {:.0}s exceeds the {:.0}s budget",
+                    build_time, BUILD_TIME_SPIKE_SEC
                 )
-            }
-            AnomalyCategory::DependencyRisk => {
+            } else {
                 format!(
-                    "Dead code {:.1}% suggests technical debt accumulation",
-                    features[5]
+                    "Build time {:.0}s is far above the stack's usual build time",
+                    build_time
                 )
             }
-            _ => "Unusual metric combination detected".to_string(),
         }
+        _ => "Unusual metric combination detected".to_string(),
     }
+}
 
-    /// Generate actionable recommendation
-    fn recommend_action(&self, category: &AnomalyCategory, features: &[f64]) -> String {
-        match category {
-            AnomalyCategory::QualityRegression => {
-                if features[1] < 80.0 {
-                    "Add tests to improve coverage above 80%".to_string()
-                } else {
-                    "Review recent changes for quality regressions".to_string()
-                }
-            }
-            AnomalyCategory::CoverageDrop => {
-                "Run `cargo tarpaulin` and add tests for uncovered paths".to_string()
-            }
-            AnomalyCategory::ComplexityIncrease => {
-                "Consider refactoring complex functions (>10 cyclomatic complexity)".to_string()
-            }
-            AnomalyCategory::DependencyRisk => {
-                "Run `cargo udeps` to identify and remove dead code".to_string()
+/// Generate actionable recommendation
+fn recommend_action(category: &AnomalyCategory, features: &[f64]) -> String {
+    match category {
+        AnomalyCategory::QualityRegression => {
+            if features[1] < 80.0 {
+                "Add tests to improve coverage above 80%".to_string()
+            } else {
+                "Review recent changes for quality regressions".to_string()
             }
-            _ => "Review component metrics for unusual patterns".to_string(),
         }
+        AnomalyCategory::CoverageDrop => {
+            "Run `cargo tarpaulin` and add tests for uncovered paths".to_string()
+        }
+        AnomalyCategory::ComplexityIncrease => {
+            "Consider refactoring complex functions (>10 cyclomatic complexity)".to_string()
+        }
+        AnomalyCategory::DependencyRisk => {
+            "Run `cargo udeps` to identify and remove dead code".to_string()
+        }
+        AnomalyCategory::BuildTimeSpike => {
+            "Profile with `cargo build --timings` and trim heavy dependencies or generics"
+                .to_string()
+        }
+        _ => "Review component metrics for unusual patterns".to_string(),
     }
 }
 
 /// A single isolation tree node
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 enum IsolationTree {
     /// Internal node with split
     Internal {
@@ -1278,6 +3022,45 @@ impl IsolationTree {
     }
 }
 
+/// Column means of the data (empty if there are no rows)
+fn feature_means(data: &[Vec<f64>]) -> Vec<f64> {
+    let n_features = data.first().map_or(0, Vec::len);
+    (0..n_features)
+        .map(|j| data.iter().filter_map(|row| row.get(j)).sum::<f64>() / data.len() as f64)
+        .collect()
+}
+
+/// Column population standard deviations around `means`
+fn feature_stds(data: &[Vec<f64>], means: &[f64]) -> Vec<f64> {
+    means
+        .iter()
+        .enumerate()
+        .map(|(j, mean)| {
+            let variance = data
+                .iter()
+                .filter_map(|row| row.get(j))
+                .map(|x| (x - mean).powi(2))
+                .sum::<f64>()
+                / data.len() as f64;
+            variance.sqrt()
+        })
+        .collect()
+}
+
+/// Check whether every feature vector is identical (no variance to isolate)
+fn is_zero_variance(data: &[Vec<f64>]) -> bool {
+    match data.split_first() {
+        Some((first, rest)) => rest.iter().all(|row| {
+            row.len() == first.len()
+                && row
+                    .iter()
+                    .zip(first)
+                    .all(|(a, b)| (a - b).abs() < f64::EPSILON)
+        }),
+        None => true,
+    }
+}
+
 /// Average path length of unsuccessful search in BST
 fn average_path_length(n: f64) -> f64 {
     if n <= 1.0 {
@@ -1287,50 +3070,351 @@ fn average_path_length(n: f64) -> f64 {
 }
 
 // ============================================================================
-// Time Series Forecasting (Error Prediction)
+// Local Outlier Factor (Density-Based Anomaly Detection)
 // ============================================================================
 
-/// Simple exponential smoothing for time series forecasting
+/// Local Outlier Factor for anomaly detection
+/// Implements the density-based algorithm from Breunig et al. (2000)
 #[derive(Debug, Clone)]
-pub struct ErrorForecaster {
-    /// Smoothing parameter alpha (0-1)
-    alpha: f64,
-    /// Historical observations
-    history: Vec<f64>,
-    /// Current smoothed value
-    level: f64,
+pub struct LocalOutlierFactor {
+    /// Number of neighbors used to estimate local density
+    k: usize,
+    /// LOF score per fitted data point
+    scores: Vec<f64>,
 }
 
-impl ErrorForecaster {
-    /// Create a new error forecaster
-    pub fn new(alpha: f64) -> Self {
+impl LocalOutlierFactor {
+    /// Create a new LOF detector using `k` nearest neighbors
+    pub fn new(k: usize) -> Self {
         Self {
-            alpha: alpha.clamp(0.0, 1.0),
-            history: Vec::new(),
-            level: 0.0,
+            k,
+            scores: Vec::new(),
         }
     }
 
-    /// Default forecaster with alpha=0.3
-    pub fn default_forecaster() -> Self {
-        Self::new(0.3)
+    /// Default detector configuration
+    pub fn default_lof() -> Self {
+        Self::new(5)
     }
 
-    /// Add an observation
-    pub fn observe(&mut self, value: f64) {
-        if self.history.is_empty() {
-            self.level = value;
-        } else {
-            // Exponential smoothing: L_t = alpha * Y_t + (1 - alpha) * L_{t-1}
-            self.level = self.alpha * value + (1.0 - self.alpha) * self.level;
+    /// Fit on data points and compute their LOF scores
+    /// Each row is a data point, each column is a feature
+    pub fn fit(&mut self, data: &[Vec<f64>]) {
+        let n = data.len();
+        // k can never exceed the number of other points
+        let k = self.k.min(n.saturating_sub(1));
+        if k == 0 {
+            self.scores = vec![1.0; n];
+            return;
+        }
+
+        // k nearest neighbors (excluding self) and their distances
+        let neighbors: Vec<Vec<(usize, f64)>> = (0..n)
+            .map(|i| {
+                let mut dists: Vec<(usize, f64)> = (0..n)
+                    .filter(|&j| j != i)
+                    .map(|j| (j, euclidean_distance(&data[i], &data[j])))
+                    .collect();
+                dists.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+                dists.truncate(k);
+                dists
+            })
+            .collect();
+
+        let k_distance: Vec<f64> = neighbors
+            .iter()
+            .map(|nn| nn.last().map(|&(_, d)| d).unwrap_or(0.0))
+            .collect();
+
+        // Local reachability density: inverse mean reachability distance
+        let lrd: Vec<f64> = neighbors
+            .iter()
+            .map(|nn| {
+                let mean_reach =
+                    nn.iter().map(|&(o, d)| k_distance[o].max(d)).sum::<f64>() / nn.len() as f64;
+                1.0 / mean_reach.max(1e-10)
+            })
+            .collect();
+
+        self.scores = neighbors
+            .iter()
+            .enumerate()
+            .map(|(i, nn)| nn.iter().map(|&(o, _)| lrd[o] / lrd[i]).sum::<f64>() / nn.len() as f64)
+            .collect();
+    }
+
+    /// LOF score per fitted point (~1.0 = inlier, >1.0 = sparser than neighbors)
+    pub fn score(&self) -> Vec<f64> {
+        self.scores.clone()
+    }
+
+    /// Detect anomalies in component metrics
+    /// Scores are mapped to [0, 1) via `1 - 1/LOF` so thresholds match IsolationForest
+    pub fn detect_anomalies(&self, diagnostics: &StackDiagnostics, threshold: f64) -> Vec<Anomaly> {
+        let components: Vec<_> = diagnostics.components().collect();
+        if components.is_empty() {
+            return Vec::new();
+        }
+
+        let data: Vec<Vec<f64>> = components.iter().map(|c| component_features(c)).collect();
+
+        // Identical feature vectors have uniform density
+        if is_zero_variance(&data) {
+            tracing::debug!(
+                "Skipping anomaly detection: all {} components have identical metrics",
+                data.len()
+            );
+            return Vec::new();
+        }
+
+        // LOF is transductive: score the components themselves
+        let mut lof = self.clone();
+        lof.fit(&data);
+        let means = feature_means(&data);
+        let stds = feature_stds(&data, &means);
+
+        let mut anomalies: Vec<Anomaly> = components
+            .iter()
+            .zip(lof.scores.iter())
+            .enumerate()
+            .filter_map(|(i, (component, &lof_score))| {
+                let score = (1.0 - 1.0 / lof_score).max(0.0);
+                (score > threshold).then(|| {
+                    let category = categorize_by_deviation(&data[i], &means, &stds);
+                    build_anomaly(component, score, &data[i], category, "LOF score")
+                })
+            })
+            .collect();
+
+        // Sort by score descending
+        anomalies.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        anomalies
+    }
+}
+
+/// Euclidean distance between two feature vectors
+fn euclidean_distance(a: &[f64], b: &[f64]) -> f64 {
+    a.iter()
+        .zip(b)
+        .map(|(x, y)| (x - y).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+// ============================================================================
+// Time Series Forecasting (Error Prediction)
+// ============================================================================
+
+/// Exponential smoothing for time series forecasting
+///
+/// Uses simple exponential smoothing by default, Holt's linear trend
+/// method (double exponential smoothing) when a trend parameter is set, or
+/// additive Holt-Winters (triple exponential smoothing) with a season length.
+#[derive(Debug, Clone)]
+pub struct ErrorForecaster {
+    /// Smoothing parameter alpha (0-1)
+    alpha: f64,
+    /// Trend smoothing parameter beta (0-1), `None` for simple smoothing
+    beta: Option<f64>,
+    /// Trend damping factor phi (0-1], 1 for an undamped trend
+    phi: f64,
+    /// Historical observations
+    history: Vec<f64>,
+    /// Current smoothed value
+    level: f64,
+    /// Current smoothed trend (always 0 without trend mode)
+    trend: f64,
+    /// Additive seasonal component (Holt-Winters mode)
+    seasonality: Option<Seasonality>,
+}
+
+/// Additive seasonal state for Holt-Winters forecasting
+#[derive(Debug, Clone)]
+struct Seasonality {
+    /// Seasonal smoothing parameter gamma (0-1)
+    gamma: f64,
+    /// Observations per season (e.g. 7 for daily data with weekly cycles)
+    season_length: usize,
+    /// Seasonal offset by position within the season; empty until two full
+    /// seasons have been observed
+    offsets: Vec<f64>,
+}
+
+/// State and in-sample one-step errors from a Holt-Winters pass
+struct SeasonalFit {
+    level: f64,
+    trend: f64,
+    offsets: Vec<f64>,
+    errors: Vec<f64>,
+}
+
+impl ErrorForecaster {
+    /// Create a new error forecaster
+    pub fn new(alpha: f64) -> Self {
+        Self {
+            alpha: alpha.clamp(0.0, 1.0),
+            beta: None,
+            phi: 1.0,
+            history: Vec::new(),
+            level: 0.0,
+            trend: 0.0,
+            seasonality: None,
+        }
+    }
+
+    /// Create a forecaster using Holt's linear trend method
+    pub fn with_trend(alpha: f64, beta: f64) -> Self {
+        Self {
+            beta: Some(beta.clamp(0.0, 1.0)),
+            ..Self::new(alpha)
+        }
+    }
+
+    /// Create a forecaster using Holt's damped trend method
+    ///
+    /// The trend decays by `phi` each step, so an h-step forecast is
+    /// `level + (phi + phi^2 + ... + phi^h) * trend` and flattens over long
+    /// horizons. `phi` is clamped to `(0, 1]`; `phi = 1` is [`Self::with_trend`].
+    pub fn with_damped_trend(alpha: f64, beta: f64, phi: f64) -> Self {
+        Self {
+            phi: phi.clamp(f64::EPSILON, 1.0),
+            ..Self::with_trend(alpha, beta)
+        }
+    }
+
+    /// Create a forecaster using additive Holt-Winters seasonality
+    ///
+    /// Seasonal terms engage once two full seasons (`2 * season_length`
+    /// observations) are available; until then this behaves like
+    /// [`Self::with_trend`]. `season_length` is at least 2.
+    pub fn with_seasonality(alpha: f64, beta: f64, gamma: f64, season_length: usize) -> Self {
+        Self {
+            seasonality: Some(Seasonality {
+                gamma: gamma.clamp(0.0, 1.0),
+                season_length: season_length.max(2),
+                offsets: Vec::new(),
+            }),
+            ..Self::with_trend(alpha, beta)
+        }
+    }
+
+    /// Default forecaster with alpha=0.3
+    pub fn default_forecaster() -> Self {
+        Self::new(0.3)
+    }
+
+    /// Add an observation
+    pub fn observe(&mut self, value: f64) {
+        if self.history.is_empty() {
+            self.level = value;
+            self.trend = 0.0;
+        } else {
+            let (level, trend) =
+                self.smooth(self.level, self.trend, value, self.history.len() == 1);
+            self.level = level;
+            self.trend = trend;
         }
         self.history.push(value);
+
+        if let Some(fit) = self.seasonal_fit() {
+            self.level = fit.level;
+            self.trend = fit.trend;
+            if let Some(seasonality) = &mut self.seasonality {
+                seasonality.offsets = fit.offsets;
+            }
+        }
+    }
+
+    /// Holt-Winters pass over the full history, once two seasons are available
+    ///
+    /// Initial level and trend come from the means of the first two seasons,
+    /// and initial offsets from each position's average deviation from its
+    /// season mean.
+    fn seasonal_fit(&self) -> Option<SeasonalFit> {
+        let seasonality = self.seasonality.as_ref()?;
+        let m = seasonality.season_length;
+        if self.history.len() < 2 * m {
+            return None;
+        }
+
+        let mean = |season: &[f64]| season.iter().sum::<f64>() / m as f64;
+        let (first, second) = (&self.history[..m], &self.history[m..2 * m]);
+        let (first_mean, second_mean) = (mean(first), mean(second));
+        let mut offsets: Vec<f64> = (0..m)
+            .map(|i| ((first[i] - first_mean) + (second[i] - second_mean)) / 2.0)
+            .collect();
+        let mut trend = (second_mean - first_mean) / m as f64;
+        // Level just before the first observation (first_mean sits mid-season)
+        let mut level = first_mean - (m as f64 + 1.0) / 2.0 * trend;
+
+        let beta = self.beta.unwrap_or(0.0);
+        let gamma = seasonality.gamma;
+        let mut errors = Vec::with_capacity(self.history.len());
+        for (i, &value) in self.history.iter().enumerate() {
+            let offset = offsets[i % m];
+            errors.push(value - (level + self.phi * trend + offset));
+            // L_t = alpha * (Y_t - S_{t-m}) + (1 - alpha) * (L_{t-1} + phi * T_{t-1})
+            let new_level =
+                self.alpha * (value - offset) + (1.0 - self.alpha) * (level + self.phi * trend);
+            // T_t = beta * (L_t - L_{t-1}) + (1 - beta) * phi * T_{t-1}
+            trend = beta * (new_level - level) + (1.0 - beta) * self.phi * trend;
+            level = new_level;
+            // S_t = gamma * (Y_t - L_t) + (1 - gamma) * S_{t-m}
+            offsets[i % m] = gamma * (value - level) + (1.0 - gamma) * offset;
+        }
+
+        Some(SeasonalFit {
+            level,
+            trend,
+            offsets,
+            errors,
+        })
+    }
+
+    /// One smoothing step from the previous level/trend and a new observation
+    fn smooth(&self, level: f64, trend: f64, value: f64, second: bool) -> (f64, f64) {
+        match self.beta {
+            Some(beta) => {
+                // Initialize the trend from the first two observations
+                let trend = if second { value - level } else { trend };
+                // Holt: L_t = alpha * Y_t + (1 - alpha) * (L_{t-1} + phi * T_{t-1})
+                let new_level =
+                    self.alpha * value + (1.0 - self.alpha) * (level + self.phi * trend);
+                // T_t = beta * (L_t - L_{t-1}) + (1 - beta) * phi * T_{t-1}
+                let new_trend = beta * (new_level - level) + (1.0 - beta) * self.phi * trend;
+                (new_level, new_trend)
+            }
+            // Exponential smoothing: L_t = alpha * Y_t + (1 - alpha) * L_{t-1}
+            None => (self.alpha * value + (1.0 - self.alpha) * level, 0.0),
+        }
     }
 
     /// Forecast next n values
     pub fn forecast(&self, n: usize) -> Vec<f64> {
-        // Simple exponential smoothing forecasts are constant
-        vec![self.level; n]
+        // Projects level + (phi + ... + phi^h) * trend (constant without trend
+        // mode), plus the seasonal offset for step h once seasonality engaged
+        let offsets = self
+            .seasonality
+            .as_ref()
+            .map_or(&[][..], |s| s.offsets.as_slice());
+        let mut damping = 0.0;
+        let mut phi_h = 1.0;
+        (1..=n)
+            .map(|h| {
+                phi_h *= self.phi;
+                damping += phi_h;
+                let seasonal = if offsets.is_empty() {
+                    0.0
+                } else {
+                    offsets[(self.history.len() + h - 1) % offsets.len()]
+                };
+                self.level + damping * self.trend + seasonal
+            })
+            .collect()
     }
 
     /// Compute forecast error metrics
@@ -1339,15 +3423,23 @@ impl ErrorForecaster {
             return ForecastMetrics::default();
         }
 
-        // Compute in-sample errors
-        let mut errors = Vec::new();
-        let mut level = self.history[0];
-
-        for &actual in self.history.iter().skip(1) {
-            let forecast = level;
-            errors.push(actual - forecast);
-            level = self.alpha * actual + (1.0 - self.alpha) * level;
-        }
+        // Compute in-sample one-step-ahead errors (Holt-Winters covers every
+        // observation; the other methods start from the second)
+        let (errors, actuals) = match self.seasonal_fit() {
+            Some(fit) => (fit.errors, &self.history[..]),
+            None => {
+                let mut errors = Vec::new();
+                let mut level = self.history[0];
+                let mut trend = 0.0;
+
+                for (i, &actual) in self.history.iter().skip(1).enumerate() {
+                    let forecast = level + self.phi * trend;
+                    errors.push(actual - forecast);
+                    (level, trend) = self.smooth(level, trend, actual, i == 0);
+                }
+                (errors, &self.history[1..])
+            }
+        };
 
         let n = errors.len() as f64;
         let mae = errors.iter().map(|e| e.abs()).sum::<f64>() / n;
@@ -1355,12 +3447,8 @@ impl ErrorForecaster {
         let rmse = mse.sqrt();
 
         // MAPE (avoid division by zero)
-        let mape = if self.history.iter().skip(1).all(|&v| v.abs() > f64::EPSILON) {
-            let sum: f64 = errors
-                .iter()
-                .zip(self.history.iter().skip(1))
-                .map(|(e, a)| (e / a).abs())
-                .sum();
+        let mape = if actuals.iter().all(|&v| v.abs() > f64::EPSILON) {
+            let sum: f64 = errors.iter().zip(actuals).map(|(e, a)| (e / a).abs()).sum();
             sum / n * 100.0
         } else {
             f64::NAN
@@ -1383,6 +3471,40 @@ impl ErrorForecaster {
     pub fn current_level(&self) -> f64 {
         self.level
     }
+
+    /// Get current trend (0 without trend mode)
+    pub fn current_trend(&self) -> f64 {
+        self.trend
+    }
+
+    /// Flag observations deviating more than `z` standard deviations from
+    /// the mean of the trailing `window` observations before them
+    ///
+    /// Early points use whatever history is available; points with fewer
+    /// than two prior observations are never flagged. A constant window
+    /// flags any change at all.
+    pub fn anomaly_flags(&self, window: usize, z: f64) -> Vec<bool> {
+        let window = window.max(1);
+        self.history
+            .iter()
+            .enumerate()
+            .map(|(i, &value)| {
+                let trailing = &self.history[i.saturating_sub(window)..i];
+                if trailing.len() < 2 {
+                    return false;
+                }
+                let n = trailing.len() as f64;
+                let mean = trailing.iter().sum::<f64>() / n;
+                let std = (trailing.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n).sqrt();
+                let deviation = (value - mean).abs();
+                if std < f64::EPSILON {
+                    deviation > f64::EPSILON
+                } else {
+                    deviation / std > z
+                }
+            })
+            .collect()
+    }
 }
 
 /// Forecast error metrics
@@ -1405,6 +3527,35 @@ pub struct ForecastMetrics {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::stack::types::{CrateInfo, DependencyInfo};
+    use std::path::PathBuf;
+
+    /// Build a dependency graph from `(from, to)` edges
+    fn graph_with_edges(edges: &[(&str, &str)]) -> DependencyGraph {
+        let mut crates: HashMap<String, CrateInfo> = HashMap::new();
+        for (from, to) in edges {
+            for name in [from, to] {
+                crates.entry(name.to_string()).or_insert_with(|| {
+                    CrateInfo::new(
+                        *name,
+                        semver::Version::new(1, 0, 0),
+                        PathBuf::from(format!("{}/Cargo.toml", name)),
+                    )
+                });
+            }
+            crates
+                .get_mut(*from)
+                .unwrap()
+                .paiml_dependencies
+                .push(DependencyInfo::new(*to, "^1.0"));
+        }
+
+        let mut graph = DependencyGraph::new();
+        for info in crates.into_values() {
+            graph.add_crate(info);
+        }
+        graph
+    }
 
     // ========================================================================
     // HealthStatus Tests
@@ -1441,6 +3592,41 @@ mod tests {
         assert_eq!(HealthStatus::from_grade(QualityGrade::F), HealthStatus::Red);
     }
 
+    #[test]
+    fn test_health_status_from_grade_with_strict() {
+        let strict = HealthThresholds::strict();
+        assert_eq!(
+            HealthStatus::from_grade_with(QualityGrade::APlus, &strict),
+            HealthStatus::Green
+        );
+        assert_eq!(
+            HealthStatus::from_grade_with(QualityGrade::A, &strict),
+            HealthStatus::Yellow
+        );
+        assert_eq!(
+            HealthStatus::from_grade_with(QualityGrade::BPlus, &strict),
+            HealthStatus::Red
+        );
+    }
+
+    #[test]
+    fn test_health_thresholds_default_matches_from_grade() {
+        let defaults = HealthThresholds::default();
+        for grade in [
+            QualityGrade::APlus,
+            QualityGrade::A,
+            QualityGrade::AMinus,
+            QualityGrade::BPlus,
+            QualityGrade::B,
+            QualityGrade::F,
+        ] {
+            assert_eq!(
+                HealthStatus::from_grade_with(grade, &defaults),
+                HealthStatus::from_grade(grade)
+            );
+        }
+    }
+
     #[test]
     fn test_health_status_icons() {
         assert_eq!(HealthStatus::Green.icon(), "🟢");
@@ -1478,6 +3664,17 @@ mod tests {
         assert_eq!(node.health, HealthStatus::Green);
     }
 
+    #[test]
+    fn test_component_node_update_health_with_strict() {
+        let mut node = ComponentNode::new("trueno", "0.7.4", StackLayer::Compute);
+        node.metrics = ComponentMetrics::with_demo_score(92.0);
+        node.update_health();
+        assert_eq!(node.health, HealthStatus::Green);
+
+        node.update_health_with(&HealthThresholds::strict());
+        assert_eq!(node.health, HealthStatus::Yellow);
+    }
+
     #[test]
     fn test_component_node_update_health_yellow() {
         let mut node = ComponentNode::new("test", "1.0.0", StackLayer::Ml);
@@ -1627,6 +3824,66 @@ mod tests {
         assert_eq!(summary.andon_status, AndonStatus::Red);
     }
 
+    #[test]
+    fn test_orphans_excludes_connected_pair() {
+        let mut diag = StackDiagnostics::new();
+        for name in ["trueno", "aprender", "isolated"] {
+            diag.add_component(ComponentNode::new(name, "1.0.0", StackLayer::Ml));
+        }
+        diag.set_graph(graph_with_edges(&[("aprender", "trueno")]));
+
+        let orphans: Vec<&str> = diag.orphans().iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(orphans, ["isolated"]);
+        assert_eq!(diag.health_summary().orphan_count, 1);
+    }
+
+    #[test]
+    fn test_orphans_without_graph() {
+        let mut diag = StackDiagnostics::new();
+        assert!(diag.orphans().is_empty());
+        assert_eq!(diag.health_summary().orphan_count, 0);
+
+        diag.add_component(ComponentNode::new("b", "1.0.0", StackLayer::Ml));
+        diag.add_component(ComponentNode::new("a", "1.0.0", StackLayer::Ml));
+        let orphans: Vec<&str> = diag.orphans().iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(orphans, ["a", "b"]);
+    }
+
+    #[test]
+    fn test_health_summary_critical_anomaly_forces_red() {
+        let mut diag = StackDiagnostics::new();
+        let mut node = ComponentNode::new("trueno", "0.7.4", StackLayer::Compute);
+        node.health = HealthStatus::Green;
+        diag.add_component(node);
+        diag.add_anomaly(Anomaly::new(
+            "trueno",
+            0.5,
+            AnomalyCategory::CoverageDrop,
+            "Coverage dropped",
+        ));
+
+        let summary = diag.health_summary();
+        assert_eq!(summary.total_anomalies, 1);
+        assert_eq!(summary.critical_anomalies, 0);
+        assert_eq!(summary.andon_status, AndonStatus::Green);
+
+        diag.add_anomaly(Anomaly::new(
+            "trueno",
+            0.95,
+            AnomalyCategory::BuildTimeSpike,
+            "Build time tripled",
+        ));
+        let summary = diag.health_summary();
+        assert!(summary.all_healthy());
+        assert_eq!(summary.total_anomalies, 2);
+        assert_eq!(summary.critical_anomalies, 1);
+        assert_eq!(summary.andon_status, AndonStatus::Red);
+        assert!(summary
+            .notes
+            .iter()
+            .any(|n| n == "1 critical anomaly detected"));
+    }
+
     #[test]
     fn test_stack_diagnostics_add_anomaly() {
         let mut diag = StackDiagnostics::new();
@@ -1647,6 +3904,24 @@ mod tests {
         assert!(!diag.anomalies()[0].is_critical());
     }
 
+    #[test]
+    fn test_stack_diagnostics_coverage_gaps() {
+        let mut diag = StackDiagnostics::new();
+        for (name, coverage) in [("good", 90.0), ("meh", 60.0), ("poor", 40.0)] {
+            let mut node = ComponentNode::new(name, "1.0", StackLayer::Compute);
+            node.metrics.coverage = coverage;
+            diag.add_component(node);
+        }
+
+        let gaps = diag.coverage_gaps(80.0);
+
+        assert_eq!(gaps.len(), 2);
+        assert_eq!(gaps[0].0, "poor");
+        assert!((gaps[0].1 - 40.0).abs() < f64::EPSILON);
+        assert_eq!(gaps[1].0, "meh");
+        assert!((gaps[1].1 - 20.0).abs() < f64::EPSILON);
+    }
+
     // ========================================================================
     // HealthSummary Tests
     // ========================================================================
@@ -1662,6 +3937,10 @@ mod tests {
             avg_demo_score: 85.0,
             avg_coverage: 90.0,
             andon_status: AndonStatus::Yellow,
+            notes: Vec::new(),
+            orphan_count: 0,
+            critical_anomalies: 0,
+            total_anomalies: 0,
         };
 
         assert_eq!(summary.health_percentage(), 85.0);
@@ -1679,6 +3958,10 @@ mod tests {
             avg_demo_score: 0.0,
             avg_coverage: 0.0,
             andon_status: AndonStatus::Unknown,
+            notes: Vec::new(),
+            orphan_count: 0,
+            critical_anomalies: 0,
+            total_anomalies: 0,
         };
 
         assert_eq!(summary.health_percentage(), 0.0);
@@ -1744,6 +4027,64 @@ mod tests {
     // AndonStatus Tests
     // ========================================================================
 
+    #[test]
+    fn test_anomalies_to_sarif() {
+        let anomalies = vec![
+            Anomaly::new(
+                "trueno",
+                0.9,
+                AnomalyCategory::CoverageDrop,
+                "Coverage fell 20%",
+            )
+            .with_recommendation("Add tests for new SIMD paths"),
+            Anomaly::new(
+                "aprender",
+                0.5,
+                AnomalyCategory::BuildTimeSpike,
+                "Build 3x slower",
+            ),
+            Anomaly::new(
+                "realizar",
+                0.6,
+                AnomalyCategory::CoverageDrop,
+                "Coverage fell 5%",
+            ),
+        ];
+
+        let sarif = anomalies_to_sarif(&anomalies);
+        assert_eq!(sarif["version"], "2.1.0");
+        let runs = sarif["runs"].as_array().unwrap();
+        assert_eq!(runs.len(), 1);
+
+        let results = runs[0]["results"].as_array().unwrap();
+        assert_eq!(results.len(), anomalies.len());
+        assert_eq!(results[0]["ruleId"], "CoverageDrop");
+        assert_eq!(results[0]["level"], "error");
+        assert_eq!(results[0]["message"]["text"], "Coverage fell 20%");
+        assert_eq!(
+            results[0]["fixes"][0]["description"]["text"],
+            "Add tests for new SIMD paths"
+        );
+        assert_eq!(results[1]["level"], "warning");
+        assert!(results[1].get("fixes").is_none());
+        assert_eq!(
+            results[2]["locations"][0]["logicalLocations"][0]["name"],
+            "realizar"
+        );
+
+        // Rules are deduplicated and indexed by results
+        let rules = runs[0]["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(results[2]["ruleIndex"], 0);
+        assert_eq!(rules[1]["id"], "BuildTimeSpike");
+    }
+
+    #[test]
+    fn test_anomalies_to_sarif_empty() {
+        let sarif = anomalies_to_sarif(&[]);
+        assert!(sarif["runs"][0]["results"].as_array().unwrap().is_empty());
+    }
+
     #[test]
     fn test_andon_status_messages() {
         assert_eq!(AndonStatus::Green.message(), "All systems healthy");
@@ -1804,35 +4145,431 @@ mod tests {
         assert!(output.contains("trueno-graph"));
     }
 
-    // ========================================================================
-    // Phase 2: Graph Analytics Tests
-    // ========================================================================
-
     #[test]
-    fn test_compute_metrics_empty() {
+    fn test_render_dashboard_anomaly_trend_arrow() {
         let mut diag = StackDiagnostics::new();
-        let metrics = diag.compute_metrics().unwrap();
+        let anomaly = Anomaly::new(
+            "trueno-graph",
+            0.9,
+            AnomalyCategory::CoverageDrop,
+            "Coverage dropped",
+        );
+        diag.record_anomaly_score(anomaly.id(), 0.6);
+        diag.add_anomaly(anomaly);
 
-        assert_eq!(metrics.total_nodes, 0);
-        assert_eq!(metrics.total_edges, 0);
-        assert_eq!(metrics.density, 0.0);
+        let output = render_dashboard(&diag);
+        let line = output.lines().find(|l| l.contains("trueno-graph")).unwrap();
+        assert!(line.contains("↑ trueno-graph"), "line: {}", line);
     }
 
     #[test]
-    fn test_compute_metrics_single_node() {
+    fn test_render_dashboard_width_narrow() {
         let mut diag = StackDiagnostics::new();
-        diag.add_component(ComponentNode::new("trueno", "0.7.4", StackLayer::Compute));
+        let mut node = ComponentNode::new("trueno", "0.7.4", StackLayer::Compute);
+        node.health = HealthStatus::Green;
+        diag.add_component(node);
+        diag.add_anomaly(Anomaly::new(
+            "trueno-graph",
+            0.75,
+            AnomalyCategory::CoverageDrop,
+            "Coverage dropped",
+        ));
 
-        let metrics = diag.compute_metrics().unwrap();
+        let output = render_dashboard_width(&diag, 40);
+        for line in output.lines() {
+            assert!(
+                display_width(line) <= 40,
+                "{} cols: {}",
+                display_width(line),
+                line
+            );
+        }
+        assert!(output.contains("ANOMALIES DETECTED"));
+        assert!(output.contains("Total Components:      1"));
 
-        assert_eq!(metrics.total_nodes, 1);
-        assert_eq!(metrics.total_edges, 0);
-        assert_eq!(metrics.density, 0.0);
-        assert_eq!(metrics.avg_degree, 0.0);
+        // Below the minimum the box is laid out at the minimum width
+        let tiny = render_dashboard_width(&diag, 10);
+        assert!(tiny
+            .lines()
+            .all(|line| display_width(line) == MIN_DASHBOARD_WIDTH));
+    }
 
-        // PageRank should be 1.0 for single node
-        let pagerank = metrics.pagerank.get("trueno").copied().unwrap_or(0.0);
-        assert!(
+    #[test]
+    fn test_render_dashboard_truncates_long_anomaly() {
+        let mut diag = StackDiagnostics::new();
+        diag.add_anomaly(Anomaly::new(
+            "a-component-with-an-unusually-long-name",
+            0.95,
+            AnomalyCategory::QualityRegression,
+            "Quality score regressed sharply across every tracked metric since the last release",
+        ));
+        diag.add_anomaly(Anomaly::new(
+            "trueno",
+            0.5,
+            AnomalyCategory::CoverageDrop,
+            "Coverage dropped",
+        ));
+
+        for output in [
+            render_dashboard(&diag),
+            render_dashboard_colored(&diag, false),
+        ] {
+            let widths: Vec<usize> = output.lines().map(display_width).collect();
+            assert!(
+                widths.iter().all(|w| *w == DEFAULT_DASHBOARD_WIDTH),
+                "{:?}",
+                widths
+            );
+            let line = output
+                .lines()
+                .find(|l| l.contains("a-component-with"))
+                .unwrap();
+            assert!(line.ends_with("… │"), "line: {}", line);
+            assert!(output.contains("trueno: Coverage dropped  "));
+        }
+    }
+
+    #[test]
+    fn test_dashboard_box_row_overflowing_segments() {
+        let mut dashboard = DashboardBox::new(DashboardStyle::EMOJI, MIN_DASHBOARD_WIDTH);
+        let full = "x".repeat(dashboard.content_width());
+        dashboard.row(&[(full.as_str(), None), ("more", Some(HealthStatus::Red))]);
+        dashboard.row(&[(full.as_str(), None), ("🟢 more", None)]);
+        dashboard.row(&[(&full[1..], None), ("🟢", None)]);
+
+        let lines: Vec<&str> = dashboard.output.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines
+            .iter()
+            .all(|line| display_width(line) == MIN_DASHBOARD_WIDTH));
+        assert!(!lines[0].contains("more"));
+        assert!(lines[2].ends_with("x… │"));
+    }
+
+    #[test]
+    fn test_render_dashboard_default_width() {
+        let diag = StackDiagnostics::new();
+        let output = render_dashboard(&diag);
+        assert!(output
+            .lines()
+            .all(|line| display_width(line) == DEFAULT_DASHBOARD_WIDTH));
+        assert_eq!(
+            render_dashboard_width(&diag, 120)
+                .lines()
+                .next()
+                .unwrap()
+                .chars()
+                .count(),
+            120
+        );
+    }
+
+    #[test]
+    fn test_component_report() {
+        let mut diag = StackDiagnostics::new();
+        let mut node = ComponentNode::new("aprender", "0.9.0", StackLayer::Ml);
+        node.metrics = ComponentMetrics::with_demo_score(88.0);
+        node.health = HealthStatus::Yellow;
+        diag.add_component(node);
+        diag.add_component(ComponentNode::new("trueno", "0.7.4", StackLayer::Compute));
+        diag.set_graph(graph_with_edges(&[("aprender", "trueno")]));
+        diag.add_anomaly(
+            Anomaly::new(
+                "aprender",
+                0.85,
+                AnomalyCategory::CoverageDrop,
+                "Coverage fell below 80%",
+            )
+            .with_recommendation("Add tests"),
+        );
+        diag.compute_metrics().unwrap();
+
+        let report = diag.component_report("aprender").unwrap();
+        assert!(report.contains("COMPONENT: aprender v0.9.0"));
+        assert!(report.contains("Demo Score:          88.0/100"));
+        assert!(report.contains("PageRank:"));
+        assert!(report.contains("DEPENDENCIES (1)"));
+        assert!(report.contains("trueno"));
+        assert!(report.contains("DEPENDENTS (0)"));
+        assert!(report.contains("ANOMALIES (1)"));
+        assert!(report.contains("Coverage fell below 80%"));
+        assert!(report.contains("→ Add tests"));
+        assert!(report
+            .lines()
+            .all(|line| display_width(line) == DEFAULT_DASHBOARD_WIDTH));
+
+        let dependency = diag.component_report("trueno").unwrap();
+        assert!(dependency.contains("DEPENDENTS (1)"));
+        assert!(dependency.contains("ANOMALIES (0)"));
+        assert!(diag.component_report("missing").is_none());
+    }
+
+    #[test]
+    fn test_health_by_layer() {
+        let mut diag = StackDiagnostics::new();
+        for name in ["trueno", "trueno-viz"] {
+            let mut node = ComponentNode::new(name, "1.0.0", StackLayer::Compute);
+            node.health = HealthStatus::Green;
+            diag.add_component(node);
+        }
+        let mut good = ComponentNode::new("aprender", "0.9.0", StackLayer::Ml);
+        good.health = HealthStatus::Green;
+        diag.add_component(good);
+        let mut bad = ComponentNode::new("aprender-tsp", "0.1.0", StackLayer::Ml);
+        bad.health = HealthStatus::Red;
+        diag.add_component(bad);
+
+        let by_layer = diag.health_by_layer();
+        assert_eq!(by_layer.len(), 2);
+        let compute = &by_layer[&StackLayer::Compute];
+        assert_eq!(compute.total_components, 2);
+        assert_eq!(compute.andon_status, AndonStatus::Green);
+        let ml = &by_layer[&StackLayer::Ml];
+        assert_eq!(ml.red_count, 1);
+        assert_eq!(ml.andon_status, AndonStatus::Red);
+
+        let output = render_dashboard_by_layer(&diag);
+        let compute_line = output
+            .lines()
+            .find(|l| l.contains("COMPUTE PRIMITIVES"))
+            .unwrap();
+        assert!(compute_line.contains("🟢"));
+        let ml_line = output
+            .lines()
+            .find(|l| l.contains("ML ALGORITHMS"))
+            .unwrap();
+        assert!(ml_line.contains("🔴"));
+        // Layers render in stack order
+        assert!(output.find("COMPUTE").unwrap() < output.find("ML ALGORITHMS").unwrap());
+
+        // Rows between the borders are closed and padded like the header
+        let lines: Vec<&str> = output.lines().collect();
+        assert!(lines[0].starts_with('┌') && lines[lines.len() - 1].starts_with('└'));
+        for line in &lines[1..lines.len() - 1] {
+            assert_eq!(display_width(line), DEFAULT_DASHBOARD_WIDTH, "{:?}", line);
+            assert!(line.ends_with('│') || line.ends_with('┤'), "{:?}", line);
+        }
+    }
+
+    #[test]
+    fn test_render_dashboard_colored_without_color() {
+        let mut diag = StackDiagnostics::new();
+        let mut node = ComponentNode::new("trueno", "0.7.4", StackLayer::Compute);
+        node.health = HealthStatus::Red;
+        diag.add_component(node);
+        diag.add_anomaly(Anomaly::new(
+            "trueno",
+            0.9,
+            AnomalyCategory::CoverageDrop,
+            "Coverage dropped",
+        ));
+
+        let output = render_dashboard_colored(&diag, false);
+        assert!(!output.contains('\x1b'));
+        assert!(output.contains(HealthStatus::Red.symbol()));
+        assert!(!output.contains("🔴"));
+        assert!(output.contains("Healthy:               0"));
+    }
+
+    #[test]
+    fn test_render_dashboard_colored_with_color() {
+        let mut diag = StackDiagnostics::new();
+        let mut node = ComponentNode::new("trueno", "0.7.4", StackLayer::Compute);
+        node.health = HealthStatus::Red;
+        diag.add_component(node);
+
+        let output = render_dashboard_colored(&diag, true);
+        assert!(output.contains("\x1b[31m"));
+        assert!(output.contains("\x1b[0m"));
+        // The classic dashboard stays escape-free
+        assert!(!render_dashboard(&diag).contains('\x1b'));
+    }
+
+    #[test]
+    fn test_merge_anomaly_deduplicates() {
+        let mut diag = StackDiagnostics::new();
+        diag.merge_anomaly(
+            Anomaly::new("trueno", 0.6, AnomalyCategory::CoverageDrop, "first")
+                .with_evidence("Coverage: 70.0%")
+                .with_evidence("Demo score: 88.0"),
+        );
+        diag.merge_anomaly(
+            Anomaly::new("trueno", 0.8, AnomalyCategory::CoverageDrop, "second")
+                .with_evidence("Coverage: 70.0%")
+                .with_evidence("Isolation score: 0.800"),
+        );
+        diag.merge_anomaly(Anomaly::new(
+            "aprender",
+            0.7,
+            AnomalyCategory::CoverageDrop,
+            "other",
+        ));
+
+        assert_eq!(diag.anomalies().len(), 2);
+        let trueno = diag.anomalies_for("trueno");
+        assert_eq!(trueno.len(), 1);
+        assert_eq!(trueno[0].score, 0.8);
+        assert_eq!(trueno[0].description, "second");
+        assert_eq!(
+            trueno[0].evidence,
+            vec![
+                "Coverage: 70.0%",
+                "Demo score: 88.0",
+                "Isolation score: 0.800"
+            ]
+        );
+        assert_eq!(diag.anomaly_history("trueno:CoverageDrop"), &[0.6, 0.8]);
+    }
+
+    #[test]
+    fn test_merge_anomaly_keeps_distinct_categories() {
+        let mut diag = StackDiagnostics::new();
+        diag.merge_anomaly(Anomaly::new(
+            "trueno",
+            0.9,
+            AnomalyCategory::CoverageDrop,
+            "coverage",
+        ));
+        diag.merge_anomaly(Anomaly::new(
+            "trueno",
+            0.5,
+            AnomalyCategory::ComplexityIncrease,
+            "complexity",
+        ));
+        assert_eq!(diag.anomalies_for("trueno").len(), 2);
+        assert!(diag.anomalies_for("missing").is_empty());
+    }
+
+    #[test]
+    fn test_record_snapshot_detects_regression() {
+        let mut diag = StackDiagnostics::new();
+        let mut trueno = ComponentNode::new("trueno", "0.7.4", StackLayer::Compute);
+        trueno.metrics = ComponentMetrics::with_demo_score(92.0);
+        diag.add_component(trueno);
+        let mut aprender = ComponentNode::new("aprender", "0.9.0", StackLayer::Ml);
+        aprender.metrics = ComponentMetrics::with_demo_score(88.0);
+        diag.add_component(aprender);
+        diag.record_snapshot();
+        assert!(diag.regressions().is_empty());
+
+        let mut trueno = ComponentNode::new("trueno", "0.7.5", StackLayer::Compute);
+        trueno.metrics = ComponentMetrics::with_demo_score(82.0);
+        diag.add_component(trueno);
+        diag.record_snapshot();
+
+        let regressions = diag.regressions();
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].component, "trueno");
+        assert_eq!(regressions[0].category, AnomalyCategory::QualityRegression);
+        assert!(regressions[0].description.contains("10.0 points"));
+        assert_eq!(diag.history().snapshots("trueno").len(), 2);
+
+        // A looser delta tolerates the drop
+        let relaxed = diag.history().clone().with_regression_delta(15.0);
+        assert!(relaxed.regressions().is_empty());
+    }
+
+    #[test]
+    fn test_component_history_save_load() {
+        let mut history = ComponentHistory::default();
+        let now = chrono::Utc::now();
+        history.record("trueno", now, ComponentMetrics::with_demo_score(95.0));
+        history.record("trueno", now, ComponentMetrics::with_demo_score(80.0));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("history.json");
+        history.save(&path).unwrap();
+
+        let loaded = ComponentHistory::load(&path).unwrap();
+        assert_eq!(loaded.snapshots("trueno").len(), 2);
+        assert_eq!(loaded.regressions().len(), 1);
+
+        let missing = ComponentHistory::load(&dir.path().join("missing.json")).unwrap();
+        assert!(missing.snapshots("trueno").is_empty());
+    }
+
+    #[test]
+    fn test_anomaly_trend_from_history() {
+        let mut diag = StackDiagnostics::new();
+        assert_eq!(diag.anomaly_trend("x:Other"), None);
+
+        diag.record_anomaly_score("x:Other", 0.9);
+        assert_eq!(diag.anomaly_trend("x:Other"), None);
+
+        diag.record_anomaly_score("x:Other", 0.5);
+        assert_eq!(diag.anomaly_trend("x:Other"), Some(AnomalyTrend::Improving));
+
+        diag.record_anomaly_score("x:Other", 0.505);
+        assert_eq!(diag.anomaly_trend("x:Other"), Some(AnomalyTrend::Stable));
+        assert_eq!(diag.anomaly_history("x:Other").len(), 3);
+    }
+
+    #[test]
+    fn test_render_dot() {
+        let mut diag = StackDiagnostics::new();
+        let mut trueno = ComponentNode::new("trueno", "0.7.4", StackLayer::Compute);
+        trueno.metrics.grade = QualityGrade::APlus;
+        trueno.update_health();
+        diag.add_component(trueno);
+        diag.add_component(ComponentNode::new("aprender", "0.8.1", StackLayer::Ml));
+        diag.set_graph(graph_with_edges(&[("aprender", "trueno")]));
+        diag.compute_metrics().unwrap();
+
+        let dot = render_dot(&diag);
+        let trimmed = dot.trim();
+
+        assert!(trimmed.starts_with("digraph stack {"));
+        assert!(trimmed.ends_with('}'));
+        assert_eq!(dot.matches('{').count(), 1);
+        assert_eq!(dot.matches('}').count(), 1);
+        assert!(dot.contains("\"trueno\""));
+        assert!(dot.contains("\"aprender\""));
+        assert!(dot.contains("\"aprender\" -> \"trueno\";"));
+        assert!(dot.contains("fillcolor=green"));
+        assert!(dot.contains("fillcolor=gray"));
+        assert!(dot.contains("v0.7.4"));
+        assert!(dot.contains("PR: "));
+    }
+
+    #[test]
+    fn test_render_dot_without_metrics() {
+        let mut diag = StackDiagnostics::new();
+        diag.add_component(ComponentNode::new("trueno", "0.7.4", StackLayer::Compute));
+
+        let dot = render_dot(&diag);
+        assert!(dot.contains("trueno\\nv0.7.4\""));
+        assert!(!dot.contains("PR: "));
+    }
+
+    // ========================================================================
+    // Phase 2: Graph Analytics Tests
+    // ========================================================================
+
+    #[test]
+    fn test_compute_metrics_empty() {
+        let mut diag = StackDiagnostics::new();
+        let metrics = diag.compute_metrics().unwrap();
+
+        assert_eq!(metrics.total_nodes, 0);
+        assert_eq!(metrics.total_edges, 0);
+        assert_eq!(metrics.density, 0.0);
+    }
+
+    #[test]
+    fn test_compute_metrics_single_node() {
+        let mut diag = StackDiagnostics::new();
+        diag.add_component(ComponentNode::new("trueno", "0.7.4", StackLayer::Compute));
+
+        let metrics = diag.compute_metrics().unwrap();
+
+        assert_eq!(metrics.total_nodes, 1);
+        assert_eq!(metrics.total_edges, 0);
+        assert_eq!(metrics.density, 0.0);
+        assert_eq!(metrics.avg_degree, 0.0);
+
+        // PageRank should be 1.0 for single node
+        let pagerank = metrics.pagerank.get("trueno").copied().unwrap_or(0.0);
+        assert!(
             (pagerank - 1.0).abs() < 0.01,
             "Single node PageRank should be ~1.0"
         );
@@ -1862,6 +4599,138 @@ mod tests {
         assert!((sum - 1.0).abs() < 0.01, "PageRank sum should be ~1.0");
     }
 
+    #[test]
+    fn test_compute_metrics_reuses_cache_without_structural_change() {
+        let mut diag = StackDiagnostics::new();
+        diag.add_component(ComponentNode::new("a", "1.0", StackLayer::Compute));
+        diag.add_component(ComponentNode::new("b", "1.0", StackLayer::Compute));
+        diag.set_graph(graph_with_edges(&[("a", "b")]));
+
+        let first = diag.compute_metrics().unwrap().pagerank.clone();
+        assert_eq!(diag.metrics_computations(), 1);
+
+        let second = diag.compute_metrics().unwrap().pagerank.clone();
+        assert_eq!(diag.metrics_computations(), 1);
+        assert_eq!(first, second);
+
+        // Structural change forces a full recomputation
+        diag.add_component(ComponentNode::new("c", "1.0", StackLayer::Ml));
+        diag.compute_metrics().unwrap();
+        assert_eq!(diag.metrics_computations(), 2);
+        assert!(diag.metrics().pagerank.contains_key("c"));
+    }
+
+    #[test]
+    fn test_mark_dirty_updates_health_only() {
+        let mut diag = StackDiagnostics::new();
+        diag.add_component(ComponentNode::new("trueno", "1.0", StackLayer::Compute));
+        diag.compute_metrics().unwrap();
+
+        let mut updated = ComponentNode::new("trueno", "1.0", StackLayer::Compute);
+        updated.metrics = ComponentMetrics::with_demo_score(96.0);
+        diag.add_component(updated);
+        diag.mark_dirty("trueno");
+        diag.compute_metrics().unwrap();
+
+        assert_eq!(diag.metrics_computations(), 1);
+        assert_eq!(
+            diag.get_component("trueno").unwrap().health,
+            HealthStatus::Green
+        );
+        assert_eq!(diag.health_summary().green_count, 1);
+    }
+
+    #[test]
+    fn test_mark_dirty_uses_health_thresholds() {
+        let mut diag = StackDiagnostics::new().with_health_thresholds(HealthThresholds::strict());
+        assert_eq!(diag.health_thresholds(), &HealthThresholds::strict());
+
+        // Grade A: Green by default, only Yellow under the strict preset
+        let mut node = ComponentNode::new("trueno", "1.0", StackLayer::Compute);
+        node.metrics = ComponentMetrics::with_demo_score(92.0);
+        diag.add_component(node);
+        diag.mark_dirty("trueno");
+        diag.compute_metrics().unwrap();
+
+        assert_eq!(
+            diag.get_component("trueno").unwrap().health,
+            HealthStatus::Yellow
+        );
+    }
+
+    #[test]
+    fn test_compute_metrics_clustering_triangle() {
+        let mut diag = StackDiagnostics::new();
+        for name in ["a", "b", "c"] {
+            diag.add_component(ComponentNode::new(name, "1.0", StackLayer::Compute));
+        }
+        diag.set_graph(graph_with_edges(&[("a", "b"), ("b", "c"), ("a", "c")]));
+
+        let metrics = diag.compute_metrics().unwrap();
+        for name in ["a", "b", "c"] {
+            assert!((metrics.clustering[name] - 1.0).abs() < 1e-9);
+        }
+        assert!((metrics.avg_clustering - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_compute_metrics_clustering_star() {
+        let mut diag = StackDiagnostics::new();
+        for name in ["hub", "x", "y", "z"] {
+            diag.add_component(ComponentNode::new(name, "1.0", StackLayer::Compute));
+        }
+        diag.set_graph(graph_with_edges(&[
+            ("hub", "x"),
+            ("hub", "y"),
+            ("hub", "z"),
+        ]));
+
+        let metrics = diag.compute_metrics().unwrap();
+        assert_eq!(metrics.clustering["hub"], 0.0);
+        // Leaves have a single neighbor
+        assert_eq!(metrics.clustering["x"], 0.0);
+        assert_eq!(metrics.avg_clustering, 0.0);
+    }
+
+    #[test]
+    fn test_graph_metrics_to_csv() {
+        let mut diag = StackDiagnostics::new();
+        diag.add_component(ComponentNode::new("A", "1.0", StackLayer::Orchestration));
+        diag.add_component(ComponentNode::new("B", "1.0", StackLayer::Ml));
+        diag.add_component(ComponentNode::new("C", "1.0", StackLayer::Compute));
+        let metrics = diag.compute_metrics().unwrap().clone();
+
+        let csv = metrics.to_csv();
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some("node,pagerank,betweenness,closeness,clustering,community,depth")
+        );
+        let rows: Vec<Vec<&str>> = lines.map(|l| l.split(',').collect()).collect();
+        assert_eq!(rows.len(), metrics.total_nodes);
+        for row in &rows {
+            assert_eq!(row.len(), 7);
+            let pagerank: f64 = row[1].parse().unwrap();
+            assert_eq!(pagerank, metrics.pagerank[row[0]]);
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("metrics.csv");
+        metrics.write_csv(&path).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), csv);
+    }
+
+    #[test]
+    fn test_graph_metrics_to_csv_missing_values() {
+        let mut metrics = GraphMetrics::default();
+        metrics.pagerank.insert("a,b".to_string(), 0.5);
+        metrics.depth_map.insert("solo".to_string(), 2);
+
+        let csv = metrics.to_csv();
+        assert!(csv.contains("\"a,b\",0.5,,,,,\n"));
+        assert!(csv.contains("solo,,,,,,2\n"));
+    }
+
     #[test]
     fn test_compute_metrics_betweenness() {
         let mut diag = StackDiagnostics::new();
@@ -1890,6 +4759,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_compute_metrics_betweenness_undirected() {
+        let mut diag = StackDiagnostics::new();
+        for name in ["left", "middle", "right"] {
+            diag.add_component(ComponentNode::new(name, "1.0", StackLayer::Compute));
+        }
+        // Edges point outward from the middle node
+        diag.set_graph(graph_with_edges(&[("middle", "left"), ("middle", "right")]));
+
+        let directed = diag.compute_metrics().unwrap();
+        assert_eq!(directed.direction, GraphDirection::Directed);
+        assert_eq!(directed.betweenness["middle"], 0.0);
+
+        let undirected = diag
+            .compute_metrics_with(GraphDirection::Undirected)
+            .unwrap();
+        assert_eq!(undirected.direction, GraphDirection::Undirected);
+        assert!(undirected.betweenness["middle"] > 0.0);
+        assert_eq!(undirected.betweenness["left"], 0.0);
+        assert_eq!(diag.metrics_computations(), 2);
+    }
+
     #[test]
     fn test_compute_metrics_depth() {
         let mut diag = StackDiagnostics::new();
@@ -1908,6 +4799,30 @@ mod tests {
         assert_eq!(metrics.max_depth, 0);
     }
 
+    #[test]
+    fn test_compute_metrics_depth_diamond() {
+        let mut diag = StackDiagnostics::new();
+        for name in ["top", "left", "right", "sink"] {
+            diag.add_component(ComponentNode::new(name, "1.0", StackLayer::Compute));
+        }
+        // Two paths to the sink, plus a shortcut edge that must not shorten it
+        diag.set_graph(graph_with_edges(&[
+            ("top", "left"),
+            ("top", "right"),
+            ("left", "sink"),
+            ("right", "sink"),
+            ("top", "sink"),
+        ]));
+
+        let metrics = diag.compute_metrics().unwrap();
+
+        assert_eq!(metrics.depth_map["top"], 0);
+        assert_eq!(metrics.depth_map["left"], 1);
+        assert_eq!(metrics.depth_map["right"], 1);
+        assert_eq!(metrics.depth_map["sink"], 2);
+        assert_eq!(metrics.max_depth, 2);
+    }
+
     #[test]
     fn test_compute_metrics_graph_density() {
         let mut diag = StackDiagnostics::new();
@@ -1954,45 +4869,296 @@ mod tests {
         assert_eq!(metrics.total_edges, 0);
     }
 
-    #[test]
-    fn test_graph_metrics_top_by_pagerank_empty() {
-        let metrics = GraphMetrics::default();
-        let top = metrics.top_by_pagerank(5);
-        assert!(top.is_empty());
+    #[test]
+    fn test_graph_metrics_top_by_pagerank_empty() {
+        let metrics = GraphMetrics::default();
+        let top = metrics.top_by_pagerank(5);
+        assert!(top.is_empty());
+    }
+
+    #[test]
+    fn test_graph_metrics_bottlenecks_empty() {
+        let metrics = GraphMetrics::default();
+        let bottlenecks = metrics.bottlenecks(0.5);
+        assert!(bottlenecks.is_empty());
+    }
+
+    #[test]
+    fn test_compute_metrics_pagerank_convergence() {
+        let mut diag = StackDiagnostics::new();
+
+        // Larger graph to test convergence
+        for i in 0..10 {
+            diag.add_component(ComponentNode::new(
+                format!("node{}", i),
+                "1.0",
+                StackLayer::Compute,
+            ));
+        }
+
+        let metrics = diag.compute_metrics().unwrap();
+
+        // All nodes should have PageRank assigned
+        assert_eq!(metrics.pagerank.len(), 10);
+
+        // Sum should be ~1.0 (normalized)
+        let sum: f64 = metrics.pagerank.values().sum();
+        assert!(
+            (sum - 1.0).abs() < 0.01,
+            "PageRank sum={} should be ~1.0",
+            sum
+        );
+    }
+
+    #[test]
+    fn test_compute_metrics_pagerank_warm_start() {
+        let mut diag = StackDiagnostics::new();
+        for name in ["A", "B", "C", "D"] {
+            diag.add_component(ComponentNode::new(name, "1.0", StackLayer::Compute));
+        }
+        diag.set_graph(graph_with_edges(&[
+            ("A", "B"),
+            ("B", "C"),
+            ("C", "D"),
+            ("A", "C"),
+        ]));
+
+        let cold = diag.compute_metrics().unwrap().pagerank_iterations;
+        let cold_scores = diag.metrics().pagerank.clone();
+
+        // Re-setting the same graph forces a recomputation that warm starts
+        // from the converged scores
+        let graph = diag.graph().unwrap().clone();
+        diag.set_graph(graph);
+        let warm = diag.compute_metrics().unwrap().pagerank_iterations;
+
+        assert!(
+            warm < cold,
+            "warm start ({} iterations) should beat cold start ({})",
+            warm,
+            cold
+        );
+        for (name, score) in &cold_scores {
+            assert!((diag.metrics().pagerank[name] - score).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_compute_metrics_pagerank_warm_start_new_node() {
+        let mut diag = StackDiagnostics::new();
+        diag.add_component(ComponentNode::new("A", "1.0", StackLayer::Compute));
+        diag.add_component(ComponentNode::new("B", "1.0", StackLayer::Ml));
+        diag.compute_metrics().unwrap();
+
+        diag.add_component(ComponentNode::new("C", "1.0", StackLayer::DataMlops));
+        let metrics = diag.compute_metrics().unwrap();
+
+        assert_eq!(metrics.pagerank.len(), 3);
+        let sum: f64 = metrics.pagerank.values().sum();
+        assert!(
+            (sum - 1.0).abs() < 0.01,
+            "PageRank sum={} should be ~1.0",
+            sum
+        );
+    }
+
+    #[test]
+    fn test_compute_metrics_communities_two_clusters() {
+        let mut diag = StackDiagnostics::new();
+        for name in ["a1", "a2", "a3", "b1", "b2", "b3"] {
+            diag.add_component(ComponentNode::new(name, "1.0", StackLayer::Compute));
+        }
+        diag.set_graph(graph_with_edges(&[
+            ("a1", "a2"),
+            ("a2", "a3"),
+            ("a3", "a1"),
+            ("b1", "b2"),
+            ("b2", "b3"),
+            ("b3", "b1"),
+            ("a3", "b1"),
+        ]));
+
+        let metrics = diag.compute_metrics().unwrap();
+        let c = &metrics.communities;
+
+        assert_eq!(c.len(), 6);
+        assert_eq!(c["a1"], c["a2"]);
+        assert_eq!(c["a2"], c["a3"]);
+        assert_eq!(c["b1"], c["b2"]);
+        assert_eq!(c["b2"], c["b3"]);
+        assert_ne!(c["a1"], c["b1"]);
+
+        let mut sizes: Vec<_> = metrics.community_sizes().into_values().collect();
+        sizes.sort();
+        assert_eq!(sizes, vec![3, 3]);
+    }
+
+    #[test]
+    fn test_compute_metrics_communities_isolated() {
+        let mut diag = StackDiagnostics::new();
+        for name in ["x", "y", "z"] {
+            diag.add_component(ComponentNode::new(name, "1.0", StackLayer::Compute));
+        }
+
+        let metrics = diag.compute_metrics().unwrap();
+
+        // Every isolated node is its own singleton community
+        let sizes = metrics.community_sizes();
+        assert_eq!(sizes.len(), 3);
+        assert!(sizes.values().all(|&n| n == 1));
+    }
+
+    #[test]
+    fn test_adjacency_and_reverse_adjacency() {
+        let mut diag = StackDiagnostics::new();
+        for name in ["A", "B"] {
+            diag.add_component(ComponentNode::new(name, "1.0", StackLayer::Compute));
+        }
+        // "external" is in the dependency graph but not the knowledge graph
+        diag.set_graph(graph_with_edges(&[("A", "B"), ("external", "B")]));
+
+        let adjacency = diag.adjacency();
+        assert_eq!(adjacency.len(), 2);
+        assert_eq!(adjacency["A"], vec!["B"]);
+        assert!(adjacency["B"].is_empty());
+
+        let reverse = diag.reverse_adjacency();
+        assert_eq!(reverse["B"], vec!["A"]);
+        assert!(reverse["A"].is_empty());
+    }
+
+    #[test]
+    fn test_compute_metrics_closeness_chain() {
+        let mut diag = StackDiagnostics::new();
+        for name in ["A", "B", "C"] {
+            diag.add_component(ComponentNode::new(name, "1.0", StackLayer::Compute));
+        }
+        diag.set_graph(graph_with_edges(&[("A", "B"), ("B", "C")]));
+
+        let metrics = diag.compute_metrics().unwrap();
+
+        // A reaches B (1) and C (2): (2/2) * (2/3)
+        assert!((metrics.closeness["A"] - 2.0 / 3.0).abs() < 1e-9);
+        // B reaches only C: (1/2) * (1/1)
+        assert!((metrics.closeness["B"] - 0.5).abs() < 1e-9);
+        // C reaches nothing
+        assert_eq!(metrics.closeness["C"], 0.0);
+
+        let top = metrics.top_by_closeness(1);
+        assert_eq!(top[0].0, "A");
+    }
+
+    #[test]
+    fn test_compute_metrics_closeness_single_node() {
+        let mut diag = StackDiagnostics::new();
+        diag.add_component(ComponentNode::new("solo", "1.0", StackLayer::Compute));
+
+        let metrics = diag.compute_metrics().unwrap();
+        assert_eq!(metrics.closeness["solo"], 0.0);
+    }
+
+    #[test]
+    fn test_detect_cycles_none_in_dag() {
+        let mut diag = StackDiagnostics::new();
+        for name in ["A", "B", "C"] {
+            diag.add_component(ComponentNode::new(name, "1.0", StackLayer::Compute));
+        }
+        diag.set_graph(graph_with_edges(&[("A", "B"), ("B", "C"), ("A", "C")]));
+
+        assert!(diag.detect_cycles().is_empty());
+        assert!(diag.health_summary().notes.is_empty());
+    }
+
+    #[test]
+    fn test_detect_cycles_three_node_cycle() {
+        let mut diag = StackDiagnostics::new();
+        for name in ["A", "B", "C"] {
+            let mut node = ComponentNode::new(name, "1.0", StackLayer::Compute);
+            node.metrics.grade = QualityGrade::APlus;
+            node.update_health();
+            diag.add_component(node);
+        }
+        diag.set_graph(graph_with_edges(&[("A", "B"), ("B", "C"), ("C", "A")]));
+
+        let cycles = diag.detect_cycles();
+        assert_eq!(cycles, vec![vec!["A", "B", "C"]]);
+
+        // All-green stack is bumped to Yellow with an explanation
+        let summary = diag.health_summary();
+        assert_eq!(summary.andon_status, AndonStatus::Yellow);
+        assert_eq!(summary.notes.len(), 1);
+        assert!(summary.notes[0].contains("A -> B -> C -> A"));
+    }
+
+    #[test]
+    fn test_detect_cycles_self_loop() {
+        let mut diag = StackDiagnostics::new();
+        diag.add_component(ComponentNode::new("A", "1.0", StackLayer::Compute));
+        diag.set_graph(graph_with_edges(&[("A", "A")]));
+
+        assert_eq!(diag.detect_cycles(), vec![vec!["A"]]);
     }
 
     #[test]
-    fn test_graph_metrics_bottlenecks_empty() {
-        let metrics = GraphMetrics::default();
-        let bottlenecks = metrics.bottlenecks(0.5);
-        assert!(bottlenecks.is_empty());
+    fn test_compute_metrics_weighted_pagerank() {
+        let pagerank = |edges: &[(&str, &str)]| {
+            let mut diag = StackDiagnostics::new();
+            for name in ["A", "B", "C"] {
+                diag.add_component(ComponentNode::new(name, "1.0", StackLayer::Compute));
+            }
+            diag.set_graph(graph_with_edges(edges));
+            diag.compute_metrics().unwrap().pagerank.clone()
+        };
+
+        let unweighted = pagerank(&[("A", "B"), ("A", "C")]);
+        let weighted = pagerank(&[("A", "B"), ("A", "B"), ("A", "C")]);
+
+        // Equal edges split rank evenly; a duplicated edge carries twice the share
+        assert!((unweighted["B"] - unweighted["C"]).abs() < 1e-9);
+        assert!(weighted["B"] > weighted["C"]);
+        assert!(weighted["B"] > unweighted["B"]);
+
+        for ranks in [&unweighted, &weighted] {
+            let sum: f64 = ranks.values().sum();
+            assert!(
+                (sum - 1.0).abs() < 0.01,
+                "PageRank sum={} should be ~1.0",
+                sum
+            );
+        }
     }
 
     #[test]
-    fn test_compute_metrics_pagerank_convergence() {
+    fn test_strongly_connected_components_cycle() {
         let mut diag = StackDiagnostics::new();
-
-        // Larger graph to test convergence
-        for i in 0..10 {
-            diag.add_component(ComponentNode::new(
-                format!("node{}", i),
-                "1.0",
-                StackLayer::Compute,
-            ));
+        for name in ["A", "B", "C", "D"] {
+            diag.add_component(ComponentNode::new(name, "1.0", StackLayer::Compute));
         }
+        diag.set_graph(graph_with_edges(&[
+            ("A", "B"),
+            ("B", "C"),
+            ("C", "A"),
+            ("C", "D"),
+        ]));
 
-        let metrics = diag.compute_metrics().unwrap();
+        let sccs = diag.strongly_connected_components();
+        assert_eq!(sccs, vec![vec!["A", "B", "C"], vec!["D"]]);
 
-        // All nodes should have PageRank assigned
-        assert_eq!(metrics.pagerank.len(), 10);
+        let output = render_dashboard(&diag);
+        assert!(output.contains("Coupled Subsystems:    1"));
+    }
 
-        // Sum should be ~1.0 (normalized)
-        let sum: f64 = metrics.pagerank.values().sum();
-        assert!(
-            (sum - 1.0).abs() < 0.01,
-            "PageRank sum={} should be ~1.0",
-            sum
-        );
+    #[test]
+    fn test_strongly_connected_components_dag() {
+        let mut diag = StackDiagnostics::new();
+        for name in ["A", "B", "C"] {
+            diag.add_component(ComponentNode::new(name, "1.0", StackLayer::Compute));
+        }
+        diag.set_graph(graph_with_edges(&[("A", "B"), ("B", "C"), ("A", "C")]));
+
+        let sccs = diag.strongly_connected_components();
+        assert_eq!(sccs, vec![vec!["A"], vec!["B"], vec!["C"]]);
+        assert!(!render_dashboard(&diag).contains("Coupled Subsystems"));
     }
 
     #[test]
@@ -2028,6 +5194,29 @@ mod tests {
         assert_eq!(forest.sample_size, 256);
     }
 
+    #[test]
+    fn test_isolation_forest_auto_sized() {
+        let small = IsolationForest::auto_sized(10);
+        let large = IsolationForest::auto_sized(10_000);
+
+        assert!(small.sample_size < large.sample_size);
+        assert!(small.n_trees <= large.n_trees);
+        assert!(large.sample_size <= 256);
+        assert!(small.n_trees >= 25 && large.n_trees <= 200);
+
+        for (mut forest, n) in [(small, 10), (large, 10_000)] {
+            let data: Vec<Vec<f64>> = (0..n)
+                .map(|i| vec![(i % 97) as f64, (i % 13) as f64 * 2.5])
+                .collect();
+            forest.fit(&data);
+            let scores = forest.score(&data[..10]);
+            assert_eq!(scores.len(), 10);
+            for score in scores {
+                assert!((0.0..=1.0).contains(&score), "invalid score {}", score);
+            }
+        }
+    }
+
     #[test]
     fn test_isolation_forest_with_feature_names() {
         let forest = IsolationForest::new(10, 32, 42)
@@ -2055,6 +5244,131 @@ mod tests {
         assert_eq!(forest.trees.len(), 10);
     }
 
+    #[test]
+    fn test_isolation_forest_fit_matches_sequential_build() {
+        let data: Vec<Vec<f64>> = (0..40)
+            .map(|i| vec![(i % 9) as f64, (i * 3 % 11) as f64])
+            .chain(std::iter::once(vec![50.0, -20.0]))
+            .collect();
+        let mut forest = IsolationForest::new(30, 32, 123);
+        forest.fit(&data);
+
+        let max_depth = (32f64).log2().ceil() as usize;
+        let mut sequential = IsolationForest::new(30, 32, 123);
+        sequential.trees = (0..30)
+            .map(|i| sequential.build_tree(&data, i, max_depth))
+            .collect();
+
+        assert_eq!(forest.trees.len(), 30);
+        assert_eq!(forest.score(&data), sequential.score(&data));
+
+        // Refitting with the same seed is reproducible
+        let mut again = IsolationForest::new(30, 32, 123);
+        again.fit(&data);
+        assert_eq!(again.score(&data), forest.score(&data));
+    }
+
+    #[test]
+    fn test_tree_seed_distinct_per_tree() {
+        assert_ne!(tree_seed(42, 0), tree_seed(42, 1));
+        assert_ne!(tree_seed(42, 0), tree_seed(43, 0));
+        assert_eq!(tree_seed(42, 5), tree_seed(42, 5));
+    }
+
+    #[test]
+    fn test_isolation_forest_feature_contributions() {
+        let mut data: Vec<Vec<f64>> = (0..30)
+            .map(|i| vec![50.0 + (i % 5) as f64, 10.0 + (i % 3) as f64])
+            .collect();
+        data.push(vec![52.0, 90.0]);
+
+        let mut forest = IsolationForest::new(100, 32, 42)
+            .with_feature_names(vec!["stable".to_string(), "spiky".to_string()]);
+        forest.fit(&data);
+        assert_eq!(forest.feature_means.len(), 2);
+
+        let contributions = forest.feature_contributions(&data[30]);
+        assert_eq!(contributions.len(), 2);
+        assert_eq!(contributions[0].0, "spiky");
+        assert!(contributions[0].1 > contributions[1].1);
+    }
+
+    #[test]
+    fn test_isolation_forest_anomaly_evidence_has_top_contributors() {
+        let mut diag = StackDiagnostics::new();
+        for i in 0..8 {
+            let mut node = ComponentNode::new(format!("ok{}", i), "1.0", StackLayer::Compute);
+            node.metrics = ComponentMetrics {
+                demo_score: 90.0 + (i % 3) as f64,
+                coverage: 90.0 - (i % 2) as f64,
+                mutation_score: 80.0,
+                complexity_avg: 5.0,
+                satd_count: 2,
+                dead_code_pct: 1.0,
+                build_time_sec: 0.0,
+                grade: QualityGrade::A,
+            };
+            diag.add_component(node);
+        }
+        let mut outlier = ComponentNode::new("untested", "1.0", StackLayer::Ml);
+        outlier.metrics = ComponentMetrics {
+            demo_score: 90.0,
+            coverage: 5.0,
+            mutation_score: 80.0,
+            complexity_avg: 5.0,
+            satd_count: 2,
+            dead_code_pct: 1.0,
+            build_time_sec: 0.0,
+            grade: QualityGrade::A,
+        };
+        diag.add_component(outlier);
+
+        let data: Vec<Vec<f64>> = diag.components().map(component_features).collect();
+        let mut forest = IsolationForest::new(100, 16, 42);
+        forest.fit(&data);
+
+        let anomalies = forest.detect_anomalies(&diag, 0.5);
+        let flagged = anomalies
+            .iter()
+            .find(|a| a.component == "untested")
+            .expect("outlier flagged");
+        let top: Vec<_> = flagged
+            .evidence
+            .iter()
+            .filter(|e| e.starts_with("Top contributor"))
+            .collect();
+        assert_eq!(top.len(), 2);
+        assert!(top[0].contains("coverage = 5.0"));
+    }
+
+    #[test]
+    fn test_isolation_forest_save_load_roundtrip() {
+        let data: Vec<Vec<f64>> = (0..20)
+            .map(|i| vec![i as f64, (i * 2) as f64 % 7.0])
+            .chain(std::iter::once(vec![100.0, 100.0]))
+            .collect();
+        let mut forest = IsolationForest::new(20, 16, 7)
+            .with_feature_names(vec!["a".to_string(), "b".to_string()]);
+        forest.fit(&data);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("models").join("forest.json");
+        forest.save(&path).unwrap();
+        let loaded = IsolationForest::load(&path).unwrap();
+
+        assert_eq!(loaded.n_trees, 20);
+        assert_eq!(loaded.sample_size, 16);
+        assert_eq!(loaded.seed, 7);
+        assert_eq!(loaded.feature_names, forest.feature_names);
+        assert_eq!(loaded.score(&data), forest.score(&data));
+    }
+
+    #[test]
+    fn test_isolation_forest_load_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(IsolationForest::load(&dir.path().join("missing.json")).is_err());
+    }
+
     #[test]
     fn test_isolation_forest_score_empty() {
         let forest = IsolationForest::new(10, 32, 42);
@@ -2113,6 +5427,7 @@ mod tests {
                 complexity_avg: 5.0,
                 satd_count: 2,
                 dead_code_pct: 1.0,
+                build_time_sec: 0.0,
                 grade: QualityGrade::A,
             };
             diag.add_component(node);
@@ -2127,6 +5442,7 @@ mod tests {
             complexity_avg: 25.0, // High
             satd_count: 50,
             dead_code_pct: 30.0, // High
+            build_time_sec: 0.0,
             grade: QualityGrade::F,
         };
         diag.add_component(anomaly_node);
@@ -2153,6 +5469,92 @@ mod tests {
         assert!(anomalies.len() <= 6);
     }
 
+    #[test]
+    fn test_isolation_forest_detect_anomalies_identical_components() {
+        let mut diag = StackDiagnostics::new();
+        for i in 0..5 {
+            diag.add_component(ComponentNode::new(
+                format!("fresh{}", i),
+                "0.1.0",
+                StackLayer::Compute,
+            ));
+        }
+
+        let mut forest = IsolationForest::new(50, 64, 42);
+        forest.fit(&vec![vec![0.0; 6]; 5]);
+
+        let anomalies = forest.detect_anomalies(&diag, 0.0);
+        assert!(anomalies.is_empty());
+    }
+
+    #[test]
+    fn test_isolation_forest_detect_anomalies_against_baseline() {
+        // Last quarter: healthy components with natural spread
+        let baseline: Vec<Vec<f64>> = (0..64)
+            .map(|i| {
+                vec![
+                    85.0 + ((i * 7) % 11) as f64,
+                    80.0 + ((i * 5) % 13) as f64,
+                    72.0 + ((i * 3) % 9) as f64,
+                    4.0 + (i % 5) as f64 * 0.4,
+                    ((i * 3) % 5) as f64,
+                    0.5 + (i % 4) as f64 * 0.3,
+                    55.0 + ((i * 11) % 30) as f64,
+                ]
+            })
+            .collect();
+
+        // Today: every component degraded the same way, so each matches its peers
+        let mut diag = StackDiagnostics::new();
+        for i in 0..4 {
+            let mut node = ComponentNode::new(format!("degraded{}", i), "1.0", StackLayer::Compute);
+            node.metrics = ComponentMetrics {
+                demo_score: 45.0,
+                coverage: 35.0,
+                mutation_score: 30.0,
+                complexity_avg: 22.0,
+                satd_count: 40,
+                dead_code_pct: 20.0,
+                build_time_sec: 600.0,
+                grade: QualityGrade::F,
+            };
+            diag.add_component(node);
+        }
+
+        // Fit on today's stack alone, nothing stands out
+        let mut forest = IsolationForest::new(100, 64, 42);
+        let live: Vec<Vec<f64>> = diag.components().map(component_features).collect();
+        forest.fit(&live);
+        assert!(forest.detect_anomalies(&diag, 0.55).is_empty());
+
+        let anomalies = forest.detect_anomalies_against(&baseline, &diag, 0.55);
+        assert_eq!(anomalies.len(), 4);
+        // Build time sits furthest from the baseline, in standard deviations
+        assert!(anomalies
+            .iter()
+            .all(|a| a.category == AnomalyCategory::BuildTimeSpike));
+        assert!(anomalies[0].evidence[0].starts_with("Baseline isolation score"));
+    }
+
+    #[test]
+    fn test_isolation_forest_detect_anomalies_against_constant_baseline() {
+        let mut diag = StackDiagnostics::new();
+        diag.add_component(ComponentNode::new("trueno", "0.1.0", StackLayer::Compute));
+
+        let forest = IsolationForest::new(50, 64, 42);
+        assert!(forest.detect_anomalies_against(&[], &diag, 0.0).is_empty());
+        assert!(forest
+            .detect_anomalies_against(&vec![vec![1.0; 7]; 8], &diag, 0.0)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_is_zero_variance() {
+        assert!(is_zero_variance(&[]));
+        assert!(is_zero_variance(&[vec![1.0, 2.0], vec![1.0, 2.0]]));
+        assert!(!is_zero_variance(&[vec![1.0, 2.0], vec![1.0, 3.0]]));
+    }
+
     #[test]
     fn test_isolation_forest_categorize_anomaly() {
         let forest = IsolationForest::default_forest();
@@ -2178,6 +5580,73 @@ mod tests {
         assert_eq!(cat5, AnomalyCategory::Other);
     }
 
+    #[test]
+    fn test_categorize_build_time_spike() {
+        let forest = IsolationForest::default_forest();
+
+        let cat = forest.categorize_anomaly(&[90.0, 90.0, 85.0, 5.0, 2.0, 1.0, 900.0]);
+        assert_eq!(cat, AnomalyCategory::BuildTimeSpike);
+
+        let features = [90.0, 90.0, 85.0, 5.0, 2.0, 1.0, 900.0];
+        assert!(describe_anomaly(&features, &cat).contains("900s"));
+        assert!(recommend_action(&cat, &features).contains("--timings"));
+
+        // A fast build is not a spike
+        let cat = forest.categorize_anomaly(&[90.0, 90.0, 85.0, 5.0, 2.0, 1.0, 30.0]);
+        assert_eq!(cat, AnomalyCategory::Other);
+    }
+
+    #[test]
+    fn test_categorize_dominant_deviation() {
+        // Coverage spread wide in training, build time tightly clustered
+        let data: Vec<Vec<f64>> = (0..40)
+            .map(|i| {
+                vec![
+                    90.0 + (i % 5) as f64,
+                    50.0 + (i % 41) as f64,
+                    80.0,
+                    5.0 + (i % 3) as f64 * 0.5,
+                    2.0,
+                    1.0 + (i % 4) as f64 * 0.2,
+                    55.0 + (i % 10) as f64,
+                ]
+            })
+            .collect();
+        let mut forest = IsolationForest::new(20, 32, 42);
+        forest.fit(&data);
+        assert_eq!(forest.feature_stds.len(), 7);
+
+        // Coverage is mildly out of range, build time is far out
+        let features = [92.0, 45.0, 80.0, 5.5, 2.0, 1.3, 600.0];
+        assert_eq!(
+            categorize_features(&features),
+            AnomalyCategory::CoverageDrop
+        );
+        assert_eq!(
+            forest.categorize_anomaly(&features),
+            AnomalyCategory::BuildTimeSpike
+        );
+
+        // Nothing deviates notably, so thresholds decide
+        let features = [92.0, 70.0, 80.0, 5.5, 2.0, 1.3, 60.0];
+        assert_eq!(forest.categorize_anomaly(&features), AnomalyCategory::Other);
+    }
+
+    #[test]
+    fn test_component_features_include_build_time() {
+        let mut node = ComponentNode::new("slow", "1.0", StackLayer::Compute);
+        node.metrics.build_time_sec = 420.0;
+        let features = component_features(&node);
+        assert_eq!(features.len(), COMPONENT_FEATURE_NAMES.len());
+        assert_eq!(features[6], 420.0);
+
+        // Older serialized metrics default the new field
+        let json = r#"{"demo_score":90.0,"coverage":90.0,"mutation_score":80.0,
+            "complexity_avg":5.0,"satd_count":1,"dead_code_pct":1.0,"grade":"A"}"#;
+        let metrics: ComponentMetrics = serde_json::from_str(json).unwrap();
+        assert_eq!(metrics.build_time_sec, 0.0);
+    }
+
     #[test]
     fn test_average_path_length() {
         assert_eq!(average_path_length(0.0), 0.0);
@@ -2192,6 +5661,105 @@ mod tests {
         assert!(c256 > c2);
     }
 
+    // ========================================================================
+    // Phase 3: ML Insights Tests - Local Outlier Factor
+    // ========================================================================
+
+    #[test]
+    fn test_lof_outlier_scores_highest() {
+        let mut lof = LocalOutlierFactor::new(3);
+        let data = vec![
+            vec![1.0, 1.0],
+            vec![1.1, 1.0],
+            vec![1.0, 1.1],
+            vec![1.1, 1.1],
+            vec![1.05, 1.05],
+            vec![10.0, 10.0],
+        ];
+        lof.fit(&data);
+
+        let scores = lof.score();
+        assert_eq!(scores.len(), 6);
+        let max_idx = scores
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+            .map(|(i, _)| i)
+            .unwrap();
+        assert_eq!(max_idx, 5);
+        assert!(scores[5] > 2.0);
+        // Inliers sit near density 1.0
+        assert!(scores[..5].iter().all(|&s| s < 1.5));
+    }
+
+    #[test]
+    fn test_lof_k_clamped_to_sample_count() {
+        let mut lof = LocalOutlierFactor::new(50);
+        lof.fit(&[vec![0.0], vec![1.0], vec![5.0]]);
+        let scores = lof.score();
+        assert_eq!(scores.len(), 3);
+        assert!(scores.iter().all(|s| s.is_finite()));
+
+        let mut single = LocalOutlierFactor::default_lof();
+        single.fit(&[vec![1.0, 2.0]]);
+        assert_eq!(single.score(), vec![1.0]);
+    }
+
+    #[test]
+    fn test_lof_detect_anomalies() {
+        let mut diag = StackDiagnostics::new();
+        for i in 0..5 {
+            let mut node = ComponentNode::new(format!("healthy{}", i), "1.0", StackLayer::Compute);
+            node.metrics = ComponentMetrics {
+                demo_score: 90.0 + i as f64,
+                coverage: 85.0 + i as f64,
+                mutation_score: 80.0,
+                complexity_avg: 5.0,
+                satd_count: 2,
+                dead_code_pct: 1.0,
+                build_time_sec: 0.0,
+                grade: QualityGrade::A,
+            };
+            diag.add_component(node);
+        }
+        let mut outlier = ComponentNode::new("anomalous", "1.0", StackLayer::Ml);
+        outlier.metrics = ComponentMetrics {
+            demo_score: 30.0,
+            coverage: 20.0,
+            mutation_score: 10.0,
+            complexity_avg: 25.0,
+            satd_count: 50,
+            dead_code_pct: 30.0,
+            build_time_sec: 0.0,
+            grade: QualityGrade::F,
+        };
+        diag.add_component(outlier);
+
+        let lof = LocalOutlierFactor::new(3);
+        let anomalies = lof.detect_anomalies(&diag, 0.5);
+        assert_eq!(anomalies.len(), 1);
+        assert_eq!(anomalies[0].component, "anomalous");
+        assert!(anomalies[0].score < 1.0);
+        assert!(anomalies[0]
+            .evidence
+            .iter()
+            .any(|e| e.starts_with("LOF score")));
+    }
+
+    #[test]
+    fn test_lof_detect_anomalies_identical_components() {
+        let mut diag = StackDiagnostics::new();
+        for i in 0..4 {
+            diag.add_component(ComponentNode::new(
+                format!("fresh{}", i),
+                "0.1.0",
+                StackLayer::Compute,
+            ));
+        }
+        let anomalies = LocalOutlierFactor::default_lof().detect_anomalies(&diag, 0.0);
+        assert!(anomalies.is_empty());
+    }
+
     // ========================================================================
     // Phase 3: ML Insights Tests - Error Forecaster
     // ========================================================================
@@ -2291,6 +5859,163 @@ mod tests {
         assert!((level - 106.3).abs() < 0.01);
     }
 
+    #[test]
+    fn test_error_forecaster_anomaly_flags_coverage_drop() {
+        let mut forecaster = ErrorForecaster::default_forecaster();
+        for value in [90.0, 91.0, 89.5, 90.5, 90.0, 60.0, 90.0] {
+            forecaster.observe(value);
+        }
+
+        let flags = forecaster.anomaly_flags(4, 3.0);
+        assert_eq!(flags.len(), 7);
+        assert_eq!(flags, vec![false, false, false, false, false, true, false]);
+    }
+
+    #[test]
+    fn test_error_forecaster_anomaly_flags_edge_cases() {
+        let empty = ErrorForecaster::default_forecaster();
+        assert!(empty.anomaly_flags(5, 2.0).is_empty());
+
+        // Constant window: no division by zero, only a change is flagged
+        let mut forecaster = ErrorForecaster::default_forecaster();
+        for value in [5.0, 5.0, 5.0, 5.0, 7.0] {
+            forecaster.observe(value);
+        }
+        assert_eq!(
+            forecaster.anomaly_flags(100, 2.0),
+            vec![false, false, false, false, true]
+        );
+    }
+
+    #[test]
+    fn test_error_forecaster_with_trend_projects_linear() {
+        let mut forecaster = ErrorForecaster::with_trend(0.5, 0.5);
+        for v in [10.0, 20.0, 30.0, 40.0] {
+            forecaster.observe(v);
+        }
+
+        assert!((forecaster.current_level() - 40.0).abs() < 1e-9);
+        assert!((forecaster.current_trend() - 10.0).abs() < 1e-9);
+
+        let forecast = forecaster.forecast(3);
+        assert_eq!(forecast.len(), 3);
+        for (f, expected) in forecast.iter().zip([50.0, 60.0, 70.0]) {
+            assert!((f - expected).abs() < 1e-9, "{} != {}", f, expected);
+        }
+    }
+
+    #[test]
+    fn test_error_forecaster_damped_trend_flattens() {
+        let mut undamped = ErrorForecaster::with_trend(0.5, 0.5);
+        let mut damped = ErrorForecaster::with_damped_trend(0.5, 0.5, 0.8);
+        for i in 0..10 {
+            undamped.observe(10.0 + 2.0 * i as f64);
+            damped.observe(10.0 + 2.0 * i as f64);
+        }
+
+        let undamped_forecast = undamped.forecast(10);
+        let damped_forecast = damped.forecast(10);
+        assert!(damped_forecast[9] < undamped_forecast[9]);
+        assert!(damped_forecast.windows(2).all(|w| w[1] >= w[0]));
+
+        // Step increments shrink by phi each step
+        let step = |h: usize| damped_forecast[h] - damped_forecast[h - 1];
+        assert!((step(2) / step(1) - 0.8).abs() < 1e-9);
+        // The total rise is bounded by phi / (1 - phi) * trend
+        let bound = damped.current_level() + 4.0 * damped.current_trend();
+        assert!(damped_forecast[9] < bound);
+    }
+
+    #[test]
+    fn test_error_forecaster_damped_phi_one_matches_holt() {
+        let mut holt = ErrorForecaster::with_trend(0.4, 0.3);
+        let mut damped = ErrorForecaster::with_damped_trend(0.4, 0.3, 1.0);
+        for value in [3.0, 5.0, 4.0, 8.0, 9.0] {
+            holt.observe(value);
+            damped.observe(value);
+        }
+        assert_eq!(holt.forecast(5), damped.forecast(5));
+        assert_eq!(holt.error_metrics().mae, damped.error_metrics().mae);
+    }
+
+    /// Period-7 sine wave around 10 with amplitude 3
+    fn weekly(t: usize) -> f64 {
+        10.0 + 3.0 * (2.0 * std::f64::consts::PI * t as f64 / 7.0).sin()
+    }
+
+    #[test]
+    fn test_error_forecaster_seasonal_reproduces_phase() {
+        let mut forecaster = ErrorForecaster::with_seasonality(0.3, 0.1, 0.3, 7);
+        for t in 0..28 {
+            forecaster.observe(weekly(t));
+        }
+
+        let forecast = forecaster.forecast(14);
+        for (h, f) in forecast.iter().enumerate() {
+            let expected = weekly(28 + h);
+            assert!(
+                (f - expected).abs() < 0.1,
+                "h={} {} != {}",
+                h + 1,
+                f,
+                expected
+            );
+        }
+        let peak = |values: &[f64]| {
+            (0..values.len())
+                .max_by(|&a, &b| values[a].total_cmp(&values[b]))
+                .unwrap()
+        };
+        let truth: Vec<f64> = (28..35).map(weekly).collect();
+        assert_eq!(peak(&forecast[..7]), peak(&truth));
+        assert!(forecaster.error_metrics().mae < 0.1);
+    }
+
+    #[test]
+    fn test_error_forecaster_seasonal_needs_two_seasons() {
+        let mut seasonal = ErrorForecaster::with_seasonality(0.3, 0.1, 0.3, 7);
+        let mut holt = ErrorForecaster::with_trend(0.3, 0.1);
+        for t in 0..13 {
+            seasonal.observe(weekly(t));
+            holt.observe(weekly(t));
+        }
+        assert_eq!(seasonal.forecast(7), holt.forecast(7));
+        assert_eq!(seasonal.error_metrics().mae, holt.error_metrics().mae);
+
+        seasonal.observe(weekly(13));
+        holt.observe(weekly(13));
+        assert_ne!(seasonal.forecast(7), holt.forecast(7));
+    }
+
+    #[test]
+    fn test_error_forecaster_new_has_no_trend() {
+        let mut forecaster = ErrorForecaster::new(0.5);
+        for v in [10.0, 20.0, 30.0] {
+            forecaster.observe(v);
+        }
+        assert_eq!(forecaster.current_trend(), 0.0);
+        let forecast = forecaster.forecast(2);
+        assert_eq!(forecast[0], forecast[1]);
+    }
+
+    #[test]
+    fn test_error_forecaster_trend_error_metrics() {
+        let series = [10.0, 20.0, 30.0, 40.0, 50.0];
+        let mut simple = ErrorForecaster::new(0.5);
+        let mut holt = ErrorForecaster::with_trend(0.5, 0.5);
+        for v in series {
+            simple.observe(v);
+            holt.observe(v);
+        }
+
+        // Trend-aware one-step forecasts track a linear series far better
+        let simple_mae = simple.error_metrics().mae;
+        let holt_mae = holt.error_metrics().mae;
+        assert!(holt_mae < simple_mae, "{} >= {}", holt_mae, simple_mae);
+        // Only the first step (before a trend is known) misses: 10 / 4
+        assert!((holt_mae - 2.5).abs() < 1e-9);
+    }
+
     #[test]
     fn test_forecast_metrics_default() {
         let metrics = ForecastMetrics::default();
@@ -264,6 +264,23 @@ impl DependencyGraph {
         }
     }
 
+    /// Get direct release dependencies of a crate (excludes dev-dependencies)
+    pub fn release_dependencies(&self, crate_name: &str) -> Vec<String> {
+        let Some(&idx) = self.node_indices.get(crate_name) else {
+            return Vec::new();
+        };
+
+        let mut deps: Vec<String> = self
+            .graph
+            .edges_directed(idx, Direction::Outgoing)
+            .filter(|edge| edge.weight().kind != DependencyKind::Dev)
+            .map(|edge| self.graph[edge.target()].clone())
+            .collect();
+        deps.sort();
+        deps.dedup();
+        deps
+    }
+
     /// Get crates that depend on this crate (reverse dependencies)
     pub fn dependents(&self, crate_name: &str) -> Vec<String> {
         let mut dependents = Vec::new();
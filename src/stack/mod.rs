@@ -33,15 +33,19 @@ pub mod types;
 pub use checker::StackChecker;
 pub use crates_io::CratesIoClient;
 pub use diagnostics::{
-    render_dashboard, AndonStatus, Anomaly, AnomalyCategory, ComponentMetrics, ComponentNode,
-    ErrorForecaster, ForecastMetrics, GraphMetrics, HealthStatus, HealthSummary, IsolationForest,
-    StackDiagnostics,
+    anomalies_to_sarif, render_dashboard, render_dashboard_by_layer, render_dashboard_colored,
+    render_dashboard_width, render_dot, AndonStatus, Anomaly, AnomalyCategory, AnomalyTrend,
+    ComponentHistory, ComponentMetrics, ComponentNode, ErrorForecaster, ForecastMetrics,
+    GraphDirection, GraphMetrics, HealthStatus, HealthSummary, HealthThresholds, IsolationForest,
+    LocalOutlierFactor, MetricsSnapshot, StackDiagnostics,
 };
 pub use graph::DependencyGraph;
 pub use publish_status::{
+    format_report_csv as format_publish_status_csv,
     format_report_json as format_publish_status_json,
-    format_report_text as format_publish_status_text, CrateStatus, PublishAction,
-    PublishStatusCache, PublishStatusReport, PublishStatusScanner,
+    format_report_markdown as format_publish_status_markdown,
+    format_report_text as format_publish_status_text, BumpKind, CacheEvent, CrateStatus,
+    PublishAction, PublishStatusCache, PublishStatusReport, PublishStatusScanner, RetryPolicy,
 };
 pub use quality::{
     format_report_json as format_quality_report_json,
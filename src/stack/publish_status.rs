@@ -10,7 +10,7 @@
 //! - Git HEAD commit SHA
 //! - Worktree modification time
 //!
-//! crates.io versions are cached with 15-minute TTL.
+//! crates.io versions are cached with a configurable TTL (15 minutes by default).
 //!
 //! ## Performance Target
 //!
@@ -19,19 +19,23 @@
 
 use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::hash::{DefaultHasher, Hash, Hasher};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::path::{Path, PathBuf};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use super::PAIML_CRATES;
+#[cfg(feature = "native")]
+use super::crates_io::{
+    is_not_found, is_retryable, CrateResponse, CratesIoClient, MockCratesIoClient,
+};
+use super::diagnostics::csv_escape;
+use super::{DependencyGraph, PAIML_CRATES};
 
 // ============================================================================
 // PUB-001: Core Types
 // ============================================================================
 
 /// Recommended action for a crate
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum PublishAction {
     /// Already published and up to date
     UpToDate,
@@ -43,6 +47,10 @@ pub enum PublishAction {
     LocalBehind,
     /// Not yet published to crates.io
     NotPublished,
+    /// Local version matches a yanked crates.io release
+    NeedsRepublish,
+    /// Published and clean, but local commits are not pushed
+    NeedsPush,
     /// Error checking status
     Error,
 }
@@ -58,6 +66,8 @@ impl PublishAction {
             Self::NeedsPublish => "📦",
             Self::LocalBehind => "⚠️",
             Self::NotPublished => "🆕",
+            Self::NeedsRepublish => "🔁",
+            Self::NeedsPush => "⬆️",
             Self::Error => "❌",
         }
     }
@@ -71,6 +81,8 @@ impl PublishAction {
             Self::NeedsPublish => "PUBLISH",
             Self::LocalBehind => "local behind",
             Self::NotPublished => "not published",
+            Self::NeedsRepublish => "REPUBLISH",
+            Self::NeedsPush => "push first",
             Self::Error => "error",
         }
     }
@@ -85,6 +97,15 @@ pub struct GitStatus {
     pub untracked: usize,
     /// Number of staged files
     pub staged: usize,
+    /// Local commits not yet pushed to the upstream branch
+    #[serde(default)]
+    pub ahead: usize,
+    /// Upstream commits not yet pulled
+    #[serde(default)]
+    pub behind: usize,
+    /// `Cargo.lock` differs from HEAD (staged or unstaged)
+    #[serde(default)]
+    pub lockfile_drift: bool,
     /// Current HEAD commit SHA (short)
     pub head_sha: String,
     /// Is repo clean?
@@ -101,10 +122,10 @@ impl GitStatus {
     /// Summary string
     #[must_use]
     pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
         if self.is_clean {
-            "clean".to_string()
+            parts.push("clean".to_string());
         } else {
-            let mut parts = Vec::new();
             if self.modified > 0 {
                 parts.push(format!("{}M", self.modified));
             }
@@ -114,8 +135,17 @@ impl GitStatus {
             if self.staged > 0 {
                 parts.push(format!("{}+", self.staged));
             }
-            parts.join(" ")
         }
+        if self.ahead > 0 {
+            parts.push(format!("↑{}", self.ahead));
+        }
+        if self.behind > 0 {
+            parts.push(format!("↓{}", self.behind));
+        }
+        if self.lockfile_drift {
+            parts.push("Cargo.lock drift".to_string());
+        }
+        parts.join(" ")
     }
 }
 
@@ -128,6 +158,9 @@ pub struct CrateStatus {
     pub local_version: Option<String>,
     /// Published version on crates.io
     pub crates_io_version: Option<String>,
+    /// Whether the local version was published to crates.io and yanked
+    #[serde(default)]
+    pub crates_io_yanked: bool,
     /// Git status
     pub git_status: GitStatus,
     /// Recommended action
@@ -138,6 +171,64 @@ pub struct CrateStatus {
     pub error: Option<String>,
 }
 
+/// Semver component to bump for the next release
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BumpKind {
+    /// Breaking changes (x.0.0)
+    Major,
+    /// New features (0.x.0)
+    Minor,
+    /// Fixes only (0.0.x)
+    Patch,
+}
+
+/// Most changed files for a release to still be recommended as a patch
+pub const PATCH_BUMP_MAX_CHANGES: usize = 5;
+
+impl CrateStatus {
+    /// Suggest the next version after `local_version` for the given bump
+    ///
+    /// Build metadata is dropped. A pre-release is promoted to its release
+    /// when the bump would not go past it (e.g. `1.2.0-rc.1` + minor = `1.2.0`).
+    #[must_use]
+    pub fn suggest_version_bump(&self, kind: BumpKind) -> Option<String> {
+        let current = semver::Version::parse(self.local_version.as_deref()?).ok()?;
+        let is_pre = !current.pre.is_empty();
+
+        let next = match kind {
+            BumpKind::Major if is_pre && current.minor == 0 && current.patch == 0 => {
+                semver::Version::new(current.major, 0, 0)
+            }
+            BumpKind::Major => semver::Version::new(current.major + 1, 0, 0),
+            BumpKind::Minor if is_pre && current.patch == 0 => {
+                semver::Version::new(current.major, current.minor, 0)
+            }
+            BumpKind::Minor => semver::Version::new(current.major, current.minor + 1, 0),
+            BumpKind::Patch if is_pre => {
+                semver::Version::new(current.major, current.minor, current.patch)
+            }
+            BumpKind::Patch => {
+                semver::Version::new(current.major, current.minor, current.patch + 1)
+            }
+        };
+
+        Some(next.to_string())
+    }
+
+    /// Heuristic bump based on the amount of uncommitted work
+    ///
+    /// Uses `git_status.total_changes()` as a crude signal: up to
+    /// [`PATCH_BUMP_MAX_CHANGES`] changed files suggests a patch, more a minor.
+    #[must_use]
+    pub fn recommended_bump(&self) -> BumpKind {
+        if self.git_status.total_changes() <= PATCH_BUMP_MAX_CHANGES {
+            BumpKind::Patch
+        } else {
+            BumpKind::Minor
+        }
+    }
+}
+
 /// Cache entry for a single repo
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CacheEntry {
@@ -152,14 +243,14 @@ pub struct CacheEntry {
 }
 
 impl CacheEntry {
-    /// Check if crates.io data is stale (>15 min)
+    /// Check if crates.io data is at least `ttl_secs` old
     #[must_use]
-    pub fn is_crates_io_stale(&self) -> bool {
+    pub fn is_crates_io_stale(&self, ttl_secs: u64) -> bool {
         let now = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap_or_default()
             .as_secs();
-        now - self.crates_io_checked_at > 15 * 60
+        now.saturating_sub(self.crates_io_checked_at) >= ttl_secs
     }
 }
 
@@ -182,6 +273,10 @@ pub struct PublishStatusReport {
     pub cache_misses: usize,
     /// Time to generate report (ms)
     pub elapsed_ms: u64,
+    /// Crates needing publish that depend on unpublished crates
+    /// (filled by [`PublishStatusReport::with_blocked`])
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blocked: Vec<(String, Vec<String>)>,
 }
 
 impl PublishStatusReport {
@@ -212,22 +307,181 @@ impl PublishStatusReport {
             cache_hits,
             cache_misses,
             elapsed_ms,
+            blocked: Vec::new(),
+        }
+    }
+
+    /// Number of crates per recommended action
+    ///
+    /// Covers every action, including those without a named count field
+    /// (e.g. `LocalBehind`, `NotPublished`, `Error`). Actions no crate has
+    /// are absent.
+    #[must_use]
+    pub fn action_histogram(&self) -> HashMap<PublishAction, usize> {
+        let mut histogram = HashMap::new();
+        for c in &self.crates {
+            *histogram.entry(c.action).or_insert(0) += 1;
+        }
+        histogram
+    }
+
+    /// Crates needing publish whose direct release dependencies are unpublished
+    ///
+    /// `cargo publish` fails for these until the listed dependencies are
+    /// published first.
+    #[must_use]
+    pub fn blocked_crates(&self, graph: &DependencyGraph) -> Vec<(String, Vec<String>)> {
+        let unpublished: BTreeSet<&str> = self
+            .crates
+            .iter()
+            .filter(|c| c.action == PublishAction::NotPublished)
+            .map(|c| c.name.as_str())
+            .collect();
+
+        let mut blocked: Vec<(String, Vec<String>)> = self
+            .crates
+            .iter()
+            .filter(|c| c.action == PublishAction::NeedsPublish)
+            .filter_map(|c| {
+                let blockers: Vec<String> = graph
+                    .release_dependencies(&c.name)
+                    .into_iter()
+                    .filter(|dep| unpublished.contains(dep.as_str()))
+                    .collect();
+                (!blockers.is_empty()).then(|| (c.name.clone(), blockers))
+            })
+            .collect();
+        blocked.sort();
+        blocked
+    }
+
+    /// Record blocked crates so reports can show them
+    #[must_use]
+    pub fn with_blocked(mut self, graph: &DependencyGraph) -> Self {
+        self.blocked = self.blocked_crates(graph);
+        self
+    }
+
+    /// Order crates needing publish so dependencies come before dependents
+    ///
+    /// Only crates with `NeedsPublish` are included; dependencies through
+    /// other crates still constrain the order. Errors if those crates form
+    /// a cycle.
+    pub fn publish_order(&self, graph: &DependencyGraph) -> Result<Vec<String>> {
+        let to_publish: BTreeSet<&str> = self
+            .crates
+            .iter()
+            .filter(|c| c.action == PublishAction::NeedsPublish)
+            .map(|c| c.name.as_str())
+            .collect();
+
+        // Transitive release dependencies restricted to the publish set
+        let deps: BTreeMap<&str, BTreeSet<&str>> = to_publish
+            .iter()
+            .map(|&name| {
+                let mut reachable = BTreeSet::new();
+                let mut stack = graph.release_dependencies(name);
+                let mut seen: BTreeSet<String> = BTreeSet::new();
+                while let Some(dep) = stack.pop() {
+                    if !seen.insert(dep.clone()) {
+                        continue;
+                    }
+                    if let Some(&member) = to_publish.get(dep.as_str()) {
+                        if member != name {
+                            reachable.insert(member);
+                        }
+                    }
+                    stack.extend(graph.release_dependencies(&dep));
+                }
+                (name, reachable)
+            })
+            .collect();
+
+        // Kahn's algorithm, alphabetical among ready crates
+        let mut remaining = deps.clone();
+        let mut order = Vec::with_capacity(remaining.len());
+        while !remaining.is_empty() {
+            let ready: Vec<&str> = remaining
+                .iter()
+                .filter(|(_, d)| d.is_empty())
+                .map(|(&name, _)| name)
+                .collect();
+            if ready.is_empty() {
+                return Err(anyhow!(
+                    "Dependency cycle among crates to publish: {}",
+                    find_cycle(&remaining).join(" -> ")
+                ));
+            }
+            for name in ready {
+                remaining.remove(name);
+                for d in remaining.values_mut() {
+                    d.remove(name);
+                }
+                order.push(name.to_string());
+            }
+        }
+
+        Ok(order)
+    }
+}
+
+/// Follow unresolved dependencies until a crate repeats, returning the loop
+fn find_cycle<'a>(remaining: &BTreeMap<&'a str, BTreeSet<&'a str>>) -> Vec<&'a str> {
+    let mut path: Vec<&str> = Vec::new();
+    let mut current = remaining.keys().next().copied();
+    while let Some(name) = current {
+        if let Some(start) = path.iter().position(|&n| n == name) {
+            let mut cycle = path.split_off(start);
+            cycle.push(name);
+            return cycle;
         }
+        path.push(name);
+        current = remaining
+            .get(name)
+            .and_then(|d| d.iter().find(|dep| remaining.contains_key(*dep)))
+            .copied();
     }
+    path
 }
 
 // ============================================================================
 // PUB-002: Cache Implementation
 // ============================================================================
 
+/// Default crates.io staleness window (15 minutes)
+pub const DEFAULT_CRATES_IO_TTL_SECS: u64 = 15 * 60;
+
+/// Entries older than this are evicted before saving (7 days)
+pub const DEFAULT_CACHE_MAX_AGE_SECS: u64 = 7 * 24 * 60 * 60;
+
+/// Maximum number of entries kept when saving
+pub const DEFAULT_CACHE_MAX_ENTRIES: usize = 256;
+
+fn default_crates_io_ttl_secs() -> u64 {
+    DEFAULT_CRATES_IO_TTL_SECS
+}
+
 /// Persistent cache for publish status
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PublishStatusCache {
     /// Cache entries by crate name
     entries: HashMap<String, CacheEntry>,
     /// Cache file path
     #[serde(skip)]
     cache_path: Option<PathBuf>,
+    /// crates.io staleness window in seconds (runtime setting, not persisted)
+    #[serde(skip, default = "default_crates_io_ttl_secs")]
+    ttl_secs: u64,
+}
+
+impl Default for PublishStatusCache {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+            cache_path: None,
+            ttl_secs: DEFAULT_CRATES_IO_TTL_SECS,
+        }
+    }
 }
 
 impl PublishStatusCache {
@@ -282,10 +536,64 @@ impl PublishStatusCache {
         self.entries.insert(name, entry);
     }
 
+    /// Drop entries created more than `max_age_secs` ago
+    ///
+    /// Returns the number of entries removed.
+    pub fn evict(&mut self, max_age_secs: u64) -> usize {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let cutoff = now.saturating_sub(max_age_secs);
+        let before = self.entries.len();
+        self.entries.retain(|_, entry| entry.created_at >= cutoff);
+        before - self.entries.len()
+    }
+
+    /// Keep only the `max_entries` most recently created entries
+    ///
+    /// Returns the number of entries removed.
+    pub fn evict_to_capacity(&mut self, max_entries: usize) -> usize {
+        if self.entries.len() <= max_entries {
+            return 0;
+        }
+        let mut by_age: Vec<(u64, String)> = self
+            .entries
+            .iter()
+            .map(|(name, entry)| (entry.created_at, name.clone()))
+            .collect();
+        // Newest first; name breaks ties so eviction is deterministic
+        by_age.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+
+        let evicted = by_age.len() - max_entries;
+        for (_, name) in by_age.into_iter().skip(max_entries) {
+            self.entries.remove(&name);
+        }
+        evicted
+    }
+
+    /// Remove the entry for a crate
+    pub fn remove(&mut self, name: &str) -> Option<CacheEntry> {
+        self.entries.remove(name)
+    }
+
     /// Clear all entries
     pub fn clear(&mut self) {
         self.entries.clear();
     }
+
+    /// crates.io staleness window in seconds
+    #[must_use]
+    pub fn ttl_secs(&self) -> u64 {
+        self.ttl_secs
+    }
+
+    /// Set the crates.io staleness window
+    #[must_use]
+    pub fn with_ttl_secs(mut self, ttl_secs: u64) -> Self {
+        self.ttl_secs = ttl_secs;
+        self
+    }
 }
 
 // ============================================================================
@@ -314,13 +622,14 @@ pub fn compute_cache_key(repo_path: &Path) -> Result<String> {
     // Get git HEAD (if available)
     let head_sha = get_git_head(repo_path).unwrap_or_else(|_| "no-git".to_string());
 
-    // Compute hash using DefaultHasher (fast, good enough for cache keys)
-    let mut hasher = DefaultHasher::new();
-    content.hash(&mut hasher);
-    head_sha.hash(&mut hasher);
-    mtime.hash(&mut hasher);
+    // blake3 is stable across platforms and Rust versions, so cache files
+    // stay valid when shared between machines
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&content);
+    hasher.update(head_sha.as_bytes());
+    hasher.update(&mtime.to_le_bytes());
 
-    Ok(format!("{:016x}", hasher.finish()))
+    Ok(hasher.finalize().to_hex().to_string())
 }
 
 /// Get git HEAD commit SHA
@@ -352,10 +661,21 @@ pub fn get_git_status(repo_path: &Path) -> Result<GitStatus> {
         return Err(anyhow!("git status failed"));
     }
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut status = parse_git_porcelain(&String::from_utf8_lossy(&output.stdout));
+    status.head_sha = get_git_head(repo_path).unwrap_or_default();
+    (status.ahead, status.behind) = get_git_ahead_behind(repo_path).unwrap_or_default();
+
+    Ok(status)
+}
+
+/// Parse `git status --porcelain` output into change counts
+///
+/// A tracked `Cargo.lock` at the repo root that differs from HEAD sets
+/// `lockfile_drift`. HEAD and upstream fields are left at their defaults.
+fn parse_git_porcelain(output: &str) -> GitStatus {
     let mut status = GitStatus::default();
 
-    for line in stdout.lines() {
+    for line in output.lines() {
         if line.len() < 2 {
             continue;
         }
@@ -368,54 +688,144 @@ pub fn get_git_status(repo_path: &Path) -> Result<GitStatus> {
             (_, w) if w != ' ' && w != '?' => status.modified += 1,
             _ => {}
         }
+
+        // Renames list `old -> new`; the new path is what HEAD will see
+        let path = line.get(3..).unwrap_or_default();
+        let path = path.rsplit(" -> ").next().unwrap_or(path);
+        if path == "Cargo.lock" && index != '?' {
+            status.lockfile_drift = true;
+        }
     }
 
     status.is_clean = status.total_changes() == 0;
-    status.head_sha = get_git_head(repo_path).unwrap_or_default();
+    status
+}
 
-    Ok(status)
+/// Count commits ahead of/behind the upstream branch
+///
+/// Returns `(0, 0)` when no upstream is configured.
+fn get_git_ahead_behind(repo_path: &Path) -> Result<(usize, usize)> {
+    let output = std::process::Command::new("git")
+        .args(["rev-list", "--left-right", "--count", "@{u}...HEAD"])
+        .current_dir(repo_path)
+        .output()?;
+
+    if !output.status.success() {
+        // No upstream (or not a repo): nothing to push or pull
+        return Ok((0, 0));
+    }
+
+    parse_ahead_behind(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parse `git rev-list --left-right --count @{u}...HEAD` output into `(ahead, behind)`
+///
+/// Output is `<behind>\t<ahead>`: the left side counts upstream-only commits.
+fn parse_ahead_behind(output: &str) -> Result<(usize, usize)> {
+    let mut counts = output.split_whitespace().map(str::parse::<usize>);
+    match (counts.next(), counts.next(), counts.next()) {
+        (Some(Ok(behind)), Some(Ok(ahead)), None) => Ok((ahead, behind)),
+        _ => Err(anyhow!(
+            "Unexpected git rev-list output: {:?}",
+            output.trim()
+        )),
+    }
 }
 
 // ============================================================================
 // PUB-005: Version Parsing
 // ============================================================================
 
-/// Extract version from Cargo.toml
+/// Extract `[package].version` from Cargo.toml
+/// Resolves `version.workspace = true` from the enclosing workspace root
 pub fn get_local_version(repo_path: &Path) -> Result<String> {
     let cargo_toml = repo_path.join("Cargo.toml");
-    let content = std::fs::read_to_string(&cargo_toml)?;
+    let manifest = read_manifest(&cargo_toml)?;
+
+    match manifest.get("package").and_then(|p| p.get("version")) {
+        Some(toml::Value::String(version)) => Ok(version.clone()),
+        Some(toml::Value::Table(table))
+            if table.get("workspace").and_then(toml::Value::as_bool) == Some(true) =>
+        {
+            workspace_package_version(repo_path)
+        }
+        _ => Err(anyhow!(
+            "No [package].version found in {}",
+            cargo_toml.display()
+        )),
+    }
+}
 
-    for line in content.lines() {
-        if line.starts_with("version") {
-            if let Some(version) = line.split('"').nth(1) {
-                return Ok(version.to_string());
-            }
+/// Read `[workspace.package].version` from the nearest workspace root
+fn workspace_package_version(repo_path: &Path) -> Result<String> {
+    for dir in repo_path.ancestors() {
+        let cargo_toml = dir.join("Cargo.toml");
+        if !cargo_toml.exists() {
+            continue;
         }
+        let manifest = read_manifest(&cargo_toml)?;
+        let Some(workspace) = manifest.get("workspace") else {
+            continue;
+        };
+        return workspace
+            .get("package")
+            .and_then(|p| p.get("version"))
+            .and_then(toml::Value::as_str)
+            .map(str::to_string)
+            .ok_or_else(|| {
+                anyhow!(
+                    "Version is inherited from the workspace, but {} has no [workspace.package].version",
+                    cargo_toml.display()
+                )
+            });
     }
 
-    Err(anyhow!("No version found in Cargo.toml"))
+    Err(anyhow!(
+        "Version is inherited from the workspace, but no workspace root was found above {}",
+        repo_path.display()
+    ))
+}
+
+/// Parse a Cargo.toml into a TOML table
+fn read_manifest(cargo_toml: &Path) -> Result<toml::Table> {
+    let content = std::fs::read_to_string(cargo_toml)?;
+    toml::from_str(&content).map_err(|e| anyhow!("Invalid {}: {}", cargo_toml.display(), e))
 }
 
 /// Compare versions and determine action
+///
+/// `local_yanked` is whether the local version exists on crates.io as a
+/// yanked release; it can't be published again, whatever `max_version` says.
 pub fn determine_action(
     local: Option<&str>,
     crates_io: Option<&str>,
+    local_yanked: bool,
     git_status: &GitStatus,
 ) -> PublishAction {
+    // A stale lockfile must be committed before publishing, even if it is
+    // the only change
+    let is_clean = git_status.is_clean && !git_status.lockfile_drift;
+
     match (local, crates_io) {
         (None, _) => PublishAction::Error,
         (Some(_), None) => {
-            if git_status.is_clean {
+            if is_clean {
                 PublishAction::NotPublished
             } else {
                 PublishAction::NeedsCommit
             }
         }
         (Some(local), Some(remote)) => {
-            if !git_status.is_clean {
+            if !is_clean {
                 PublishAction::NeedsCommit
+            } else if local_yanked {
+                PublishAction::NeedsRepublish
             } else if local == remote {
-                PublishAction::UpToDate
+                if git_status.ahead > 0 {
+                    PublishAction::NeedsPush
+                } else {
+                    PublishAction::UpToDate
+                }
             } else {
                 // Parse and compare versions
                 match (
@@ -435,6 +845,108 @@ pub fn determine_action(
 // PUB-006: Scanner Implementation
 // ============================================================================
 
+/// Default number of concurrent crates.io requests during a scan
+pub const DEFAULT_FETCH_CONCURRENCY: usize = 8;
+
+/// Retry policy for transient crates.io failures (exponential backoff)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total attempts per crate, including the first (minimum 1)
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubled on each subsequent retry
+    pub base_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay before retrying after the given failed attempt (1-based)
+    #[must_use]
+    pub fn delay_for(&self, attempt: u32) -> Duration {
+        self.base_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+    }
+}
+
+/// Fetch with retries, giving up immediately on errors that cannot clear
+/// (missing crate, offline cache miss; see [`is_retryable`])
+#[cfg(feature = "native")]
+async fn fetch_with_retry(
+    fetcher: &dyn CrateFetcher,
+    name: &str,
+    policy: RetryPolicy,
+) -> (Result<CrateResponse>, u32) {
+    let max_attempts = policy.max_attempts.max(1);
+    let mut attempt = 1;
+    loop {
+        match fetcher.fetch(name).await {
+            Ok(response) => return (Ok(response), attempt),
+            Err(e) if !is_retryable(&e) || attempt >= max_attempts => return (Err(e), attempt),
+            Err(_) => {
+                tokio::time::sleep(policy.delay_for(attempt)).await;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Source of crates.io metadata for the scanner
+#[cfg(feature = "native")]
+#[async_trait::async_trait]
+pub trait CrateFetcher: Send + Sync {
+    /// Fetch crate info; called concurrently for several crates
+    async fn fetch(&self, name: &str) -> Result<CrateResponse>;
+
+    /// Record a fetched response (e.g. in a client-side cache)
+    fn store(&mut self, _name: &str, _response: &CrateResponse) {}
+}
+
+#[cfg(feature = "native")]
+#[async_trait::async_trait]
+impl CrateFetcher for CratesIoClient {
+    async fn fetch(&self, name: &str) -> Result<CrateResponse> {
+        match self.cached_crate(name) {
+            Some(response) => Ok(response),
+            None => self.fetch_crate(name).await,
+        }
+    }
+
+    fn store(&mut self, name: &str, response: &CrateResponse) {
+        self.cache_crate(name, response);
+    }
+}
+
+#[cfg(feature = "native")]
+#[async_trait::async_trait]
+impl CrateFetcher for MockCratesIoClient {
+    async fn fetch(&self, name: &str) -> Result<CrateResponse> {
+        self.get_crate(name)
+    }
+}
+
+/// Why a crate's cached status was used or refreshed during a scan
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheEvent {
+    /// Cache key matched and crates.io data was fresh
+    Hit,
+    /// Cached entry exists but Cargo.toml or git HEAD changed
+    KeyChanged,
+    /// Cache key matched but crates.io data outlived the TTL
+    TtlExpired,
+    /// No cached entry for the crate
+    Missing,
+}
+
+/// Callback invoked with each crate name and its [`CacheEvent`]
+pub type CacheEventHook = Box<dyn Fn(&str, CacheEvent) + Send + Sync>;
+
 /// Scan workspace for PAIML crates and return publish status
 pub struct PublishStatusScanner {
     /// Workspace root (parent of crate directories)
@@ -443,7 +955,15 @@ pub struct PublishStatusScanner {
     cache: PublishStatusCache,
     /// crates.io client (for async fetches)
     #[cfg(feature = "native")]
-    crates_io: Option<super::crates_io::CratesIoClient>,
+    crates_io: Option<Box<dyn CrateFetcher>>,
+    /// Maximum concurrent crates.io requests
+    fetch_concurrency: usize,
+    /// Retry policy for transient crates.io failures
+    retry: RetryPolicy,
+    /// Crates discovered from `[workspace].members` (None = use `PAIML_CRATES`)
+    members: Option<Vec<(String, PathBuf)>>,
+    /// Observer told why each crate was served from cache or refreshed
+    on_cache_event: Option<CacheEventHook>,
 }
 
 impl PublishStatusScanner {
@@ -455,19 +975,144 @@ impl PublishStatusScanner {
             cache: PublishStatusCache::load(),
             #[cfg(feature = "native")]
             crates_io: None,
+            fetch_concurrency: DEFAULT_FETCH_CONCURRENCY,
+            retry: RetryPolicy::default(),
+            members: None,
+            on_cache_event: None,
+        }
+    }
+
+    /// Create scanner from a workspace Cargo.toml, scanning its `[workspace].members`
+    ///
+    /// Member entries may be glob patterns (e.g. `crates/*`). Accepts either
+    /// the manifest path or the directory containing it.
+    pub fn from_workspace_manifest(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let manifest_path = if path.is_dir() {
+            path.join("Cargo.toml")
+        } else {
+            path.to_path_buf()
+        };
+        let workspace_root = manifest_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+
+        let manifest = read_manifest(&manifest_path)?;
+        let patterns = manifest
+            .get("workspace")
+            .and_then(|w| w.get("members"))
+            .and_then(|m| m.as_array())
+            .ok_or_else(|| anyhow!("No [workspace].members in {}", manifest_path.display()))?;
+
+        let mut members = BTreeMap::new();
+        for pattern in patterns.iter().filter_map(|p| p.as_str()) {
+            let full_pattern = workspace_root.join(pattern);
+            let entries = glob::glob(&full_pattern.to_string_lossy())
+                .map_err(|e| anyhow!("Invalid workspace member pattern '{}': {}", pattern, e))?;
+            for dir in entries.flatten() {
+                let member_manifest = dir.join("Cargo.toml");
+                if !member_manifest.exists() {
+                    continue;
+                }
+                let name = read_manifest(&member_manifest)
+                    .ok()
+                    .and_then(|m| {
+                        m.get("package")
+                            .and_then(|p| p.get("name"))
+                            .and_then(|n| n.as_str())
+                            .map(str::to_string)
+                    })
+                    .or_else(|| dir.file_name().map(|n| n.to_string_lossy().into_owned()));
+                if let Some(name) = name {
+                    members.insert(name, dir);
+                }
+            }
         }
+
+        let mut scanner = Self::new(workspace_root);
+        scanner.members = Some(members.into_iter().collect());
+        Ok(scanner)
     }
 
     /// Initialize crates.io client
     #[cfg(feature = "native")]
-    pub fn with_crates_io(mut self) -> Self {
-        self.crates_io = Some(super::crates_io::CratesIoClient::new().with_persistent_cache());
+    pub fn with_crates_io(self) -> Self {
+        self.with_fetcher(CratesIoClient::new().with_persistent_cache())
+    }
+
+    /// Use a custom crates.io metadata source
+    #[cfg(feature = "native")]
+    pub fn with_fetcher(mut self, fetcher: impl CrateFetcher + 'static) -> Self {
+        self.crates_io = Some(Box::new(fetcher));
+        self
+    }
+
+    /// Limit the number of concurrent crates.io requests (minimum 1)
+    pub fn with_fetch_concurrency(mut self, limit: usize) -> Self {
+        self.fetch_concurrency = limit.max(1);
+        self
+    }
+
+    /// Retry transient crates.io failures up to `max_attempts` times in total
+    pub fn with_retry(mut self, max_attempts: u32, base_delay: Duration) -> Self {
+        self.retry = RetryPolicy {
+            max_attempts: max_attempts.max(1),
+            base_delay,
+        };
+        self
+    }
+
+    /// Use a specific status cache instead of the default on-disk one
+    pub fn with_cache(mut self, cache: PublishStatusCache) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// Set how long cached crates.io versions stay fresh
+    pub fn with_crates_io_ttl(mut self, ttl: Duration) -> Self {
+        self.cache.ttl_secs = ttl.as_secs();
+        self
+    }
+
+    /// Report why each crate was served from cache or refreshed
+    ///
+    /// The hook runs once per crate during [`Self::scan`] and
+    /// [`Self::check_crate`], e.g. to log which crates were refetched and why.
+    pub fn with_cache_event_hook(
+        mut self,
+        hook: impl Fn(&str, CacheEvent) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_cache_event = Some(Box::new(hook));
         self
     }
 
-    /// Find all PAIML crate directories in workspace
+    /// Classify the cache state for a crate and notify the hook
+    fn cache_event(&self, name: &str, cache_key: &str) -> CacheEvent {
+        let event = match self.cache.entries.get(name) {
+            None => CacheEvent::Missing,
+            Some(entry) if entry.cache_key != cache_key => CacheEvent::KeyChanged,
+            Some(entry) if entry.is_crates_io_stale(self.cache.ttl_secs()) => {
+                CacheEvent::TtlExpired
+            }
+            Some(_) => CacheEvent::Hit,
+        };
+        if let Some(hook) = &self.on_cache_event {
+            hook(name, event);
+        }
+        event
+    }
+
+    /// Find all crate directories in workspace
+    ///
+    /// Uses the workspace members when created via [`Self::from_workspace_manifest`],
+    /// otherwise falls back to the known `PAIML_CRATES` directories.
     #[must_use]
     pub fn find_crate_dirs(&self) -> Vec<(String, PathBuf)> {
+        if let Some(members) = &self.members {
+            return members.clone();
+        }
+
         PAIML_CRATES
             .iter()
             .filter_map(|name| {
@@ -492,6 +1137,7 @@ impl PublishStatusScanner {
                     name: name.to_string(),
                     local_version: None,
                     crates_io_version: None,
+                    crates_io_yanked: false,
                     git_status: GitStatus::default(),
                     action: PublishAction::Error,
                     path: path.to_path_buf(),
@@ -501,8 +1147,8 @@ impl PublishStatusScanner {
         };
 
         // Check cache
-        if let Some(entry) = self.cache.get(name, &cache_key) {
-            if !entry.is_crates_io_stale() {
+        if self.cache_event(name, &cache_key) == CacheEvent::Hit {
+            if let Some(entry) = self.cache.get(name, &cache_key) {
                 // Cache hit - O(1)
                 return entry.status.clone();
             }
@@ -523,6 +1169,7 @@ impl PublishStatusScanner {
         let action = determine_action(
             local_version.as_deref(),
             crates_io_version.as_deref(),
+            false,
             &git_status,
         );
 
@@ -530,6 +1177,7 @@ impl PublishStatusScanner {
             name: name.to_string(),
             local_version,
             crates_io_version,
+            crates_io_yanked: false,
             git_status,
             action,
             path: path.to_path_buf(),
@@ -565,12 +1213,13 @@ impl PublishStatusScanner {
         let mut statuses = Vec::with_capacity(crate_dirs.len());
         let mut cache_hits = 0;
 
-        // First pass: check cache and collect statuses
+        // First pass: check cache and collect local info
+        let mut pending: Vec<(usize, String)> = Vec::new();
         for (name, path) in &crate_dirs {
             let cache_key = compute_cache_key(path).unwrap_or_default();
 
-            if let Some(entry) = self.cache.get(name, &cache_key) {
-                if !entry.is_crates_io_stale() {
+            if self.cache_event(name, &cache_key) == CacheEvent::Hit {
+                if let Some(entry) = self.cache.get(name, &cache_key) {
                     cache_hits += 1;
                     statuses.push(entry.status.clone());
                     continue;
@@ -578,40 +1227,77 @@ impl PublishStatusScanner {
             }
 
             // Need refresh - get local info first
-            let mut status = self.refresh_crate(name, path, &cache_key);
-
-            // Fetch crates.io version
-            if let Some(ref mut client) = self.crates_io {
-                if let Ok(response) = client.get_crate(name).await {
-                    status.crates_io_version = Some(response.krate.max_version.clone());
-                    status.action = determine_action(
-                        status.local_version.as_deref(),
-                        status.crates_io_version.as_deref(),
-                        &status.git_status,
-                    );
-
-                    // Update cache with crates.io version
-                    let now = SystemTime::now()
-                        .duration_since(UNIX_EPOCH)
-                        .unwrap_or_default()
-                        .as_secs();
-
-                    self.cache.insert(
-                        name.clone(),
-                        CacheEntry {
-                            cache_key: cache_key.clone(),
-                            status: status.clone(),
-                            crates_io_checked_at: now,
-                            created_at: now,
-                        },
-                    );
-                }
-            }
+            pending.push((statuses.len(), cache_key.clone()));
+            statuses.push(self.refresh_crate(name, path, &cache_key));
+        }
 
-            statuses.push(status);
+        // Second pass: fetch crates.io versions concurrently
+        if let Some(fetcher) = self.crates_io.as_mut() {
+            use futures::stream::{self, StreamExt};
+
+            let names: Vec<String> = pending
+                .iter()
+                .map(|(i, _)| statuses[*i].name.clone())
+                .collect();
+            let shared: &dyn CrateFetcher = fetcher.as_ref();
+            let retry = self.retry;
+            let results: Vec<(Result<CrateResponse>, u32)> = stream::iter(&names)
+                .map(|name| fetch_with_retry(shared, name, retry))
+                .buffered(self.fetch_concurrency)
+                .collect()
+                .await;
+
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs();
+
+            for ((index, cache_key), (result, attempts)) in pending.into_iter().zip(results) {
+                let status = &mut statuses[index];
+                let response = match result {
+                    Ok(response) => response,
+                    // Genuinely unpublished: the NotPublished action from refresh stands
+                    Err(e) if is_not_found(&e) => continue,
+                    Err(e) => {
+                        // Unknown remote state: report it and don't cache the guess
+                        status.error = Some(format!(
+                            "crates.io fetch failed after {} attempt(s): {}",
+                            attempts, e
+                        ));
+                        status.action = PublishAction::Error;
+                        self.cache.remove(&status.name);
+                        continue;
+                    }
+                };
+                fetcher.store(&status.name, &response);
+                status.crates_io_version = Some(response.krate.max_version.clone());
+                status.crates_io_yanked = status
+                    .local_version
+                    .as_deref()
+                    .is_some_and(|version| response.is_version_yanked(version));
+                status.action = determine_action(
+                    status.local_version.as_deref(),
+                    status.crates_io_version.as_deref(),
+                    status.crates_io_yanked,
+                    &status.git_status,
+                );
+
+                // Update cache with crates.io version
+                self.cache.insert(
+                    status.name.clone(),
+                    CacheEntry {
+                        cache_key,
+                        status: status.clone(),
+                        crates_io_checked_at: now,
+                        created_at: now,
+                    },
+                );
+            }
         }
 
-        // Save cache
+        // Save cache (dropping old entries so it can't grow unbounded)
+        self.cache.evict(DEFAULT_CACHE_MAX_AGE_SECS);
+        self.cache.evict_to_capacity(DEFAULT_CACHE_MAX_ENTRIES);
         let _ = self.cache.save();
 
         let elapsed_ms = start.elapsed().as_millis() as u64;
@@ -650,7 +1336,11 @@ pub fn format_report_text(report: &PublishStatusReport) -> String {
 
     // Rows
     for status in &report.crates {
-        let local = status.local_version.as_deref().unwrap_or("-");
+        let local = match status.local_version.as_deref() {
+            Some(version) if status.crates_io_yanked => format!("{} (yanked)", version),
+            Some(version) => version.to_string(),
+            None => "-".to_string(),
+        };
         let remote = status.crates_io_version.as_deref().unwrap_or("-");
         let git = status.git_status.summary();
 
@@ -669,6 +1359,16 @@ pub fn format_report_text(report: &PublishStatusReport) -> String {
 
     writeln!(out, "{}", "─".repeat(65)).unwrap();
 
+    for (name, blockers) in &report.blocked {
+        writeln!(
+            out,
+            "⛔ BLOCKED: {} depends on unpublished {}",
+            name,
+            blockers.join(", ")
+        )
+        .unwrap();
+    }
+
     // Summary
     writeln!(out).unwrap();
     writeln!(
@@ -677,6 +1377,10 @@ pub fn format_report_text(report: &PublishStatusReport) -> String {
         report.total, report.needs_publish, report.needs_commit, report.up_to_date
     )
     .unwrap();
+    let yanked = report.crates.iter().filter(|c| c.crates_io_yanked).count();
+    if yanked > 0 {
+        writeln!(out, "🔁 {} crates have a yanked crates.io release", yanked).unwrap();
+    }
     writeln!(
         out,
         "⚡ {}ms (cache: {} hits, {} misses)",
@@ -687,6 +1391,52 @@ pub fn format_report_text(report: &PublishStatusReport) -> String {
     out
 }
 
+/// Format report as CSV (one row per crate)
+pub fn format_report_csv(report: &PublishStatusReport) -> String {
+    let mut out = String::from("name,local,crates_io,git,action\n");
+    for status in &report.crates {
+        let fields = [
+            status.name.as_str(),
+            status.local_version.as_deref().unwrap_or(""),
+            status.crates_io_version.as_deref().unwrap_or(""),
+            &status.git_status.summary(),
+            status.action.description(),
+        ];
+        let row: Vec<String> = fields.iter().map(|f| csv_escape(f)).collect();
+        out.push_str(&row.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// Format report as a GitHub-flavored Markdown table
+pub fn format_report_markdown(report: &PublishStatusReport) -> String {
+    let mut out = String::new();
+
+    out.push_str("# PAIML Stack Publish Status\n\n");
+    out.push_str("| Crate | Local | crates.io | Git | Action |\n");
+    out.push_str("|-------|-------|-----------|-----|--------|\n");
+
+    for status in &report.crates {
+        out.push_str(&format!(
+            "| {} | {} | {} | {} | {} {} |\n",
+            status.name,
+            status.local_version.as_deref().unwrap_or("-"),
+            status.crates_io_version.as_deref().unwrap_or("-"),
+            status.git_status.summary(),
+            status.action.symbol(),
+            status.action.description()
+        ));
+    }
+
+    out.push_str(&format!(
+        "\n**{} crates**: {} publish, {} commit, {} up-to-date\n",
+        report.total, report.needs_publish, report.needs_commit, report.up_to_date
+    ));
+
+    out
+}
+
 /// Format report as JSON
 pub fn format_report_json(report: &PublishStatusReport) -> Result<String> {
     Ok(serde_json::to_string_pretty(report)?)
@@ -699,6 +1449,9 @@ pub fn format_report_json(report: &PublishStatusReport) -> Result<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::stack::crates_io::CrateFetchError;
+    use crate::stack::graph::DependencyEdge;
+    use crate::stack::types::{CrateInfo, DependencyKind};
 
     // ========================================================================
     // PUB-001: PublishAction tests
@@ -711,6 +1464,8 @@ mod tests {
         assert_eq!(PublishAction::NeedsPublish.symbol(), "📦");
         assert_eq!(PublishAction::LocalBehind.symbol(), "⚠️");
         assert_eq!(PublishAction::NotPublished.symbol(), "🆕");
+        assert_eq!(PublishAction::NeedsRepublish.symbol(), "🔁");
+        assert_eq!(PublishAction::NeedsPush.symbol(), "⬆️");
         assert_eq!(PublishAction::Error.symbol(), "❌");
     }
 
@@ -718,54 +1473,249 @@ mod tests {
     fn test_pub_001_action_descriptions() {
         assert_eq!(PublishAction::UpToDate.description(), "up to date");
         assert_eq!(PublishAction::NeedsPublish.description(), "PUBLISH");
+        assert_eq!(PublishAction::NeedsRepublish.description(), "REPUBLISH");
     }
 
-    // ========================================================================
-    // PUB-002: GitStatus tests
-    // ========================================================================
-
-    #[test]
-    fn test_pub_002_git_status_clean() {
-        let status = GitStatus {
-            modified: 0,
-            untracked: 0,
-            staged: 0,
-            head_sha: "abc123".to_string(),
-            is_clean: true,
-        };
-        assert_eq!(status.total_changes(), 0);
-        assert_eq!(status.summary(), "clean");
+    fn status_with_version(version: &str) -> CrateStatus {
+        CrateStatus {
+            name: "test".to_string(),
+            local_version: Some(version.to_string()),
+            crates_io_version: None,
+            crates_io_yanked: false,
+            git_status: GitStatus::default(),
+            action: PublishAction::NeedsPublish,
+            path: PathBuf::from("."),
+            error: None,
+        }
     }
 
     #[test]
-    fn test_pub_002_git_status_dirty() {
-        let status = GitStatus {
-            modified: 3,
-            untracked: 2,
-            staged: 1,
-            head_sha: "abc123".to_string(),
-            is_clean: false,
-        };
-        assert_eq!(status.total_changes(), 6);
-        assert_eq!(status.summary(), "3M 2? 1+");
+    fn test_pub_001_suggest_version_bump() {
+        let status = status_with_version("1.2.3");
+        assert_eq!(
+            status.suggest_version_bump(BumpKind::Major).as_deref(),
+            Some("2.0.0")
+        );
+        assert_eq!(
+            status.suggest_version_bump(BumpKind::Minor).as_deref(),
+            Some("1.3.0")
+        );
+        assert_eq!(
+            status.suggest_version_bump(BumpKind::Patch).as_deref(),
+            Some("1.2.4")
+        );
     }
 
     #[test]
-    fn test_pub_002_git_status_modified_only() {
+    fn test_pub_001_suggest_version_bump_pre_release() {
+        let status = status_with_version("1.2.3-alpha.1");
+        assert_eq!(
+            status.suggest_version_bump(BumpKind::Major).as_deref(),
+            Some("2.0.0")
+        );
+        assert_eq!(
+            status.suggest_version_bump(BumpKind::Minor).as_deref(),
+            Some("1.3.0")
+        );
+        assert_eq!(
+            status.suggest_version_bump(BumpKind::Patch).as_deref(),
+            Some("1.2.3")
+        );
+
+        let status = status_with_version("2.0.0-rc.1");
+        assert_eq!(
+            status.suggest_version_bump(BumpKind::Major).as_deref(),
+            Some("2.0.0")
+        );
+        assert_eq!(
+            status.suggest_version_bump(BumpKind::Minor).as_deref(),
+            Some("2.0.0")
+        );
+    }
+
+    #[test]
+    fn test_pub_001_suggest_version_bump_build_metadata() {
+        let status = status_with_version("0.4.1+build.42");
+        assert_eq!(
+            status.suggest_version_bump(BumpKind::Patch).as_deref(),
+            Some("0.4.2")
+        );
+        assert_eq!(
+            status.suggest_version_bump(BumpKind::Minor).as_deref(),
+            Some("0.5.0")
+        );
+    }
+
+    #[test]
+    fn test_pub_001_suggest_version_bump_invalid() {
+        assert_eq!(
+            status_with_version("not-a-version").suggest_version_bump(BumpKind::Patch),
+            None
+        );
+
+        let mut status = status_with_version("1.0.0");
+        status.local_version = None;
+        assert_eq!(status.suggest_version_bump(BumpKind::Patch), None);
+    }
+
+    #[test]
+    fn test_pub_001_recommended_bump() {
+        let mut status = status_with_version("1.0.0");
+        assert_eq!(status.recommended_bump(), BumpKind::Patch);
+
+        status.git_status.modified = PATCH_BUMP_MAX_CHANGES;
+        assert_eq!(status.recommended_bump(), BumpKind::Patch);
+
+        status.git_status.untracked = 1;
+        assert_eq!(status.recommended_bump(), BumpKind::Minor);
+    }
+
+    // ========================================================================
+    // PUB-002: GitStatus tests
+    // ========================================================================
+
+    #[test]
+    fn test_pub_002_git_status_clean() {
+        let status = GitStatus {
+            modified: 0,
+            untracked: 0,
+            staged: 0,
+            ahead: 0,
+            behind: 0,
+            lockfile_drift: false,
+            head_sha: "abc123".to_string(),
+            is_clean: true,
+        };
+        assert_eq!(status.total_changes(), 0);
+        assert_eq!(status.summary(), "clean");
+    }
+
+    #[test]
+    fn test_pub_002_git_status_dirty() {
+        let status = GitStatus {
+            modified: 3,
+            untracked: 2,
+            staged: 1,
+            ahead: 0,
+            behind: 0,
+            lockfile_drift: false,
+            head_sha: "abc123".to_string(),
+            is_clean: false,
+        };
+        assert_eq!(status.total_changes(), 6);
+        assert_eq!(status.summary(), "3M 2? 1+");
+    }
+
+    #[test]
+    fn test_pub_002_git_status_modified_only() {
         let status = GitStatus {
             modified: 5,
             untracked: 0,
             staged: 0,
+            ahead: 0,
+            behind: 0,
+            lockfile_drift: false,
             head_sha: "def456".to_string(),
             is_clean: false,
         };
         assert_eq!(status.summary(), "5M");
     }
 
+    #[test]
+    fn test_pub_002_git_status_ahead_behind_summary() {
+        let status = GitStatus {
+            ahead: 2,
+            behind: 1,
+            is_clean: true,
+            ..Default::default()
+        };
+        assert_eq!(status.summary(), "clean ↑2 ↓1");
+    }
+
+    #[test]
+    fn test_pub_002_parse_git_porcelain() {
+        let status = parse_git_porcelain("M  src/lib.rs\n M README.md\n?? notes.txt\n");
+        assert_eq!(
+            (status.staged, status.modified, status.untracked),
+            (1, 1, 1)
+        );
+        assert!(!status.is_clean);
+        assert!(!status.lockfile_drift);
+
+        assert!(parse_git_porcelain("").is_clean);
+        // An untracked lockfile is not drift from HEAD
+        assert!(!parse_git_porcelain("?? Cargo.lock\n").lockfile_drift);
+    }
+
+    #[test]
+    fn test_pub_002_cargo_lock_drift_needs_commit() {
+        let git = parse_git_porcelain(" M Cargo.lock\n");
+        assert!(git.lockfile_drift);
+        assert_eq!(git.summary(), "1M Cargo.lock drift");
+
+        // Versions match, so only the lockfile stands between this and UpToDate
+        let action = determine_action(Some("1.0.0"), Some("1.0.0"), false, &git);
+        assert_eq!(action, PublishAction::NeedsCommit);
+
+        // Drift is reported even if the caller marked the tree clean
+        let git = GitStatus {
+            is_clean: true,
+            lockfile_drift: true,
+            ..Default::default()
+        };
+        let action = determine_action(Some("1.0.0"), Some("1.0.0"), false, &git);
+        assert_eq!(action, PublishAction::NeedsCommit);
+        assert_eq!(git.summary(), "clean Cargo.lock drift");
+    }
+
+    #[test]
+    fn test_pub_002_parse_ahead_behind() {
+        assert_eq!(parse_ahead_behind("0\t3\n").unwrap(), (3, 0));
+        assert_eq!(parse_ahead_behind("4\t1\n").unwrap(), (1, 4));
+        assert_eq!(parse_ahead_behind("0\t0").unwrap(), (0, 0));
+        assert!(parse_ahead_behind("").is_err());
+        assert!(parse_ahead_behind("fatal: no upstream").is_err());
+    }
+
+    #[test]
+    fn test_pub_002_ahead_behind_without_upstream() {
+        let temp = tempfile::tempdir().unwrap();
+        assert_eq!(get_git_ahead_behind(temp.path()).unwrap(), (0, 0));
+    }
+
     // ========================================================================
     // PUB-003: Cache tests
     // ========================================================================
 
+    #[test]
+    fn test_pub_003_cache_key_blake3_stable() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("Cargo.toml"),
+            "[package]\nname = \"demo\"\nversion = \"0.1.0\"\n",
+        )
+        .unwrap();
+
+        let key1 = compute_cache_key(dir.path()).unwrap();
+        let key2 = compute_cache_key(dir.path()).unwrap();
+        assert_eq!(key1, key2);
+        assert_eq!(key1.len(), 64);
+        assert!(key1.chars().all(|c| c.is_ascii_hexdigit()));
+
+        std::fs::write(
+            dir.path().join("Cargo.toml"),
+            "[package]\nname = \"demo\"\nversion = \"0.2.0\"\n",
+        )
+        .unwrap();
+        assert_ne!(compute_cache_key(dir.path()).unwrap(), key1);
+    }
+
+    #[test]
+    fn test_pub_003_cache_key_missing_manifest() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(compute_cache_key(dir.path()).is_err());
+    }
+
     #[test]
     fn test_pub_003_cache_entry_stale() {
         let old_time = SystemTime::now()
@@ -780,6 +1730,7 @@ mod tests {
                 name: "test".to_string(),
                 local_version: Some("1.0.0".to_string()),
                 crates_io_version: Some("1.0.0".to_string()),
+                crates_io_yanked: false,
                 git_status: GitStatus::default(),
                 action: PublishAction::UpToDate,
                 path: PathBuf::from("."),
@@ -789,7 +1740,7 @@ mod tests {
             created_at: old_time,
         };
 
-        assert!(entry.is_crates_io_stale());
+        assert!(entry.is_crates_io_stale(DEFAULT_CRATES_IO_TTL_SECS));
     }
 
     #[test]
@@ -805,6 +1756,7 @@ mod tests {
                 name: "test".to_string(),
                 local_version: Some("1.0.0".to_string()),
                 crates_io_version: Some("1.0.0".to_string()),
+                crates_io_yanked: false,
                 git_status: GitStatus::default(),
                 action: PublishAction::UpToDate,
                 path: PathBuf::from("."),
@@ -814,7 +1766,106 @@ mod tests {
             created_at: now,
         };
 
-        assert!(!entry.is_crates_io_stale());
+        assert!(!entry.is_crates_io_stale(DEFAULT_CRATES_IO_TTL_SECS));
+    }
+
+    #[test]
+    fn test_pub_003_cache_entry_ttl() {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let entry_at = |checked_at| CacheEntry {
+            cache_key: "test".to_string(),
+            status: CrateStatus {
+                name: "test".to_string(),
+                local_version: Some("1.0.0".to_string()),
+                crates_io_version: Some("1.0.0".to_string()),
+                crates_io_yanked: false,
+                git_status: GitStatus::default(),
+                action: PublishAction::UpToDate,
+                path: PathBuf::from("."),
+                error: None,
+            },
+            crates_io_checked_at: checked_at,
+            created_at: checked_at,
+        };
+
+        // Zero TTL: always stale, even when just checked
+        assert!(entry_at(now).is_crates_io_stale(0));
+
+        // One-hour TTL keeps a 20-minute-old entry fresh
+        let twenty_minutes_ago = entry_at(now - 20 * 60);
+        assert!(twenty_minutes_ago.is_crates_io_stale(DEFAULT_CRATES_IO_TTL_SECS));
+        assert!(!twenty_minutes_ago.is_crates_io_stale(60 * 60));
+    }
+
+    fn entry_created_at(name: &str, created_at: u64) -> CacheEntry {
+        CacheEntry {
+            cache_key: "key".to_string(),
+            status: CrateStatus {
+                name: name.to_string(),
+                local_version: Some("1.0.0".to_string()),
+                crates_io_version: Some("1.0.0".to_string()),
+                crates_io_yanked: false,
+                git_status: GitStatus::default(),
+                action: PublishAction::UpToDate,
+                path: PathBuf::from("."),
+                error: None,
+            },
+            crates_io_checked_at: created_at,
+            created_at,
+        }
+    }
+
+    #[test]
+    fn test_pub_003_cache_evict_by_age() {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let mut cache = PublishStatusCache::default();
+        cache.insert("fresh".into(), entry_created_at("fresh", now));
+        cache.insert("hour".into(), entry_created_at("hour", now - 3600));
+        cache.insert("week".into(), entry_created_at("week", now - 8 * 86400));
+
+        assert_eq!(cache.evict(DEFAULT_CACHE_MAX_AGE_SECS), 1);
+        assert!(cache.entries.contains_key("fresh"));
+        assert!(cache.entries.contains_key("hour"));
+        assert!(!cache.entries.contains_key("week"));
+
+        assert_eq!(cache.evict(60), 1);
+        assert_eq!(cache.entries.len(), 1);
+        assert!(cache.entries.contains_key("fresh"));
+    }
+
+    #[test]
+    fn test_pub_003_cache_evict_to_capacity() {
+        let mut cache = PublishStatusCache::default();
+        for (name, created_at) in [("a", 100), ("b", 400), ("c", 200), ("d", 300)] {
+            cache.insert(name.into(), entry_created_at(name, created_at));
+        }
+
+        assert_eq!(cache.evict_to_capacity(10), 0);
+        assert_eq!(cache.evict_to_capacity(2), 2);
+
+        let mut survivors: Vec<&String> = cache.entries.keys().collect();
+        survivors.sort();
+        assert_eq!(survivors, vec!["b", "d"]);
+
+        assert_eq!(cache.evict_to_capacity(0), 2);
+        assert!(cache.entries.is_empty());
+    }
+
+    #[test]
+    fn test_pub_003_cache_ttl_default_and_override() {
+        let cache = PublishStatusCache::default();
+        assert_eq!(cache.ttl_secs(), DEFAULT_CRATES_IO_TTL_SECS);
+        assert_eq!(cache.with_ttl_secs(3600).ttl_secs(), 3600);
+
+        // TTL is a runtime setting and is not read back from disk
+        let cache: PublishStatusCache = serde_json::from_str(r#"{"entries": {}}"#).unwrap();
+        assert_eq!(cache.ttl_secs(), DEFAULT_CRATES_IO_TTL_SECS);
     }
 
     #[test]
@@ -831,6 +1882,7 @@ mod tests {
                 name: "test".to_string(),
                 local_version: Some("1.0.0".to_string()),
                 crates_io_version: None,
+                crates_io_yanked: false,
                 git_status: GitStatus::default(),
                 action: PublishAction::NotPublished,
                 path: PathBuf::from("."),
@@ -858,7 +1910,7 @@ mod tests {
             is_clean: true,
             ..Default::default()
         };
-        let action = determine_action(Some("1.0.0"), Some("1.0.0"), &git);
+        let action = determine_action(Some("1.0.0"), Some("1.0.0"), false, &git);
         assert_eq!(action, PublishAction::UpToDate);
     }
 
@@ -868,7 +1920,7 @@ mod tests {
             is_clean: true,
             ..Default::default()
         };
-        let action = determine_action(Some("1.0.1"), Some("1.0.0"), &git);
+        let action = determine_action(Some("1.0.1"), Some("1.0.0"), false, &git);
         assert_eq!(action, PublishAction::NeedsPublish);
     }
 
@@ -879,7 +1931,7 @@ mod tests {
             modified: 5,
             ..Default::default()
         };
-        let action = determine_action(Some("1.0.1"), Some("1.0.0"), &git);
+        let action = determine_action(Some("1.0.1"), Some("1.0.0"), false, &git);
         assert_eq!(action, PublishAction::NeedsCommit);
     }
 
@@ -889,7 +1941,7 @@ mod tests {
             is_clean: true,
             ..Default::default()
         };
-        let action = determine_action(Some("1.0.0"), Some("1.0.1"), &git);
+        let action = determine_action(Some("1.0.0"), Some("1.0.1"), false, &git);
         assert_eq!(action, PublishAction::LocalBehind);
     }
 
@@ -899,17 +1951,49 @@ mod tests {
             is_clean: true,
             ..Default::default()
         };
-        let action = determine_action(Some("1.0.0"), None, &git);
+        let action = determine_action(Some("1.0.0"), None, false, &git);
         assert_eq!(action, PublishAction::NotPublished);
     }
 
     #[test]
     fn test_pub_004_determine_action_no_local() {
         let git = GitStatus::default();
-        let action = determine_action(None, Some("1.0.0"), &git);
+        let action = determine_action(None, Some("1.0.0"), false, &git);
         assert_eq!(action, PublishAction::Error);
     }
 
+    #[test]
+    fn test_pub_004_determine_action_yanked() {
+        let git = GitStatus {
+            is_clean: true,
+            ..Default::default()
+        };
+        let action = determine_action(Some("1.0.0"), Some("1.0.0"), true, &git);
+        assert_eq!(action, PublishAction::NeedsRepublish);
+
+        // crates.io leaves the yanked 1.0.1 out of max_version, but it
+        // can't be published again
+        let action = determine_action(Some("1.0.1"), Some("1.0.0"), true, &git);
+        assert_eq!(action, PublishAction::NeedsRepublish);
+
+        // Uncommitted changes still come first
+        let dirty = GitStatus::default();
+        let action = determine_action(Some("1.0.1"), Some("1.0.0"), true, &dirty);
+        assert_eq!(action, PublishAction::NeedsCommit);
+    }
+
+    #[test]
+    fn test_pub_004_determine_action_needs_push() {
+        let git = GitStatus {
+            is_clean: true,
+            ahead: 2,
+            ..Default::default()
+        };
+        let action = determine_action(Some("1.0.0"), Some("1.0.0"), false, &git);
+        assert_eq!(action, PublishAction::NeedsPush);
+        assert_eq!(action.description(), "push first");
+    }
+
     // ========================================================================
     // PUB-005: Report tests
     // ========================================================================
@@ -921,6 +2005,7 @@ mod tests {
                 name: "a".to_string(),
                 local_version: Some("1.0.0".to_string()),
                 crates_io_version: Some("1.0.0".to_string()),
+                crates_io_yanked: false,
                 git_status: GitStatus::default(),
                 action: PublishAction::UpToDate,
                 path: PathBuf::from("."),
@@ -930,6 +2015,7 @@ mod tests {
                 name: "b".to_string(),
                 local_version: Some("1.0.1".to_string()),
                 crates_io_version: Some("1.0.0".to_string()),
+                crates_io_yanked: false,
                 git_status: GitStatus::default(),
                 action: PublishAction::NeedsPublish,
                 path: PathBuf::from("."),
@@ -939,6 +2025,7 @@ mod tests {
                 name: "c".to_string(),
                 local_version: Some("1.0.0".to_string()),
                 crates_io_version: Some("1.0.0".to_string()),
+                crates_io_yanked: false,
                 git_status: GitStatus {
                     modified: 3,
                     is_clean: false,
@@ -961,6 +2048,516 @@ mod tests {
         assert_eq!(report.elapsed_ms, 50);
     }
 
+    #[test]
+    fn test_pub_005_report_action_histogram() {
+        let statuses = vec![
+            status("a", PublishAction::UpToDate),
+            status("b", PublishAction::UpToDate),
+            status("c", PublishAction::NeedsPublish),
+            status("d", PublishAction::NeedsCommit),
+            status("e", PublishAction::LocalBehind),
+            status("f", PublishAction::NotPublished),
+            status("g", PublishAction::NotPublished),
+            status("h", PublishAction::NotPublished),
+            status("i", PublishAction::Error),
+        ];
+
+        let report = PublishStatusReport::from_statuses(statuses, 0, 0);
+        let histogram = report.action_histogram();
+
+        assert_eq!(histogram[&PublishAction::UpToDate], 2);
+        assert_eq!(histogram[&PublishAction::NeedsPublish], 1);
+        assert_eq!(histogram[&PublishAction::NeedsCommit], 1);
+        assert_eq!(histogram[&PublishAction::LocalBehind], 1);
+        assert_eq!(histogram[&PublishAction::NotPublished], 3);
+        assert_eq!(histogram[&PublishAction::Error], 1);
+        assert!(!histogram.contains_key(&PublishAction::NeedsPush));
+        assert_eq!(histogram.values().sum::<usize>(), report.total);
+    }
+
+    #[test]
+    fn test_pub_005_local_version_plain() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("Cargo.toml"),
+            "[package]\nname = \"demo\"\nversion = \"1.2.3\"\n\n[dependencies]\nserde = { version = \"1.0\" }\n",
+        )
+        .unwrap();
+
+        assert_eq!(get_local_version(dir.path()).unwrap(), "1.2.3");
+    }
+
+    #[test]
+    fn test_pub_005_local_version_workspace_inherited() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(
+            root.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n\n[workspace.package]\nversion = \"0.4.0\"\n",
+        )
+        .unwrap();
+        let member = root.path().join("crates").join("demo");
+        std::fs::create_dir_all(&member).unwrap();
+        std::fs::write(
+            member.join("Cargo.toml"),
+            "[package]\nname = \"demo\"\nversion.workspace = true\n",
+        )
+        .unwrap();
+
+        assert_eq!(get_local_version(&member).unwrap(), "0.4.0");
+    }
+
+    #[test]
+    fn test_pub_005_local_version_missing() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\"a\"]\n\n[workspace.package]\nversion = \"9.9.9\"\n",
+        )
+        .unwrap();
+
+        let err = get_local_version(dir.path()).unwrap_err().to_string();
+        assert!(err.contains("No [package].version"), "error: {}", err);
+    }
+
+    fn status(name: &str, action: PublishAction) -> CrateStatus {
+        CrateStatus {
+            name: name.to_string(),
+            local_version: Some("1.0.1".to_string()),
+            crates_io_version: Some("1.0.0".to_string()),
+            crates_io_yanked: false,
+            git_status: GitStatus::default(),
+            action,
+            path: PathBuf::from("."),
+            error: None,
+        }
+    }
+
+    fn graph_with_edges(edges: &[(&str, &str, DependencyKind)]) -> DependencyGraph {
+        let mut graph = DependencyGraph::new();
+        for &(from, to, kind) in edges {
+            for name in [from, to] {
+                if graph.get_crate(name).is_none() {
+                    graph.add_crate(CrateInfo::new(
+                        name,
+                        semver::Version::new(1, 0, 0),
+                        PathBuf::from(format!("{}/Cargo.toml", name)),
+                    ));
+                }
+            }
+            graph.add_dependency(
+                from,
+                to,
+                DependencyEdge {
+                    version_req: "^1.0".to_string(),
+                    is_path: false,
+                    kind,
+                },
+            );
+        }
+        graph
+    }
+
+    #[test]
+    fn test_pub_005_publish_order_chain() {
+        // A depends on B, B depends on C
+        let graph = graph_with_edges(&[
+            ("A", "B", DependencyKind::Normal),
+            ("B", "C", DependencyKind::Normal),
+        ]);
+        let report = PublishStatusReport::from_statuses(
+            vec![
+                status("A", PublishAction::NeedsPublish),
+                status("B", PublishAction::NeedsPublish),
+                status("C", PublishAction::NeedsPublish),
+            ],
+            0,
+            0,
+        );
+
+        assert_eq!(report.publish_order(&graph).unwrap(), vec!["C", "B", "A"]);
+    }
+
+    #[test]
+    fn test_pub_005_publish_order_skips_up_to_date() {
+        // A -> B -> C, but B is already published
+        let graph = graph_with_edges(&[
+            ("A", "B", DependencyKind::Normal),
+            ("B", "C", DependencyKind::Normal),
+        ]);
+        let report = PublishStatusReport::from_statuses(
+            vec![
+                status("A", PublishAction::NeedsPublish),
+                status("B", PublishAction::UpToDate),
+                status("C", PublishAction::NeedsPublish),
+            ],
+            0,
+            0,
+        );
+
+        assert_eq!(report.publish_order(&graph).unwrap(), vec!["C", "A"]);
+    }
+
+    #[test]
+    fn test_pub_005_blocked_by_unpublished_dependency() {
+        // B needs publish but depends on A, which was never published
+        let graph = graph_with_edges(&[
+            ("B", "A", DependencyKind::Normal),
+            ("B", "C", DependencyKind::Normal),
+            ("D", "A", DependencyKind::Dev),
+        ]);
+        let report = PublishStatusReport::from_statuses(
+            vec![
+                status("A", PublishAction::NotPublished),
+                status("B", PublishAction::NeedsPublish),
+                status("C", PublishAction::UpToDate),
+                status("D", PublishAction::NeedsPublish),
+            ],
+            0,
+            0,
+        );
+
+        // Dev-dependencies don't block publishing
+        let blocked = report.blocked_crates(&graph);
+        assert_eq!(blocked, vec![("B".to_string(), vec!["A".to_string()])]);
+
+        let text = format_report_text(&report.with_blocked(&graph));
+        assert!(text.contains("BLOCKED: B depends on unpublished A"));
+    }
+
+    #[test]
+    fn test_pub_005_publish_order_cycle() {
+        let graph = graph_with_edges(&[
+            ("A", "B", DependencyKind::Normal),
+            ("B", "A", DependencyKind::Normal),
+            ("C", "A", DependencyKind::Dev),
+            ("A", "C", DependencyKind::Normal),
+        ]);
+        let report = PublishStatusReport::from_statuses(
+            vec![
+                status("A", PublishAction::NeedsPublish),
+                status("B", PublishAction::NeedsPublish),
+            ],
+            0,
+            0,
+        );
+
+        let err = report.publish_order(&graph).unwrap_err().to_string();
+        assert!(err.contains("A -> B -> A"), "error: {}", err);
+
+        // Dev-dependencies never form a publish cycle
+        let report = PublishStatusReport::from_statuses(
+            vec![
+                status("A", PublishAction::NeedsPublish),
+                status("C", PublishAction::NeedsPublish),
+            ],
+            0,
+            0,
+        );
+        assert_eq!(report.publish_order(&graph).unwrap(), vec!["C", "A"]);
+    }
+
+    // ========================================================================
+    // PUB-006: Scanner tests
+    // ========================================================================
+
+    /// Fetcher that records the peak number of in-flight requests
+    struct ConcurrencyProbe {
+        inner: MockCratesIoClient,
+        in_flight: std::sync::atomic::AtomicUsize,
+        peak: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl CrateFetcher for ConcurrencyProbe {
+        async fn fetch(&self, name: &str) -> Result<CrateResponse> {
+            use std::sync::atomic::Ordering;
+
+            let current = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(current, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            self.inner.get_crate(name)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pub_006_scan_fetches_concurrently() {
+        let names = ["trueno", "aprender", "batuta", "pacha"];
+        let (temp, cache) = scan_workspace(&names);
+        let mut mock = MockCratesIoClient::new();
+        for name in names {
+            mock.add_crate(name, "1.0.0");
+        }
+
+        let peak = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let probe = ConcurrencyProbe {
+            inner: mock,
+            in_flight: std::sync::atomic::AtomicUsize::new(0),
+            peak: peak.clone(),
+        };
+
+        let mut scanner = PublishStatusScanner::new(temp.path().to_path_buf())
+            .with_cache(cache)
+            .with_fetcher(probe)
+            .with_fetch_concurrency(names.len());
+        let report = scanner.scan().await.unwrap();
+
+        assert_eq!(peak.load(std::sync::atomic::Ordering::SeqCst), names.len());
+        assert_eq!(report.crates.len(), names.len());
+        assert_eq!(report.cache_hits, 0);
+        assert!(report
+            .crates
+            .iter()
+            .all(|c| c.crates_io_version.as_deref() == Some("1.0.0")));
+    }
+
+    /// Fetcher that fails with `error` a fixed number of times per
+    /// published crate (404s are returned straight away)
+    struct FlakyFetcher {
+        inner: MockCratesIoClient,
+        failures: usize,
+        error: fn(&str) -> anyhow::Error,
+        attempts: std::sync::Mutex<HashMap<String, usize>>,
+    }
+
+    fn http_503(name: &str) -> anyhow::Error {
+        CrateFetchError::Status {
+            name: name.to_string(),
+            status: 503,
+        }
+        .into()
+    }
+
+    #[async_trait::async_trait]
+    impl CrateFetcher for FlakyFetcher {
+        async fn fetch(&self, name: &str) -> Result<CrateResponse> {
+            let response = self.inner.get_crate(name)?;
+            let attempt = {
+                let mut attempts = self.attempts.lock().unwrap();
+                let count = attempts.entry(name.to_string()).or_default();
+                *count += 1;
+                *count
+            };
+            if attempt <= self.failures {
+                return Err((self.error)(name));
+            }
+            Ok(response)
+        }
+    }
+
+    fn scan_workspace(names: &[&str]) -> (tempfile::TempDir, PublishStatusCache) {
+        let temp = tempfile::tempdir().unwrap();
+        for name in names {
+            let dir = temp.path().join(name);
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(
+                dir.join("Cargo.toml"),
+                format!("[package]\nname = \"{name}\"\nversion = \"1.0.0\"\n"),
+            )
+            .unwrap();
+        }
+        let cache = PublishStatusCache {
+            cache_path: Some(temp.path().join("cache.json")),
+            ..Default::default()
+        };
+        (temp, cache)
+    }
+
+    #[tokio::test]
+    async fn test_pub_006_scan_reports_cache_events() {
+        let (temp, mut cache) = scan_workspace(&["trueno", "aprender", "batuta"]);
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let seed = |name: &str, checked_at: u64| CacheEntry {
+            cache_key: compute_cache_key(&temp.path().join(name)).unwrap(),
+            status: status(name, PublishAction::UpToDate),
+            crates_io_checked_at: checked_at,
+            created_at: now,
+        };
+        cache.insert("trueno".to_string(), seed("trueno", now));
+        cache.insert("aprender".to_string(), seed("aprender", 0));
+
+        let events = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let mut scanner = PublishStatusScanner::new(temp.path().to_path_buf())
+            .with_cache(cache)
+            .with_cache_event_hook(move |name, event| {
+                sink.lock().unwrap().push((name.to_string(), event));
+            });
+        let report = scanner.scan().await.unwrap();
+        assert_eq!(report.cache_hits, 1);
+
+        let events: HashMap<String, CacheEvent> = events.lock().unwrap().iter().cloned().collect();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events["trueno"], CacheEvent::Hit);
+        assert_eq!(events["aprender"], CacheEvent::TtlExpired);
+        assert_eq!(events["batuta"], CacheEvent::Missing);
+    }
+
+    #[tokio::test]
+    async fn test_pub_006_scan_retries_transient_failures() {
+        let (temp, cache) = scan_workspace(&["trueno"]);
+        let mut mock = MockCratesIoClient::new();
+        mock.add_crate("trueno", "1.0.0");
+        let fetcher = FlakyFetcher {
+            inner: mock,
+            failures: 2,
+            error: http_503,
+            attempts: std::sync::Mutex::new(HashMap::new()),
+        };
+
+        let mut scanner = PublishStatusScanner::new(temp.path().to_path_buf())
+            .with_cache(cache)
+            .with_fetcher(fetcher)
+            .with_retry(3, Duration::from_millis(1));
+        let report = scanner.scan().await.unwrap();
+
+        let status = &report.crates[0];
+        assert_eq!(status.crates_io_version.as_deref(), Some("1.0.0"));
+        assert!(status.error.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_pub_006_scan_reports_exhausted_retries() {
+        let (temp, cache) = scan_workspace(&["trueno", "aprender"]);
+        let mut mock = MockCratesIoClient::new();
+        mock.add_crate("trueno", "1.0.0").add_not_found("aprender");
+        let fetcher = FlakyFetcher {
+            inner: mock,
+            failures: 2,
+            error: http_503,
+            attempts: std::sync::Mutex::new(HashMap::new()),
+        };
+
+        let mut scanner = PublishStatusScanner::new(temp.path().to_path_buf())
+            .with_cache(cache)
+            .with_fetcher(fetcher)
+            .with_retry(2, Duration::from_millis(1));
+        let report = scanner.scan().await.unwrap();
+
+        // Network failures are surfaced rather than treated as unpublished
+        let trueno = report.crates.iter().find(|c| c.name == "trueno").unwrap();
+        assert_eq!(trueno.action, PublishAction::Error);
+        assert!(trueno.crates_io_version.is_none());
+        assert!(trueno
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("failed after 2 attempt(s)"));
+        assert!(!scanner.cache.entries.contains_key("trueno"));
+
+        // A crate that eventually returns 404 is simply not published
+        let aprender = report.crates.iter().find(|c| c.name == "aprender").unwrap();
+        assert!(aprender.crates_io_version.is_none());
+        assert!(aprender.error.is_none());
+        assert_ne!(aprender.action, PublishAction::Error);
+    }
+
+    #[tokio::test]
+    async fn test_pub_006_scan_does_not_retry_offline_misses() {
+        let (temp, cache) = scan_workspace(&["trueno"]);
+        let mut mock = MockCratesIoClient::new();
+        mock.add_crate("trueno", "1.0.0");
+        let fetcher = FlakyFetcher {
+            inner: mock,
+            failures: usize::MAX,
+            error: |name| CrateFetchError::Offline(name.to_string()).into(),
+            attempts: std::sync::Mutex::new(HashMap::new()),
+        };
+
+        // A retry would sleep for a minute; the scan must give up at once
+        let mut scanner = PublishStatusScanner::new(temp.path().to_path_buf())
+            .with_cache(cache)
+            .with_fetcher(fetcher)
+            .with_retry(5, Duration::from_secs(60));
+        let report = scanner.scan().await.unwrap();
+
+        let status = &report.crates[0];
+        assert_eq!(status.action, PublishAction::Error);
+        assert!(status
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("failed after 1 attempt(s)"));
+    }
+
+    #[test]
+    fn test_pub_006_retry_policy_backoff() {
+        let policy = RetryPolicy {
+            max_attempts: 4,
+            base_delay: Duration::from_millis(100),
+        };
+        assert_eq!(policy.delay_for(1), Duration::from_millis(100));
+        assert_eq!(policy.delay_for(2), Duration::from_millis(200));
+        assert_eq!(policy.delay_for(3), Duration::from_millis(400));
+    }
+
+    #[test]
+    fn test_pub_006_from_workspace_manifest_members() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path();
+        std::fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"core\", \"crates/*\"]\n",
+        )
+        .unwrap();
+        for (dir, name) in [
+            ("core", "my-core"),
+            ("crates/alpha", "alpha"),
+            ("crates/beta", "beta"),
+        ] {
+            std::fs::create_dir_all(root.join(dir)).unwrap();
+            std::fs::write(
+                root.join(dir).join("Cargo.toml"),
+                format!("[package]\nname = \"{name}\"\nversion = \"0.1.0\"\n"),
+            )
+            .unwrap();
+        }
+        // Matched by the glob but not a crate
+        std::fs::create_dir_all(root.join("crates/docs")).unwrap();
+
+        let scanner = PublishStatusScanner::from_workspace_manifest(root).unwrap();
+        let dirs = scanner.find_crate_dirs();
+
+        let names: Vec<&str> = dirs.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, vec!["alpha", "beta", "my-core"]);
+        assert_eq!(dirs[2].1, root.join("core"));
+    }
+
+    #[test]
+    fn test_pub_006_from_workspace_manifest_not_workspace() {
+        let temp = tempfile::tempdir().unwrap();
+        std::fs::write(
+            temp.path().join("Cargo.toml"),
+            "[package]\nname = \"solo\"\nversion = \"0.1.0\"\n",
+        )
+        .unwrap();
+
+        let err = PublishStatusScanner::from_workspace_manifest(temp.path())
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("[workspace].members"));
+    }
+
+    #[test]
+    fn test_pub_006_find_crate_dirs_paiml_fallback() {
+        let temp = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(temp.path().join("trueno")).unwrap();
+        std::fs::write(temp.path().join("trueno/Cargo.toml"), "").unwrap();
+        std::fs::create_dir_all(temp.path().join("not-paiml")).unwrap();
+        std::fs::write(temp.path().join("not-paiml/Cargo.toml"), "").unwrap();
+
+        let scanner = PublishStatusScanner::new(temp.path().to_path_buf());
+        let names: Vec<String> = scanner
+            .find_crate_dirs()
+            .into_iter()
+            .map(|(n, _)| n)
+            .collect();
+        assert_eq!(names, vec!["trueno"]);
+    }
+
     // ========================================================================
     // PUB-006: Formatting tests
     // ========================================================================
@@ -971,6 +2568,7 @@ mod tests {
             name: "trueno".to_string(),
             local_version: Some("0.8.1".to_string()),
             crates_io_version: Some("0.8.1".to_string()),
+            crates_io_yanked: false,
             git_status: GitStatus {
                 is_clean: true,
                 ..Default::default()
@@ -989,12 +2587,93 @@ mod tests {
         assert!(text.contains("up to date"));
     }
 
+    #[test]
+    fn test_pub_006_format_report_text_yanked() {
+        let statuses = vec![CrateStatus {
+            name: "trueno".to_string(),
+            local_version: Some("0.8.1".to_string()),
+            crates_io_version: Some("0.8.1".to_string()),
+            crates_io_yanked: true,
+            git_status: GitStatus {
+                is_clean: true,
+                ..Default::default()
+            },
+            action: PublishAction::NeedsRepublish,
+            path: PathBuf::from("."),
+            error: None,
+        }];
+
+        let report = PublishStatusReport::from_statuses(statuses, 0, 10);
+        let text = format_report_text(&report);
+
+        assert!(text.contains("0.8.1 (yanked)"));
+        assert!(text.contains("REPUBLISH"));
+        assert!(text.contains("1 crates have a yanked crates.io release"));
+    }
+
+    fn sample_report() -> PublishStatusReport {
+        let statuses = vec![
+            CrateStatus {
+                name: "trueno".to_string(),
+                local_version: Some("0.8.2".to_string()),
+                crates_io_version: Some("0.8.1".to_string()),
+                crates_io_yanked: false,
+                git_status: GitStatus {
+                    is_clean: true,
+                    ..Default::default()
+                },
+                action: PublishAction::NeedsPublish,
+                path: PathBuf::from("."),
+                error: None,
+            },
+            CrateStatus {
+                name: "aprender".to_string(),
+                local_version: Some("0.14.0".to_string()),
+                crates_io_version: None,
+                crates_io_yanked: false,
+                git_status: GitStatus {
+                    modified: 2,
+                    untracked: 1,
+                    ..Default::default()
+                },
+                action: PublishAction::NeedsCommit,
+                path: PathBuf::from("."),
+                error: None,
+            },
+        ];
+        PublishStatusReport::from_statuses(statuses, 0, 5)
+    }
+
+    #[test]
+    fn test_pub_006_format_report_csv() {
+        let report = sample_report();
+        let csv = format_report_csv(&report);
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), report.total + 1);
+        assert_eq!(lines[0], "name,local,crates_io,git,action");
+        assert_eq!(lines[1], "trueno,0.8.2,0.8.1,clean,PUBLISH");
+        assert_eq!(lines[2], "aprender,0.14.0,,2M 1?,commit changes");
+    }
+
+    #[test]
+    fn test_pub_006_format_report_markdown() {
+        let markdown = format_report_markdown(&sample_report());
+
+        assert!(markdown.contains("| Crate | Local | crates.io | Git | Action |"));
+        assert!(markdown.contains("|-------|-------|-----------|-----|--------|"));
+        assert!(markdown.contains("| trueno | 0.8.2 | 0.8.1 | clean | 📦 PUBLISH |"));
+        assert!(markdown.contains("| aprender | 0.14.0 | - | 2M 1? | 📝 commit changes |"));
+        assert!(markdown.contains("**2 crates**: 1 publish, 1 commit, 0 up-to-date"));
+    }
+
     #[test]
     fn test_pub_006_format_report_json() {
         let statuses = vec![CrateStatus {
             name: "test".to_string(),
             local_version: Some("1.0.0".to_string()),
             crates_io_version: Some("1.0.0".to_string()),
+            crates_io_yanked: false,
             git_status: GitStatus::default(),
             action: PublishAction::UpToDate,
             path: PathBuf::from("."),
@@ -1026,6 +2705,9 @@ mod proptests {
                 modified: m,
                 untracked: u,
                 staged: s,
+                ahead: 0,
+                behind: 0,
+                lockfile_drift: false,
                 head_sha: String::new(),
                 is_clean: m + u + s == 0,
             };
@@ -1039,6 +2721,9 @@ mod proptests {
                 modified: 0,
                 untracked: 0,
                 staged: 0,
+                ahead: 0,
+                behind: 0,
+                lockfile_drift: false,
                 head_sha: sha,
                 is_clean: true,
             };
@@ -1060,6 +2745,7 @@ mod proptests {
                     name: format!("up{}", i),
                     local_version: Some("1.0.0".to_string()),
                     crates_io_version: Some("1.0.0".to_string()),
+                    crates_io_yanked: false,
                     git_status: GitStatus::default(),
                     action: PublishAction::UpToDate,
                     path: PathBuf::from("."),
@@ -1072,6 +2758,7 @@ mod proptests {
                     name: format!("pub{}", i),
                     local_version: Some("1.0.1".to_string()),
                     crates_io_version: Some("1.0.0".to_string()),
+                    crates_io_yanked: false,
                     git_status: GitStatus::default(),
                     action: PublishAction::NeedsPublish,
                     path: PathBuf::from("."),
@@ -1084,6 +2771,7 @@ mod proptests {
                     name: format!("commit{}", i),
                     local_version: Some("1.0.0".to_string()),
                     crates_io_version: Some("1.0.0".to_string()),
+                    crates_io_yanked: false,
                     git_status: GitStatus { modified: 1, is_clean: false, ..Default::default() },
                     action: PublishAction::NeedsCommit,
                     path: PathBuf::from("."),
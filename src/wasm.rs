@@ -6,7 +6,7 @@
 //! # Features
 //!
 //! - **Language Detection**: Analyze code snippets to detect languages
-//! - **Backend Selection**: Recommend optimal compute backend (SIMD/GPU)
+//! - **Backend Selection**: Recommend optimal compute backend (SIMD/WebGPU)
 //! - **NumPy Conversion**: Convert NumPy operations to Trueno
 //! - **sklearn Conversion**: Convert sklearn algorithms to Aprender
 //! - **PyTorch Conversion**: Convert PyTorch operations to Realizar
@@ -29,7 +29,7 @@
 //!
 //! // Get backend recommendation
 //! const backend = backend_recommend("matmul", 1024);
-//! console.log(backend); // "SIMD" or "GPU"
+//! console.log(backend); // "SIMD" or "WebGPU"
 //! ```
 
 #[cfg(feature = "wasm")]
@@ -228,6 +228,14 @@ pub fn convert_numpy(
         NumPyOp::Sum
     } else if numpy_code.contains("np.mean") || numpy_code.contains("numpy.mean") {
         NumPyOp::Mean
+    } else if numpy_code.contains("np.zeros") || numpy_code.contains("numpy.zeros") {
+        NumPyOp::Zeros
+    } else if numpy_code.contains("np.ones") || numpy_code.contains("numpy.ones") {
+        NumPyOp::Ones
+    } else if numpy_code.contains("np.arange") || numpy_code.contains("numpy.arange") {
+        NumPyOp::Arange
+    } else if numpy_code.contains("np.linspace") || numpy_code.contains("numpy.linspace") {
+        NumPyOp::Linspace
     } else if numpy_code.contains("np.array") || numpy_code.contains("numpy.array") {
         NumPyOp::Array
     } else if numpy_code.contains("reshape") {
@@ -361,7 +369,7 @@ pub fn convert_pytorch(
 /// * `data_size` - Size of data to process
 ///
 /// # Returns
-/// Recommended backend as string ("Scalar", "SIMD", "GPU")
+/// Recommended backend as string ("Scalar", "SIMD", "WebGPU")
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
 pub fn backend_recommend(operation_type: &str, data_size: usize) -> Result<String, JsValue> {
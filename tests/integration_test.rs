@@ -551,6 +551,50 @@ fn test_validate_with_flags() {
         .stdout(predicate::str::contains("Validating equivalence"));
 }
 
+/// Test validate content subcommand reports violations and fails
+#[test]
+fn test_validate_content_heading_skip() {
+    let temp_dir = TempDir::new().unwrap();
+    let chapter = temp_dir.path().join("chapter.md");
+    fs::write(
+        &chapter,
+        "# Chapter 1\n\nIntro text.\n\n### Skipped Level\n\nMore text.\n",
+    )
+    .unwrap();
+
+    Command::cargo_bin("batuta")
+        .unwrap()
+        .arg("validate")
+        .arg("content")
+        .arg("--type")
+        .arg("bch")
+        .arg(&chapter)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("Quality Score"))
+        .stdout(predicate::str::contains("[ERROR] heading_hierarchy"))
+        .stdout(predicate::str::contains("FAIL"));
+}
+
+/// Test validate content subcommand passes clean content
+#[test]
+fn test_validate_content_pass() {
+    let temp_dir = TempDir::new().unwrap();
+    let chapter = temp_dir.path().join("chapter.md");
+    fs::write(&chapter, "# Chapter 1\n\n## Section\n\nBody text.\n").unwrap();
+
+    Command::cargo_bin("batuta")
+        .unwrap()
+        .arg("validate")
+        .arg("content")
+        .arg("-t")
+        .arg("bch")
+        .arg(&chapter)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("PASS"));
+}
+
 /// Test build with different targets
 #[test]
 fn test_build_variants() {